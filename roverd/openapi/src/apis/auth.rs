@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Multipart};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthPasswordPostResponse {
+    /// The password was changed
+    Status200_ThePasswordWasChanged,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+    /// Only admins can change other passwords
+    Status403_OnlyAdminsCanChangeOtherPasswords,
+    /// Too many failed logins
+    Status429_TooManyFailedLogins,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthTokensGetResponse {
+    /// The API tokens
+    Status200_TheAPITokens(Vec<models::ApiToken>),
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthTokensIdDeleteResponse {
+    /// The token was revoked
+    Status200_TheTokenWasRevoked,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+    /// Entity not found
+    Status404_EntityNotFound,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthTokensPostResponse {
+    /// The token was created
+    Status200_TheTokenWasCreated(models::AuthTokensPost200Response),
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+/// Auth
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Auth {
+    /// Change the password of an account, which can be the rover user or an account from users.yaml.
+    ///
+    /// AuthPasswordPost - POST /auth/password
+    async fn auth_password_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        body: models::AuthPasswordPostRequest,
+    ) -> Result<AuthPasswordPostResponse, ()>;
+
+    /// Retrieve the API tokens of the rover, without their secrets.
+    ///
+    /// AuthTokensGet - GET /auth/tokens
+    async fn auth_tokens_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<AuthTokensGetResponse, ()>;
+
+    /// Revoke an API token.
+    ///
+    /// AuthTokensIdDelete - DELETE /auth/tokens/{id}
+    async fn auth_tokens_id_delete(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::AuthTokensIdDeletePathParams,
+    ) -> Result<AuthTokensIdDeleteResponse, ()>;
+
+    /// Create a new API token, which can be used as a bearer token. The secret is only returned once..
+    ///
+    /// AuthTokensPost - POST /auth/tokens
+    async fn auth_tokens_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        body: models::AuthTokensPostRequest,
+    ) -> Result<AuthTokensPostResponse, ()>;
+}
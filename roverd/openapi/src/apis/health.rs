@@ -17,6 +17,18 @@ pub enum RootGetResponse {
     Status400_AnErrorOccurred(models::GenericError),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RenamePostResponse {
+    /// The rover was renamed
+    Status200_TheRoverWasRenamed,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -65,6 +77,17 @@ pub trait Health {
         cookies: CookieJar,
     ) -> Result<RootGetResponse, ()>;
 
+    /// Rename the rover, the new name is written to info.txt and shown by /status right away.
+    ///
+    /// RenamePost - POST /rename
+    async fn rename_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        body: models::RenamePostRequest,
+    ) -> Result<RenamePostResponse, ()>;
+
     /// Shutdown the rover..
     ///
     /// ShutdownPost - POST /shutdown
@@ -45,6 +45,54 @@ pub enum PipelinePostResponse {
     Status401_UnauthorizedAccess,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PipelineProfilesGetResponse {
+    /// The stored profiles
+    Status200_TheStoredProfiles(Vec<models::Profile>),
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PipelineProfilesNameActivatePostResponse {
+    /// The pipeline was set to the profile
+    Status200_ThePipelineWasSetToTheProfile,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PipelineProfilesNameDeleteResponse {
+    /// The profile was deleted
+    Status200_TheProfileWasDeleted,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PipelineProfilesNamePostResponse {
+    /// The profile was saved
+    Status200_TheProfileWasSaved,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -69,6 +117,44 @@ pub enum PipelineStopPostResponse {
     Status401_UnauthorizedAccess,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PipelineTuningPostResponse {
+    /// The values were published to the running services
+    Status200_TheValuesWerePublishedToTheRunningServices,
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RunsGetResponse {
+    /// The recorded runs
+    Status200_TheRecordedRuns(Vec<models::RunsGet200ResponseInner>),
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RunsIdLogsAuthorNameVersionGetResponse {
+    /// The collection of logs
+    Status200_TheCollectionOfLogs(Vec<String>),
+    /// An error occurred
+    Status400_AnErrorOccurred(models::GenericError),
+    /// Entity not found
+    Status404_EntityNotFound,
+    /// Unauthorized access (you need to set the Authorization header with a valid username and password)
+    Status401_UnauthorizedAccess,
+}
+
 /// Pipeline
 #[async_trait]
 #[allow(clippy::ptr_arg)]
@@ -106,6 +192,50 @@ pub trait Pipeline {
         body: Vec<models::PipelinePostRequestInner>,
     ) -> Result<PipelinePostResponse, ()>;
 
+    /// Retrieve all pipeline profiles stored on the rover.
+    ///
+    /// PipelineProfilesGet - GET /pipeline/profiles
+    async fn pipeline_profiles_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<PipelineProfilesGetResponse, ()>;
+
+    /// Set the pipeline to the services of a profile. Only possible if the pipeline is not started..
+    ///
+    /// PipelineProfilesNameActivatePost - POST /pipeline/profiles/{name}/activate
+    async fn pipeline_profiles_name_activate_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::PipelineProfilesNameActivatePostPathParams,
+    ) -> Result<PipelineProfilesNameActivatePostResponse, ()>;
+
+    /// Delete a pipeline profile.
+    ///
+    /// PipelineProfilesNameDelete - DELETE /pipeline/profiles/{name}
+    async fn pipeline_profiles_name_delete(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::PipelineProfilesNameDeletePathParams,
+    ) -> Result<PipelineProfilesNameDeleteResponse, ()>;
+
+    /// Save a pipeline as a named profile, replacing the profile if it already exists. The pipeline must be valid..
+    ///
+    /// PipelineProfilesNamePost - POST /pipeline/profiles/{name}
+    async fn pipeline_profiles_name_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::PipelineProfilesNamePostPathParams,
+        body: Vec<models::ProfileService>,
+    ) -> Result<PipelineProfilesNamePostResponse, ()>;
+
     /// Start the pipeline.
     ///
     /// PipelineStartPost - POST /pipeline/start
@@ -125,4 +255,37 @@ pub trait Pipeline {
         host: Host,
         cookies: CookieJar,
     ) -> Result<PipelineStopPostResponse, ()>;
+
+    /// Change tunable configuration values of the running pipeline.
+    ///
+    /// PipelineTuningPost - POST /pipeline/tuning
+    async fn pipeline_tuning_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        body: Vec<models::PipelineTuningPostRequestInner>,
+    ) -> Result<PipelineTuningPostResponse, ()>;
+
+    /// Retrieve the history of pipeline runs, most recent first. Each start of the pipeline is recorded as a run..
+    ///
+    /// RunsGet - GET /runs
+    async fn runs_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<RunsGetResponse, ()>;
+
+    /// Retrieve the logs a service wrote during a given run..
+    ///
+    /// RunsIdLogsAuthorNameVersionGet - GET /runs/{id}/logs/{author}/{name}/{version}
+    async fn runs_id_logs_author_name_version_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::RunsIdLogsAuthorNameVersionGetPathParams,
+        query_params: models::RunsIdLogsAuthorNameVersionGetQueryParams,
+    ) -> Result<RunsIdLogsAuthorNameVersionGetResponse, ()>;
 }
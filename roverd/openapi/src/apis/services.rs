@@ -133,7 +133,7 @@ pub enum UploadPostResponse {
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Services {
-    /// Fetches the zip file from the given URL and installs the service onto the filesystem.
+    /// Fetches the service archive (ZIP, tar.gz or tar.zst) from the given URL and installs the service onto the filesystem.
     ///
     /// FetchPost - POST /fetch
     async fn fetch_post(
@@ -219,7 +219,7 @@ pub trait Services {
         cookies: CookieJar,
     ) -> Result<ServicesGetResponse, ()>;
 
-    /// Upload a new service or new version to the rover by uploading a ZIP file.
+    /// Upload a new service or new version to the rover by uploading a ZIP, tar.gz or tar.zst archive.
     ///
     /// UploadPost - POST /upload
     async fn upload_post(
@@ -7,6 +7,13 @@ use validator::Validate;
 use crate::header;
 use crate::{models, types::*};
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AuthTokensIdDeletePathParams {
+    /// The identifier of the token.
+    pub id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct LogsAuthorNameVersionGetPathParams {
@@ -28,6 +35,50 @@ pub struct LogsAuthorNameVersionGetQueryParams {
     pub lines: Option<i32>,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineProfilesNameActivatePostPathParams {
+    /// The name of the profile.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineProfilesNameDeletePathParams {
+    /// The name of the profile.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineProfilesNamePostPathParams {
+    /// The name of the profile.
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RunsIdLogsAuthorNameVersionGetPathParams {
+    /// The id of the run.
+    pub id: i64,
+    /// The author of the service.
+    pub author: String,
+    /// The name of the service.
+    pub name: String,
+    /// The version of the service.
+    pub version: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RunsIdLogsAuthorNameVersionGetQueryParams {
+    /// The number of log lines to retrieve
+    #[serde(rename = "lines")]
+    #[validate(range(min = 1, max = 1000))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lines: Option<i32>,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct ServicesAuthorGetPathParams {
@@ -77,148 +128,51 @@ pub struct ServicesAuthorServiceVersionPostPathParams {
     pub version: String,
 }
 
-/// The status of the roverd process
-/// Enumeration of values.
-/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
-/// which helps with FFI.
-#[allow(non_camel_case_types)]
-#[repr(C)]
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
-)]
-#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
-pub enum DaemonStatus {
-    #[serde(rename = "operational")]
-    Operational,
-    #[serde(rename = "recoverable")]
-    Recoverable,
-    #[serde(rename = "unrecoverable")]
-    Unrecoverable,
-}
-
-impl std::fmt::Display for DaemonStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            DaemonStatus::Operational => write!(f, "operational"),
-            DaemonStatus::Recoverable => write!(f, "recoverable"),
-            DaemonStatus::Unrecoverable => write!(f, "unrecoverable"),
-        }
-    }
-}
-
-impl std::str::FromStr for DaemonStatus {
-    type Err = String;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "operational" => std::result::Result::Ok(DaemonStatus::Operational),
-            "recoverable" => std::result::Result::Ok(DaemonStatus::Recoverable),
-            "unrecoverable" => std::result::Result::Ok(DaemonStatus::Unrecoverable),
-            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
-        }
-    }
-}
-
-/// DuplicateServiceError
-#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct DuplicateServiceError(pub String);
-
-impl validator::Validate for DuplicateServiceError {
-    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
-        std::result::Result::Ok(())
-    }
-}
-
-impl std::convert::From<String> for DuplicateServiceError {
-    fn from(x: String) -> Self {
-        DuplicateServiceError(x)
-    }
-}
-
-impl std::fmt::Display for DuplicateServiceError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.0)
-    }
-}
-
-impl std::str::FromStr for DuplicateServiceError {
-    type Err = std::string::ParseError;
-    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
-        std::result::Result::Ok(DuplicateServiceError(x.to_string()))
-    }
-}
-
-impl std::convert::From<DuplicateServiceError> for String {
-    fn from(x: DuplicateServiceError) -> Self {
-        x.0
-    }
-}
-
-impl std::ops::Deref for DuplicateServiceError {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
-
-impl std::ops::DerefMut for DuplicateServiceError {
-    fn deref_mut(&mut self) -> &mut String {
-        &mut self.0
-    }
-}
-
+/// An API token, without its secret
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct FetchPost200Response {
-    /// The name of the service
+pub struct ApiToken {
+    /// The identifier of the token
+    #[serde(rename = "id")]
+    pub id: String,
+
+    /// Description of what the token is used for
     #[serde(rename = "name")]
     pub name: String,
 
-    /// The version of the service
-    #[serde(rename = "version")]
-    pub version: String,
-
-    /// The author of the service
-    #[serde(rename = "author")]
-    pub author: String,
+    #[serde(rename = "role")]
+    pub role: models::Role,
 
-    /// Whether the pipeline was invalidated by this service upload
-    #[serde(rename = "invalidated_pipeline")]
-    pub invalidated_pipeline: bool,
+    /// The time the token was created as milliseconds since epoch
+    #[serde(rename = "created")]
+    pub created: i64,
 }
 
-impl FetchPost200Response {
+impl ApiToken {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        name: String,
-        version: String,
-        author: String,
-        invalidated_pipeline: bool,
-    ) -> FetchPost200Response {
-        FetchPost200Response {
+    pub fn new(id: String, name: String, role: models::Role, created: i64) -> ApiToken {
+        ApiToken {
+            id,
             name,
-            version,
-            author,
-            invalidated_pipeline,
+            role,
+            created,
         }
     }
 }
 
-/// Converts the FetchPost200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ApiToken value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for FetchPost200Response {
+impl std::fmt::Display for ApiToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
             Some("name".to_string()),
             Some(self.name.to_string()),
-            Some("version".to_string()),
-            Some(self.version.to_string()),
-            Some("author".to_string()),
-            Some(self.author.to_string()),
-            Some("invalidated_pipeline".to_string()),
-            Some(self.invalidated_pipeline.to_string()),
+            // Skipping role in query parameter serialization
+            Some("created".to_string()),
+            Some(self.created.to_string()),
         ];
 
         write!(
@@ -229,10 +183,10 @@ impl std::fmt::Display for FetchPost200Response {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a FetchPost200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a ApiToken value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for FetchPost200Response {
+impl std::str::FromStr for ApiToken {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -240,10 +194,10 @@ impl std::str::FromStr for FetchPost200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
+            pub id: Vec<String>,
             pub name: Vec<String>,
-            pub version: Vec<String>,
-            pub author: Vec<String>,
-            pub invalidated_pipeline: Vec<bool>,
+            pub role: Vec<models::Role>,
+            pub created: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -257,7 +211,7 @@ impl std::str::FromStr for FetchPost200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing FetchPost200Response".to_string(),
+                        "Missing value while parsing ApiToken".to_string(),
                     )
                 }
             };
@@ -266,24 +220,25 @@ impl std::str::FromStr for FetchPost200Response {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
+                    "id" => intermediate_rep.id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "author" => intermediate_rep.author.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "role" => intermediate_rep.role.push(
+                        <models::Role as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "invalidated_pipeline" => intermediate_rep.invalidated_pipeline.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "created" => intermediate_rep.created.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing FetchPost200Response".to_string(),
+                            "Unexpected key while parsing ApiToken".to_string(),
                         )
                     }
                 }
@@ -294,47 +249,45 @@ impl std::str::FromStr for FetchPost200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(FetchPost200Response {
-            name: intermediate_rep
-                .name
+        std::result::Result::Ok(ApiToken {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "name missing in FetchPost200Response".to_string())?,
-            version: intermediate_rep
-                .version
+                .ok_or_else(|| "id missing in ApiToken".to_string())?,
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "version missing in FetchPost200Response".to_string())?,
-            author: intermediate_rep
-                .author
+                .ok_or_else(|| "name missing in ApiToken".to_string())?,
+            role: intermediate_rep
+                .role
                 .into_iter()
                 .next()
-                .ok_or_else(|| "author missing in FetchPost200Response".to_string())?,
-            invalidated_pipeline: intermediate_rep
-                .invalidated_pipeline
+                .ok_or_else(|| "role missing in ApiToken".to_string())?,
+            created: intermediate_rep
+                .created
                 .into_iter()
                 .next()
-                .ok_or_else(|| {
-                    "invalidated_pipeline missing in FetchPost200Response".to_string()
-                })?,
+                .ok_or_else(|| "created missing in ApiToken".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<FetchPost200Response> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ApiToken> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<FetchPost200Response>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ApiToken>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<FetchPost200Response>,
+        hdr_value: header::IntoHeaderValue<ApiToken>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for FetchPost200Response - value: {} is invalid {}",
+                "Invalid header value for ApiToken - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -342,18 +295,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<FetchPost200Response>> for He
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FetchPost200Response> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ApiToken> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <FetchPost200Response as std::str::FromStr>::from_str(value) {
+                match <ApiToken as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into FetchPost200Response - {}",
+                        "Unable to convert header value '{}' into ApiToken - {}",
                         value, err
                     )),
                 }
@@ -368,25 +321,48 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FetchPost200
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct FetchPostRequest {
-    /// Download URL of the service to be downloaded, must include scheme
-    #[serde(rename = "url")]
-    pub url: String,
+pub struct AuthPasswordPostRequest {
+    /// The name of the account
+    #[serde(rename = "username")]
+    pub username: String,
+
+    /// The current password of the account, not checked when an admin changes the password of another account
+    #[serde(rename = "password")]
+    pub password: String,
+
+    /// The new password, of at least 8 characters
+    #[serde(rename = "new_password")]
+    pub new_password: String,
 }
 
-impl FetchPostRequest {
+impl AuthPasswordPostRequest {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(url: String) -> FetchPostRequest {
-        FetchPostRequest { url }
+    pub fn new(
+        username: String,
+        password: String,
+        new_password: String,
+    ) -> AuthPasswordPostRequest {
+        AuthPasswordPostRequest {
+            username,
+            password,
+            new_password,
+        }
     }
 }
 
-/// Converts the FetchPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AuthPasswordPostRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for FetchPostRequest {
+impl std::fmt::Display for AuthPasswordPostRequest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![Some("url".to_string()), Some(self.url.to_string())];
+        let params: Vec<Option<String>> = vec![
+            Some("username".to_string()),
+            Some(self.username.to_string()),
+            Some("password".to_string()),
+            Some(self.password.to_string()),
+            Some("new_password".to_string()),
+            Some(self.new_password.to_string()),
+        ];
 
         write!(
             f,
@@ -396,10 +372,10 @@ impl std::fmt::Display for FetchPostRequest {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a FetchPostRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a AuthPasswordPostRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for FetchPostRequest {
+impl std::str::FromStr for AuthPasswordPostRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -407,7 +383,9 @@ impl std::str::FromStr for FetchPostRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub url: Vec<String>,
+            pub username: Vec<String>,
+            pub password: Vec<String>,
+            pub new_password: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -421,7 +399,7 @@ impl std::str::FromStr for FetchPostRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing FetchPostRequest".to_string(),
+                        "Missing value while parsing AuthPasswordPostRequest".to_string(),
                     )
                 }
             };
@@ -430,12 +408,20 @@ impl std::str::FromStr for FetchPostRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "url" => intermediate_rep.url.push(
+                    "username" => intermediate_rep.username.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "password" => intermediate_rep.password.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "new_password" => intermediate_rep.new_password.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing FetchPostRequest".to_string(),
+                            "Unexpected key while parsing AuthPasswordPostRequest".to_string(),
                         )
                     }
                 }
@@ -446,30 +432,40 @@ impl std::str::FromStr for FetchPostRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(FetchPostRequest {
-            url: intermediate_rep
-                .url
+        std::result::Result::Ok(AuthPasswordPostRequest {
+            username: intermediate_rep
+                .username
                 .into_iter()
                 .next()
-                .ok_or_else(|| "url missing in FetchPostRequest".to_string())?,
+                .ok_or_else(|| "username missing in AuthPasswordPostRequest".to_string())?,
+            password: intermediate_rep
+                .password
+                .into_iter()
+                .next()
+                .ok_or_else(|| "password missing in AuthPasswordPostRequest".to_string())?,
+            new_password: intermediate_rep
+                .new_password
+                .into_iter()
+                .next()
+                .ok_or_else(|| "new_password missing in AuthPasswordPostRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<FetchPostRequest> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AuthPasswordPostRequest> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<FetchPostRequest>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AuthPasswordPostRequest>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<FetchPostRequest>,
+        hdr_value: header::IntoHeaderValue<AuthPasswordPostRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for FetchPostRequest - value: {} is invalid {}",
+                "Invalid header value for AuthPasswordPostRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -477,18 +473,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<FetchPostRequest>> for Header
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FetchPostRequest> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AuthPasswordPostRequest> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <FetchPostRequest as std::str::FromStr>::from_str(value) {
+                match <AuthPasswordPostRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into FetchPostRequest - {}",
+                        "Unable to convert header value '{}' into AuthPasswordPostRequest - {}",
                         value, err
                     )),
                 }
@@ -503,40 +499,33 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FetchPostReq
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct FqnsGet200ResponseInner {
-    #[serde(rename = "author")]
-    pub author: String,
+pub struct AuthTokensPost200Response {
+    /// The identifier of the token, used to revoke it
+    #[serde(rename = "id")]
+    pub id: String,
 
-    #[serde(rename = "name")]
-    pub name: String,
-
-    #[serde(rename = "version")]
-    pub version: String,
+    /// The secret to send in the Authorization header as Bearer token
+    #[serde(rename = "token")]
+    pub token: String,
 }
 
-impl FqnsGet200ResponseInner {
+impl AuthTokensPost200Response {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(author: String, name: String, version: String) -> FqnsGet200ResponseInner {
-        FqnsGet200ResponseInner {
-            author,
-            name,
-            version,
-        }
+    pub fn new(id: String, token: String) -> AuthTokensPost200Response {
+        AuthTokensPost200Response { id, token }
     }
 }
 
-/// Converts the FqnsGet200ResponseInner value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AuthTokensPost200Response value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for FqnsGet200ResponseInner {
+impl std::fmt::Display for AuthTokensPost200Response {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("author".to_string()),
-            Some(self.author.to_string()),
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-            Some("version".to_string()),
-            Some(self.version.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("token".to_string()),
+            Some(self.token.to_string()),
         ];
 
         write!(
@@ -547,10 +536,10 @@ impl std::fmt::Display for FqnsGet200ResponseInner {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a FqnsGet200ResponseInner value
+/// Converts Query Parameters representation (style=form, explode=false) to a AuthTokensPost200Response value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for FqnsGet200ResponseInner {
+impl std::str::FromStr for AuthTokensPost200Response {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -558,9 +547,8 @@ impl std::str::FromStr for FqnsGet200ResponseInner {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub author: Vec<String>,
-            pub name: Vec<String>,
-            pub version: Vec<String>,
+            pub id: Vec<String>,
+            pub token: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -574,7 +562,7 @@ impl std::str::FromStr for FqnsGet200ResponseInner {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing FqnsGet200ResponseInner".to_string(),
+                        "Missing value while parsing AuthTokensPost200Response".to_string(),
                     )
                 }
             };
@@ -583,20 +571,16 @@ impl std::str::FromStr for FqnsGet200ResponseInner {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "author" => intermediate_rep.author.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
+                    "id" => intermediate_rep.id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(
+                    "token" => intermediate_rep.token.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing FqnsGet200ResponseInner".to_string(),
+                            "Unexpected key while parsing AuthTokensPost200Response".to_string(),
                         )
                     }
                 }
@@ -607,40 +591,35 @@ impl std::str::FromStr for FqnsGet200ResponseInner {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(FqnsGet200ResponseInner {
-            author: intermediate_rep
-                .author
+        std::result::Result::Ok(AuthTokensPost200Response {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "author missing in FqnsGet200ResponseInner".to_string())?,
-            name: intermediate_rep
-                .name
-                .into_iter()
-                .next()
-                .ok_or_else(|| "name missing in FqnsGet200ResponseInner".to_string())?,
-            version: intermediate_rep
-                .version
+                .ok_or_else(|| "id missing in AuthTokensPost200Response".to_string())?,
+            token: intermediate_rep
+                .token
                 .into_iter()
                 .next()
-                .ok_or_else(|| "version missing in FqnsGet200ResponseInner".to_string())?,
+                .ok_or_else(|| "token missing in AuthTokensPost200Response".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<FqnsGet200ResponseInner> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AuthTokensPost200Response> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<FqnsGet200ResponseInner>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AuthTokensPost200Response>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<FqnsGet200ResponseInner>,
+        hdr_value: header::IntoHeaderValue<AuthTokensPost200Response>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for FqnsGet200ResponseInner - value: {} is invalid {}",
+                "Invalid header value for AuthTokensPost200Response - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -648,18 +627,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<FqnsGet200ResponseInner>> for
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FqnsGet200ResponseInner> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AuthTokensPost200Response> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <FqnsGet200ResponseInner as std::str::FromStr>::from_str(value) {
+                match <AuthTokensPost200Response as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into FqnsGet200ResponseInner - {}",
+                        "Unable to convert header value '{}' into AuthTokensPost200Response - {}",
                         value, err
                     )),
                 }
@@ -674,40 +653,31 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FqnsGet200Re
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct GenericError {
-    /// A message describing the error
-    #[serde(rename = "message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+pub struct AuthTokensPostRequest {
+    /// Description of what the token is used for
+    #[serde(rename = "name")]
+    pub name: String,
 
-    /// A code describing the error (this is not an HTTP status code)
-    #[serde(rename = "code")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub code: Option<i32>,
+    #[serde(rename = "role")]
+    pub role: models::Role,
 }
 
-impl GenericError {
+impl AuthTokensPostRequest {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> GenericError {
-        GenericError {
-            message: None,
-            code: None,
-        }
+    pub fn new(name: String, role: models::Role) -> AuthTokensPostRequest {
+        AuthTokensPostRequest { name, role }
     }
 }
 
-/// Converts the GenericError value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AuthTokensPostRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for GenericError {
+impl std::fmt::Display for AuthTokensPostRequest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            self.message
-                .as_ref()
-                .map(|message| ["message".to_string(), message.to_string()].join(",")),
-            self.code
-                .as_ref()
-                .map(|code| ["code".to_string(), code.to_string()].join(",")),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping role in query parameter serialization
         ];
 
         write!(
@@ -718,10 +688,10 @@ impl std::fmt::Display for GenericError {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a GenericError value
+/// Converts Query Parameters representation (style=form, explode=false) to a AuthTokensPostRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for GenericError {
+impl std::str::FromStr for AuthTokensPostRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -729,8 +699,8 @@ impl std::str::FromStr for GenericError {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub message: Vec<String>,
-            pub code: Vec<i32>,
+            pub name: Vec<String>,
+            pub role: Vec<models::Role>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -744,7 +714,7 @@ impl std::str::FromStr for GenericError {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing GenericError".to_string(),
+                        "Missing value while parsing AuthTokensPostRequest".to_string(),
                     )
                 }
             };
@@ -753,16 +723,17 @@ impl std::str::FromStr for GenericError {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "role" => intermediate_rep.role.push(
+                        <models::Role as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing GenericError".to_string(),
+                            "Unexpected key while parsing AuthTokensPostRequest".to_string(),
                         )
                     }
                 }
@@ -773,27 +744,35 @@ impl std::str::FromStr for GenericError {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(GenericError {
-            message: intermediate_rep.message.into_iter().next(),
-            code: intermediate_rep.code.into_iter().next(),
+        std::result::Result::Ok(AuthTokensPostRequest {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in AuthTokensPostRequest".to_string())?,
+            role: intermediate_rep
+                .role
+                .into_iter()
+                .next()
+                .ok_or_else(|| "role missing in AuthTokensPostRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<GenericError> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<AuthTokensPostRequest> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<GenericError>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AuthTokensPostRequest>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<GenericError>,
+        hdr_value: header::IntoHeaderValue<AuthTokensPostRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for GenericError - value: {} is invalid {}",
+                "Invalid header value for AuthTokensPostRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -801,18 +780,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<GenericError>> for HeaderValu
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<GenericError> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AuthTokensPostRequest> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <GenericError as std::str::FromStr>::from_str(value) {
+                match <AuthTokensPostRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into GenericError - {}",
+                        "Unable to convert header value '{}' into AuthTokensPostRequest - {}",
                         value, err
                     )),
                 }
@@ -827,63 +806,31 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<GenericError
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelineGet200Response {
-    #[serde(rename = "status")]
-    pub status: models::PipelineStatus,
-
-    /// Milliseconds since epoch when the pipeline was manually started
-    #[serde(rename = "last_start")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_start: Option<i64>,
-
-    /// Milliseconds since epoch when the pipeline was manually stopped
-    #[serde(rename = "last_stop")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_stop: Option<i64>,
-
-    /// Milliseconds since epoch when the pipeline was automatically restarted (on process faults)
-    #[serde(rename = "last_restart")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_restart: Option<i64>,
+pub struct ConfigurationOverride {
+    /// The name of the configuration value
+    #[serde(rename = "name")]
+    pub name: String,
 
-    /// The list of fully qualified services that are enabled in this pipeline. If the pipeline was started, this includes a process for each service
-    #[serde(rename = "enabled")]
-    pub enabled: Vec<models::PipelineGet200ResponseEnabledInner>,
+    #[serde(rename = "value")]
+    pub value: models::ConfigurationOverrideValue,
 }
 
-impl PipelineGet200Response {
+impl ConfigurationOverride {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        status: models::PipelineStatus,
-        enabled: Vec<models::PipelineGet200ResponseEnabledInner>,
-    ) -> PipelineGet200Response {
-        PipelineGet200Response {
-            status,
-            last_start: None,
-            last_stop: None,
-            last_restart: None,
-            enabled,
-        }
+    pub fn new(name: String, value: models::ConfigurationOverrideValue) -> ConfigurationOverride {
+        ConfigurationOverride { name, value }
     }
 }
 
-/// Converts the PipelineGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ConfigurationOverride value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelineGet200Response {
+impl std::fmt::Display for ConfigurationOverride {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping status in query parameter serialization
-            self.last_start
-                .as_ref()
-                .map(|last_start| ["last_start".to_string(), last_start.to_string()].join(",")),
-            self.last_stop
-                .as_ref()
-                .map(|last_stop| ["last_stop".to_string(), last_stop.to_string()].join(",")),
-            self.last_restart.as_ref().map(|last_restart| {
-                ["last_restart".to_string(), last_restart.to_string()].join(",")
-            }),
-            // Skipping enabled in query parameter serialization
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping value in query parameter serialization
         ];
 
         write!(
@@ -894,10 +841,10 @@ impl std::fmt::Display for PipelineGet200Response {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a ConfigurationOverride value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelineGet200Response {
+impl std::str::FromStr for ConfigurationOverride {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -905,11 +852,8 @@ impl std::str::FromStr for PipelineGet200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub status: Vec<models::PipelineStatus>,
-            pub last_start: Vec<i64>,
-            pub last_stop: Vec<i64>,
-            pub last_restart: Vec<i64>,
-            pub enabled: Vec<Vec<models::PipelineGet200ResponseEnabledInner>>,
+            pub name: Vec<String>,
+            pub value: Vec<models::ConfigurationOverrideValue>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -923,7 +867,7 @@ impl std::str::FromStr for PipelineGet200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelineGet200Response".to_string(),
+                        "Missing value while parsing ConfigurationOverride".to_string(),
                     )
                 }
             };
@@ -932,15 +876,19 @@ impl std::str::FromStr for PipelineGet200Response {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(<models::PipelineStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "last_start" => intermediate_rep.last_start.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "last_stop" => intermediate_rep.last_stop.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
-                    "last_restart" => intermediate_rep.last_restart.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    "enabled" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineGet200Response".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing PipelineGet200Response".to_string())
+                    "value" => intermediate_rep.value.push(
+                        <models::ConfigurationOverrideValue as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ConfigurationOverride".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -949,38 +897,35 @@ impl std::str::FromStr for PipelineGet200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelineGet200Response {
-            status: intermediate_rep
-                .status
+        std::result::Result::Ok(ConfigurationOverride {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in PipelineGet200Response".to_string())?,
-            last_start: intermediate_rep.last_start.into_iter().next(),
-            last_stop: intermediate_rep.last_stop.into_iter().next(),
-            last_restart: intermediate_rep.last_restart.into_iter().next(),
-            enabled: intermediate_rep
-                .enabled
+                .ok_or_else(|| "name missing in ConfigurationOverride".to_string())?,
+            value: intermediate_rep
+                .value
                 .into_iter()
                 .next()
-                .ok_or_else(|| "enabled missing in PipelineGet200Response".to_string())?,
+                .ok_or_else(|| "value missing in ConfigurationOverride".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelineGet200Response> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ConfigurationOverride> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200Response>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ConfigurationOverride>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelineGet200Response>,
+        hdr_value: header::IntoHeaderValue<ConfigurationOverride>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for PipelineGet200Response - value: {} is invalid {}",
+                "Invalid header value for ConfigurationOverride - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -988,18 +933,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200Response>> for
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelineGet200Response> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ConfigurationOverride> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PipelineGet200Response as std::str::FromStr>::from_str(value) {
+                match <ConfigurationOverride as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into PipelineGet200Response - {}",
+                        "Unable to convert header value '{}' into ConfigurationOverride - {}",
                         value, err
                     )),
                 }
@@ -1012,40 +957,68 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelineGet2
     }
 }
 
+/// The new value, of the type declared in the service.yaml
+/// One of:
+/// - String
+/// - f64
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConfigurationOverrideValue(pub Box<serde_json::value::RawValue>);
+
+impl validator::Validate for ConfigurationOverrideValue {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ConfigurationOverrideValue value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ConfigurationOverrideValue {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl PartialEq for ConfigurationOverrideValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.get() == other.0.get()
+    }
+}
+
+/// The services that (indirectly) depend on each other, these are started in their configured order
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelineGet200ResponseEnabledInner {
-    #[serde(rename = "service")]
-    pub service: models::PipelineGet200ResponseEnabledInnerService,
-
-    #[serde(rename = "process")]
+pub struct CycleWarning {
+    #[serde(rename = "services")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub process: Option<models::PipelineGet200ResponseEnabledInnerProcess>,
+    pub services: Option<Vec<String>>,
 }
 
-impl PipelineGet200ResponseEnabledInner {
+impl CycleWarning {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        service: models::PipelineGet200ResponseEnabledInnerService,
-    ) -> PipelineGet200ResponseEnabledInner {
-        PipelineGet200ResponseEnabledInner {
-            service,
-            process: None,
-        }
+    pub fn new() -> CycleWarning {
+        CycleWarning { services: None }
     }
 }
 
-/// Converts the PipelineGet200ResponseEnabledInner value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CycleWarning value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelineGet200ResponseEnabledInner {
+impl std::fmt::Display for CycleWarning {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![
-            // Skipping service in query parameter serialization
-
-            // Skipping process in query parameter serialization
-
-        ];
+        let params: Vec<Option<String>> = vec![self.services.as_ref().map(|services| {
+            [
+                "services".to_string(),
+                services
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ]
+            .join(",")
+        })];
 
         write!(
             f,
@@ -1055,10 +1028,10 @@ impl std::fmt::Display for PipelineGet200ResponseEnabledInner {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200ResponseEnabledInner value
+/// Converts Query Parameters representation (style=form, explode=false) to a CycleWarning value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelineGet200ResponseEnabledInner {
+impl std::str::FromStr for CycleWarning {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1066,8 +1039,7 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInner {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub service: Vec<models::PipelineGet200ResponseEnabledInnerService>,
-            pub process: Vec<models::PipelineGet200ResponseEnabledInnerProcess>,
+            pub services: Vec<Vec<String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1081,8 +1053,7 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInner {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelineGet200ResponseEnabledInner"
-                            .to_string(),
+                        "Missing value while parsing CycleWarning".to_string(),
                     )
                 }
             };
@@ -1090,130 +1061,2793 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInner {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    #[allow(clippy::redundant_clone)]
-                    "service" => intermediate_rep.service.push(<models::PipelineGet200ResponseEnabledInnerService as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "process" => intermediate_rep.process.push(<models::PipelineGet200ResponseEnabledInnerProcess as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing PipelineGet200ResponseEnabledInner".to_string())
-                }
-            }
+                    "services" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in CycleWarning"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing CycleWarning".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CycleWarning {
+            services: intermediate_rep.services.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CycleWarning> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CycleWarning>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<CycleWarning>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for CycleWarning - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CycleWarning> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <CycleWarning as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into CycleWarning - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// The status of the roverd process
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum DaemonStatus {
+    #[serde(rename = "operational")]
+    Operational,
+    #[serde(rename = "recoverable")]
+    Recoverable,
+    #[serde(rename = "unrecoverable")]
+    Unrecoverable,
+}
+
+impl std::fmt::Display for DaemonStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DaemonStatus::Operational => write!(f, "operational"),
+            DaemonStatus::Recoverable => write!(f, "recoverable"),
+            DaemonStatus::Unrecoverable => write!(f, "unrecoverable"),
+        }
+    }
+}
+
+impl std::str::FromStr for DaemonStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "operational" => std::result::Result::Ok(DaemonStatus::Operational),
+            "recoverable" => std::result::Result::Ok(DaemonStatus::Recoverable),
+            "unrecoverable" => std::result::Result::Ok(DaemonStatus::Unrecoverable),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+/// DuplicateServiceError
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DuplicateServiceError(pub String);
+
+impl validator::Validate for DuplicateServiceError {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::convert::From<String> for DuplicateServiceError {
+    fn from(x: String) -> Self {
+        DuplicateServiceError(x)
+    }
+}
+
+impl std::fmt::Display for DuplicateServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl std::str::FromStr for DuplicateServiceError {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(DuplicateServiceError(x.to_string()))
+    }
+}
+
+impl std::convert::From<DuplicateServiceError> for String {
+    fn from(x: DuplicateServiceError) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for DuplicateServiceError {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for DuplicateServiceError {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FetchPost200Response {
+    /// The name of the service
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// The version of the service
+    #[serde(rename = "version")]
+    pub version: String,
+
+    /// The author of the service
+    #[serde(rename = "author")]
+    pub author: String,
+
+    /// Whether the pipeline was invalidated by this service upload
+    #[serde(rename = "invalidated_pipeline")]
+    pub invalidated_pipeline: bool,
+}
+
+impl FetchPost200Response {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        version: String,
+        author: String,
+        invalidated_pipeline: bool,
+    ) -> FetchPost200Response {
+        FetchPost200Response {
+            name,
+            version,
+            author,
+            invalidated_pipeline,
+        }
+    }
+}
+
+/// Converts the FetchPost200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for FetchPost200Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("author".to_string()),
+            Some(self.author.to_string()),
+            Some("invalidated_pipeline".to_string()),
+            Some(self.invalidated_pipeline.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a FetchPost200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for FetchPost200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub version: Vec<String>,
+            pub author: Vec<String>,
+            pub invalidated_pipeline: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing FetchPost200Response".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "author" => intermediate_rep.author.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "invalidated_pipeline" => intermediate_rep.invalidated_pipeline.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing FetchPost200Response".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(FetchPost200Response {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in FetchPost200Response".to_string())?,
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in FetchPost200Response".to_string())?,
+            author: intermediate_rep
+                .author
+                .into_iter()
+                .next()
+                .ok_or_else(|| "author missing in FetchPost200Response".to_string())?,
+            invalidated_pipeline: intermediate_rep
+                .invalidated_pipeline
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "invalidated_pipeline missing in FetchPost200Response".to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<FetchPost200Response> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<FetchPost200Response>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<FetchPost200Response>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for FetchPost200Response - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FetchPost200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <FetchPost200Response as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into FetchPost200Response - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FetchPostRequest {
+    /// Download URL of the service to be downloaded, must include scheme. A detached minisign signature is looked for at the same URL with .minisig appended
+    #[serde(rename = "url")]
+    pub url: String,
+
+    /// Optional SHA-256 digest (hex) of the archive, the service is not installed if the downloaded file does not match it
+    #[serde(rename = "sha256")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+impl FetchPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(url: String) -> FetchPostRequest {
+        FetchPostRequest { url, sha256: None }
+    }
+}
+
+/// Converts the FetchPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for FetchPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("url".to_string()),
+            Some(self.url.to_string()),
+            self.sha256
+                .as_ref()
+                .map(|sha256| ["sha256".to_string(), sha256.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a FetchPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for FetchPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub url: Vec<String>,
+            pub sha256: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing FetchPostRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "url" => intermediate_rep.url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "sha256" => intermediate_rep.sha256.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing FetchPostRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(FetchPostRequest {
+            url: intermediate_rep
+                .url
+                .into_iter()
+                .next()
+                .ok_or_else(|| "url missing in FetchPostRequest".to_string())?,
+            sha256: intermediate_rep.sha256.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<FetchPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<FetchPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<FetchPostRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for FetchPostRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FetchPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <FetchPostRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into FetchPostRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FqnsGet200ResponseInner {
+    #[serde(rename = "author")]
+    pub author: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "version")]
+    pub version: String,
+}
+
+impl FqnsGet200ResponseInner {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(author: String, name: String, version: String) -> FqnsGet200ResponseInner {
+        FqnsGet200ResponseInner {
+            author,
+            name,
+            version,
+        }
+    }
+}
+
+/// Converts the FqnsGet200ResponseInner value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for FqnsGet200ResponseInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("author".to_string()),
+            Some(self.author.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a FqnsGet200ResponseInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for FqnsGet200ResponseInner {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub author: Vec<String>,
+            pub name: Vec<String>,
+            pub version: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing FqnsGet200ResponseInner".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "author" => intermediate_rep.author.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing FqnsGet200ResponseInner".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(FqnsGet200ResponseInner {
+            author: intermediate_rep
+                .author
+                .into_iter()
+                .next()
+                .ok_or_else(|| "author missing in FqnsGet200ResponseInner".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in FqnsGet200ResponseInner".to_string())?,
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in FqnsGet200ResponseInner".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<FqnsGet200ResponseInner> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<FqnsGet200ResponseInner>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<FqnsGet200ResponseInner>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for FqnsGet200ResponseInner - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FqnsGet200ResponseInner> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <FqnsGet200ResponseInner as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into FqnsGet200ResponseInner - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct GenericError {
+    /// A message describing the error
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    /// A code describing the error (this is not an HTTP status code)
+    #[serde(rename = "code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<i32>,
+}
+
+impl GenericError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> GenericError {
+        GenericError {
+            message: None,
+            code: None,
+        }
+    }
+}
+
+/// Converts the GenericError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for GenericError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.message
+                .as_ref()
+                .map(|message| ["message".to_string(), message.to_string()].join(",")),
+            self.code
+                .as_ref()
+                .map(|code| ["code".to_string(), code.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a GenericError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for GenericError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub message: Vec<String>,
+            pub code: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing GenericError".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing GenericError".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(GenericError {
+            message: intermediate_rep.message.into_iter().next(),
+            code: intermediate_rep.code.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<GenericError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<GenericError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<GenericError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for GenericError - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<GenericError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <GenericError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into GenericError - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// InvalidServiceError
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct InvalidServiceError {
+    #[serde(rename = "author")]
+    pub author: String,
+
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "version")]
+    pub version: String,
+
+    /// Why the service can not be used
+    #[serde(rename = "message")]
+    pub message: String,
+}
+
+impl InvalidServiceError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        author: String,
+        name: String,
+        version: String,
+        message: String,
+    ) -> InvalidServiceError {
+        InvalidServiceError {
+            author,
+            name,
+            version,
+            message,
+        }
+    }
+}
+
+/// Converts the InvalidServiceError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for InvalidServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("author".to_string()),
+            Some(self.author.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a InvalidServiceError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for InvalidServiceError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub author: Vec<String>,
+            pub name: Vec<String>,
+            pub version: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing InvalidServiceError".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "author" => intermediate_rep.author.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing InvalidServiceError".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(InvalidServiceError {
+            author: intermediate_rep
+                .author
+                .into_iter()
+                .next()
+                .ok_or_else(|| "author missing in InvalidServiceError".to_string())?,
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in InvalidServiceError".to_string())?,
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in InvalidServiceError".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in InvalidServiceError".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<InvalidServiceError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<InvalidServiceError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<InvalidServiceError>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for InvalidServiceError - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<InvalidServiceError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <InvalidServiceError as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into InvalidServiceError - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineGet200Response {
+    #[serde(rename = "status")]
+    pub status: models::PipelineStatus,
+
+    /// Milliseconds since epoch when the pipeline was manually started
+    #[serde(rename = "last_start")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_start: Option<i64>,
+
+    /// Milliseconds since epoch when the pipeline was manually stopped
+    #[serde(rename = "last_stop")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_stop: Option<i64>,
+
+    /// Milliseconds since epoch when the pipeline was automatically restarted (on process faults)
+    #[serde(rename = "last_restart")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_restart: Option<i64>,
+
+    /// The list of fully qualified services that are enabled in this pipeline. If the pipeline was started, this includes a process for each service
+    #[serde(rename = "enabled")]
+    pub enabled: Vec<models::PipelineGet200ResponseEnabledInner>,
+
+    #[serde(rename = "validation_errors")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_errors: Option<models::PipelineValidationErrors>,
+}
+
+impl PipelineGet200Response {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        status: models::PipelineStatus,
+        enabled: Vec<models::PipelineGet200ResponseEnabledInner>,
+    ) -> PipelineGet200Response {
+        PipelineGet200Response {
+            status,
+            last_start: None,
+            last_stop: None,
+            last_restart: None,
+            enabled,
+            validation_errors: None,
+        }
+    }
+}
+
+/// Converts the PipelineGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelineGet200Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping status in query parameter serialization
+            self.last_start
+                .as_ref()
+                .map(|last_start| ["last_start".to_string(), last_start.to_string()].join(",")),
+            self.last_stop
+                .as_ref()
+                .map(|last_stop| ["last_stop".to_string(), last_stop.to_string()].join(",")),
+            self.last_restart.as_ref().map(|last_restart| {
+                ["last_restart".to_string(), last_restart.to_string()].join(",")
+            }),
+            // Skipping enabled in query parameter serialization
+            // Skipping validation_errors in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineGet200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<models::PipelineStatus>,
+            pub last_start: Vec<i64>,
+            pub last_stop: Vec<i64>,
+            pub last_restart: Vec<i64>,
+            pub enabled: Vec<Vec<models::PipelineGet200ResponseEnabledInner>>,
+            pub validation_errors: Vec<models::PipelineValidationErrors>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelineGet200Response".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<models::PipelineStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "last_start" => intermediate_rep.last_start.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "last_stop" => intermediate_rep.last_stop.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "last_restart" => intermediate_rep.last_restart.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "enabled" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineGet200Response".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "validation_errors" => intermediate_rep.validation_errors.push(<models::PipelineValidationErrors as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PipelineGet200Response".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelineGet200Response {
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in PipelineGet200Response".to_string())?,
+            last_start: intermediate_rep.last_start.into_iter().next(),
+            last_stop: intermediate_rep.last_stop.into_iter().next(),
+            last_restart: intermediate_rep.last_restart.into_iter().next(),
+            enabled: intermediate_rep
+                .enabled
+                .into_iter()
+                .next()
+                .ok_or_else(|| "enabled missing in PipelineGet200Response".to_string())?,
+            validation_errors: intermediate_rep.validation_errors.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelineGet200Response> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200Response>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelineGet200Response>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for PipelineGet200Response - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelineGet200Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PipelineGet200Response as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into PipelineGet200Response - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineGet200ResponseEnabledInner {
+    #[serde(rename = "service")]
+    pub service: models::PipelineGet200ResponseEnabledInnerService,
+
+    #[serde(rename = "process")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process: Option<models::PipelineGet200ResponseEnabledInnerProcess>,
+}
+
+impl PipelineGet200ResponseEnabledInner {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        service: models::PipelineGet200ResponseEnabledInnerService,
+    ) -> PipelineGet200ResponseEnabledInner {
+        PipelineGet200ResponseEnabledInner {
+            service,
+            process: None,
+        }
+    }
+}
+
+/// Converts the PipelineGet200ResponseEnabledInner value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelineGet200ResponseEnabledInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping service in query parameter serialization
+
+            // Skipping process in query parameter serialization
+
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200ResponseEnabledInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineGet200ResponseEnabledInner {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub service: Vec<models::PipelineGet200ResponseEnabledInnerService>,
+            pub process: Vec<models::PipelineGet200ResponseEnabledInnerProcess>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelineGet200ResponseEnabledInner"
+                            .to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "service" => intermediate_rep.service.push(<models::PipelineGet200ResponseEnabledInnerService as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "process" => intermediate_rep.process.push(<models::PipelineGet200ResponseEnabledInnerProcess as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PipelineGet200ResponseEnabledInner".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelineGet200ResponseEnabledInner {
+            service: intermediate_rep.service.into_iter().next().ok_or_else(|| {
+                "service missing in PipelineGet200ResponseEnabledInner".to_string()
+            })?,
+            process: intermediate_rep.process.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelineGet200ResponseEnabledInner> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200ResponseEnabledInner>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelineGet200ResponseEnabledInner>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PipelineGet200ResponseEnabledInner - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<PipelineGet200ResponseEnabledInner>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PipelineGet200ResponseEnabledInner as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PipelineGet200ResponseEnabledInner - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+/// The last process that was started for this service (instantiated from the service). This can be undefined if the pipeline was not started before.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineGet200ResponseEnabledInnerProcess {
+    /// The process ID. Depending on the status, this PID might not exist anymore
+    #[serde(rename = "pid")]
+    pub pid: i32,
+
+    #[serde(rename = "status")]
+    pub status: models::ProcessStatus,
+
+    /// The number of milliseconds the process has been running
+    #[serde(rename = "uptime")]
+    pub uptime: i64,
+
+    /// The amount of memory used by the process in megabytes
+    #[serde(rename = "memory")]
+    pub memory: i32,
+
+    /// The percentage of CPU used by the process
+    #[serde(rename = "cpu")]
+    pub cpu: i32,
+}
+
+impl PipelineGet200ResponseEnabledInnerProcess {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        pid: i32,
+        status: models::ProcessStatus,
+        uptime: i64,
+        memory: i32,
+        cpu: i32,
+    ) -> PipelineGet200ResponseEnabledInnerProcess {
+        PipelineGet200ResponseEnabledInnerProcess {
+            pid,
+            status,
+            uptime,
+            memory,
+            cpu,
+        }
+    }
+}
+
+/// Converts the PipelineGet200ResponseEnabledInnerProcess value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelineGet200ResponseEnabledInnerProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("pid".to_string()),
+            Some(self.pid.to_string()),
+            // Skipping status in query parameter serialization
+            Some("uptime".to_string()),
+            Some(self.uptime.to_string()),
+            Some("memory".to_string()),
+            Some(self.memory.to_string()),
+            Some("cpu".to_string()),
+            Some(self.cpu.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200ResponseEnabledInnerProcess value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineGet200ResponseEnabledInnerProcess {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub pid: Vec<i32>,
+            pub status: Vec<models::ProcessStatus>,
+            pub uptime: Vec<i64>,
+            pub memory: Vec<i32>,
+            pub cpu: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelineGet200ResponseEnabledInnerProcess"
+                            .to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "pid" => intermediate_rep.pid.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <models::ProcessStatus as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "uptime" => intermediate_rep.uptime.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "memory" => intermediate_rep.memory.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "cpu" => intermediate_rep.cpu.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => return std::result::Result::Err(
+                        "Unexpected key while parsing PipelineGet200ResponseEnabledInnerProcess"
+                            .to_string(),
+                    ),
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelineGet200ResponseEnabledInnerProcess {
+            pid: intermediate_rep.pid.into_iter().next().ok_or_else(|| {
+                "pid missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
+            })?,
+            status: intermediate_rep.status.into_iter().next().ok_or_else(|| {
+                "status missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
+            })?,
+            uptime: intermediate_rep.uptime.into_iter().next().ok_or_else(|| {
+                "uptime missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
+            })?,
+            memory: intermediate_rep.memory.into_iter().next().ok_or_else(|| {
+                "memory missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
+            })?,
+            cpu: intermediate_rep.cpu.into_iter().next().ok_or_else(|| {
+                "cpu missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
+            })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PipelineGet200ResponseEnabledInnerProcess - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PipelineGet200ResponseEnabledInnerProcess as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PipelineGet200ResponseEnabledInnerProcess - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+/// The fully qualified service that is enabled
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineGet200ResponseEnabledInnerService {
+    /// The name of the service
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// The version of the service
+    #[serde(rename = "version")]
+    pub version: String,
+
+    /// The author of the service
+    #[serde(rename = "author")]
+    pub author: String,
+
+    /// The number of faults that have occurred (causing the pipeline to restart) since pipeline.last_start
+    #[serde(rename = "faults")]
+    pub faults: i32,
+
+    /// The most recent exit code returned by the process.
+    #[serde(rename = "exit")]
+    pub exit: i32,
+
+    /// Milliseconds since epoch when the service was last restarted on its own (following its restart policy)
+    #[serde(rename = "last_restart")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_restart: Option<i64>,
+}
+
+impl PipelineGet200ResponseEnabledInnerService {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        version: String,
+        author: String,
+        faults: i32,
+        exit: i32,
+    ) -> PipelineGet200ResponseEnabledInnerService {
+        PipelineGet200ResponseEnabledInnerService {
+            name,
+            version,
+            author,
+            faults,
+            exit,
+            last_restart: None,
+        }
+    }
+}
+
+/// Converts the PipelineGet200ResponseEnabledInnerService value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelineGet200ResponseEnabledInnerService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("author".to_string()),
+            Some(self.author.to_string()),
+            Some("faults".to_string()),
+            Some(self.faults.to_string()),
+            Some("exit".to_string()),
+            Some(self.exit.to_string()),
+            self.last_restart.as_ref().map(|last_restart| {
+                ["last_restart".to_string(), last_restart.to_string()].join(",")
+            }),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200ResponseEnabledInnerService value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineGet200ResponseEnabledInnerService {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub version: Vec<String>,
+            pub author: Vec<String>,
+            pub faults: Vec<i32>,
+            pub exit: Vec<i32>,
+            pub last_restart: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelineGet200ResponseEnabledInnerService"
+                            .to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "author" => intermediate_rep.author.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "faults" => intermediate_rep.faults.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "exit" => intermediate_rep.exit.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last_restart" => intermediate_rep.last_restart.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => return std::result::Result::Err(
+                        "Unexpected key while parsing PipelineGet200ResponseEnabledInnerService"
+                            .to_string(),
+                    ),
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelineGet200ResponseEnabledInnerService {
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| {
+                "name missing in PipelineGet200ResponseEnabledInnerService".to_string()
+            })?,
+            version: intermediate_rep.version.into_iter().next().ok_or_else(|| {
+                "version missing in PipelineGet200ResponseEnabledInnerService".to_string()
+            })?,
+            author: intermediate_rep.author.into_iter().next().ok_or_else(|| {
+                "author missing in PipelineGet200ResponseEnabledInnerService".to_string()
+            })?,
+            faults: intermediate_rep.faults.into_iter().next().ok_or_else(|| {
+                "faults missing in PipelineGet200ResponseEnabledInnerService".to_string()
+            })?,
+            exit: intermediate_rep.exit.into_iter().next().ok_or_else(|| {
+                "exit missing in PipelineGet200ResponseEnabledInnerService".to_string()
+            })?,
+            last_restart: intermediate_rep.last_restart.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PipelineGet200ResponseEnabledInnerService - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PipelineGet200ResponseEnabledInnerService as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PipelineGet200ResponseEnabledInnerService - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelinePost400Response {
+    /// Additional information
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+
+    #[serde(rename = "validation_errors")]
+    pub validation_errors: models::PipelineValidationErrors,
+}
+
+impl PipelinePost400Response {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(validation_errors: models::PipelineValidationErrors) -> PipelinePost400Response {
+        PipelinePost400Response {
+            message: None,
+            validation_errors,
+        }
+    }
+}
+
+/// Converts the PipelinePost400Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelinePost400Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.message
+                .as_ref()
+                .map(|message| ["message".to_string(), message.to_string()].join(",")),
+            // Skipping validation_errors in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePost400Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelinePost400Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub message: Vec<String>,
+            pub validation_errors: Vec<models::PipelineValidationErrors>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelinePost400Response".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "validation_errors" => intermediate_rep.validation_errors.push(
+                        <models::PipelineValidationErrors as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing PipelinePost400Response".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelinePost400Response {
+            message: intermediate_rep.message.into_iter().next(),
+            validation_errors: intermediate_rep
+                .validation_errors
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "validation_errors missing in PipelinePost400Response".to_string()
+                })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelinePost400Response> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePost400Response>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelinePost400Response>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for PipelinePost400Response - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelinePost400Response> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PipelinePost400Response as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into PipelinePost400Response - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelinePostRequestInner {
+    /// The name of the service
+    #[serde(rename = "name")]
+    pub name: String,
+
+    /// The version of the service
+    #[serde(rename = "version")]
+    pub version: String,
+
+    /// The author of the service
+    #[serde(rename = "author")]
+    pub author: String,
+
+    /// Configuration values that replace the values from the service.yaml of this service when the pipeline is started
+    #[serde(rename = "configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<Vec<models::PipelinePostRequestInnerConfigurationInner>>,
+}
+
+impl PipelinePostRequestInner {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String, version: String, author: String) -> PipelinePostRequestInner {
+        PipelinePostRequestInner {
+            name,
+            version,
+            author,
+            configuration: None,
+        }
+    }
+}
+
+/// Converts the PipelinePostRequestInner value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelinePostRequestInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("author".to_string()),
+            Some(self.author.to_string()),
+            // Skipping configuration in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePostRequestInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelinePostRequestInner {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub version: Vec<String>,
+            pub author: Vec<String>,
+            pub configuration: Vec<Vec<models::PipelinePostRequestInnerConfigurationInner>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelinePostRequestInner".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "author" => intermediate_rep.author.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "configuration" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelinePostRequestInner".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PipelinePostRequestInner".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelinePostRequestInner {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in PipelinePostRequestInner".to_string())?,
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in PipelinePostRequestInner".to_string())?,
+            author: intermediate_rep
+                .author
+                .into_iter()
+                .next()
+                .ok_or_else(|| "author missing in PipelinePostRequestInner".to_string())?,
+            configuration: intermediate_rep.configuration.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelinePostRequestInner> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePostRequestInner>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelinePostRequestInner>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for PipelinePostRequestInner - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelinePostRequestInner> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <PipelinePostRequestInner as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into PipelinePostRequestInner - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelinePostRequestInnerConfigurationInner {
+    /// The name of the configuration value
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "value")]
+    pub value: models::PipelinePostRequestInnerConfigurationInnerValue,
+}
+
+impl PipelinePostRequestInnerConfigurationInner {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        value: models::PipelinePostRequestInnerConfigurationInnerValue,
+    ) -> PipelinePostRequestInnerConfigurationInner {
+        PipelinePostRequestInnerConfigurationInner { name, value }
+    }
+}
+
+/// Converts the PipelinePostRequestInnerConfigurationInner value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelinePostRequestInnerConfigurationInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping value in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePostRequestInnerConfigurationInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelinePostRequestInnerConfigurationInner {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub value: Vec<models::PipelinePostRequestInnerConfigurationInnerValue>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val =
+                match string_iter.next() {
+                    Some(x) => x,
+                    None => return std::result::Result::Err(
+                        "Missing value while parsing PipelinePostRequestInnerConfigurationInner"
+                            .to_string(),
+                    ),
+                };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "value" => intermediate_rep.value.push(<models::PipelinePostRequestInnerConfigurationInnerValue as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PipelinePostRequestInnerConfigurationInner".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelinePostRequestInnerConfigurationInner {
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| {
+                "name missing in PipelinePostRequestInnerConfigurationInner".to_string()
+            })?,
+            value: intermediate_rep.value.into_iter().next().ok_or_else(|| {
+                "value missing in PipelinePostRequestInnerConfigurationInner".to_string()
+            })?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelinePostRequestInnerConfigurationInner> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePostRequestInnerConfigurationInner>>
+    for HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelinePostRequestInnerConfigurationInner>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PipelinePostRequestInnerConfigurationInner - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<PipelinePostRequestInnerConfigurationInner>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PipelinePostRequestInnerConfigurationInner as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PipelinePostRequestInnerConfigurationInner - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+/// The new value, of the type declared in the service.yaml
+/// One of:
+/// - String
+/// - f64
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PipelinePostRequestInnerConfigurationInnerValue(pub Box<serde_json::value::RawValue>);
+
+impl validator::Validate for PipelinePostRequestInnerConfigurationInnerValue {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePostRequestInnerConfigurationInnerValue value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelinePostRequestInnerConfigurationInnerValue {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl PartialEq for PipelinePostRequestInnerConfigurationInnerValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.get() == other.0.get()
+    }
+}
+
+/// The status of the entire pipeline corresponding to a state machine
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum PipelineStatus {
+    #[serde(rename = "empty")]
+    Empty,
+    #[serde(rename = "startable")]
+    Startable,
+    #[serde(rename = "started")]
+    Started,
+    #[serde(rename = "invalid")]
+    Invalid,
+}
+
+impl std::fmt::Display for PipelineStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PipelineStatus::Empty => write!(f, "empty"),
+            PipelineStatus::Startable => write!(f, "startable"),
+            PipelineStatus::Started => write!(f, "started"),
+            PipelineStatus::Invalid => write!(f, "invalid"),
+        }
+    }
+}
+
+impl std::str::FromStr for PipelineStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "empty" => std::result::Result::Ok(PipelineStatus::Empty),
+            "startable" => std::result::Result::Ok(PipelineStatus::Startable),
+            "started" => std::result::Result::Ok(PipelineStatus::Started),
+            "invalid" => std::result::Result::Ok(PipelineStatus::Invalid),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineTuningPostRequestInner {
+    /// The name of the configuration value
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "value")]
+    pub value: models::PipelineTuningPostRequestInnerValue,
+}
+
+impl PipelineTuningPostRequestInner {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        name: String,
+        value: models::PipelineTuningPostRequestInnerValue,
+    ) -> PipelineTuningPostRequestInner {
+        PipelineTuningPostRequestInner { name, value }
+    }
+}
+
+/// Converts the PipelineTuningPostRequestInner value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelineTuningPostRequestInner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping value in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineTuningPostRequestInner value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineTuningPostRequestInner {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub value: Vec<models::PipelineTuningPostRequestInnerValue>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelineTuningPostRequestInner".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "value" => intermediate_rep.value.push(<models::PipelineTuningPostRequestInnerValue as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PipelineTuningPostRequestInner".to_string())
+                }
+            }
 
             // Get the next key
             key_result = string_iter.next();
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelineGet200ResponseEnabledInner {
-            service: intermediate_rep.service.into_iter().next().ok_or_else(|| {
-                "service missing in PipelineGet200ResponseEnabledInner".to_string()
-            })?,
-            process: intermediate_rep.process.into_iter().next(),
+        std::result::Result::Ok(PipelineTuningPostRequestInner {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in PipelineTuningPostRequestInner".to_string())?,
+            value: intermediate_rep
+                .value
+                .into_iter()
+                .next()
+                .ok_or_else(|| "value missing in PipelineTuningPostRequestInner".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelineGet200ResponseEnabledInner> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<PipelineTuningPostRequestInner> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200ResponseEnabledInner>>
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelineTuningPostRequestInner>>
     for HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelineGet200ResponseEnabledInner>,
+        hdr_value: header::IntoHeaderValue<PipelineTuningPostRequestInner>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
-             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for PipelineTuningPostRequestInner - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<PipelineTuningPostRequestInner>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PipelineTuningPostRequestInner as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PipelineTuningPostRequestInner - {}",
+                                value, err))
+                    }
+             },
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for PipelineGet200ResponseEnabledInner - value: {} is invalid {}",
+                 format!("Unable to convert header: {:?} to string: {}",
                      hdr_value, e))
         }
     }
 }
 
+/// The new value, of the type declared for this configuration value
+/// One of:
+/// - String
+/// - f64
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PipelineTuningPostRequestInnerValue(pub Box<serde_json::value::RawValue>);
+
+impl validator::Validate for PipelineTuningPostRequestInnerValue {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineTuningPostRequestInnerValue value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineTuningPostRequestInnerValue {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+impl PartialEq for PipelineTuningPostRequestInnerValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.get() == other.0.get()
+    }
+}
+
+/// The validation errors that prevent the pipeline from being set or started
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PipelineValidationErrors {
+    /// Enabled services whose service.yaml is missing or invalid
+    #[serde(rename = "invalid_services")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid_services: Option<Vec<models::InvalidServiceError>>,
+
+    #[serde(rename = "unmet_streams")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmet_streams: Option<Vec<models::UnmetStreamError>>,
+
+    #[serde(rename = "unmet_services")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unmet_services: Option<Vec<models::UnmetServiceError>>,
+
+    #[serde(rename = "duplicate_service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_service: Option<Vec<models::DuplicateServiceError>>,
+
+    /// Warnings, these do not prevent the pipeline from being set on their own
+    #[serde(rename = "cycles")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycles: Option<Vec<models::CycleWarning>>,
+
+    #[serde(rename = "self_loops")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_loops: Option<Vec<models::SelfLoopError>>,
+
+    /// Warnings, these do not prevent the pipeline from being set on their own
+    #[serde(rename = "unused_outputs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unused_outputs: Option<Vec<models::UnusedOutputWarning>>,
+
+    /// Warnings, these do not prevent the pipeline from being set on their own
+    #[serde(rename = "unreachable_services")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unreachable_services: Option<Vec<models::UnreachableServiceWarning>>,
+}
+
+impl PipelineValidationErrors {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> PipelineValidationErrors {
+        PipelineValidationErrors {
+            invalid_services: None,
+            unmet_streams: None,
+            unmet_services: None,
+            duplicate_service: None,
+            cycles: None,
+            self_loops: None,
+            unused_outputs: None,
+            unreachable_services: None,
+        }
+    }
+}
+
+/// Converts the PipelineValidationErrors value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PipelineValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping invalid_services in query parameter serialization
+            // Skipping unmet_streams in query parameter serialization
+            // Skipping unmet_services in query parameter serialization
+            // Skipping duplicate_service in query parameter serialization
+            // Skipping cycles in query parameter serialization
+            // Skipping self_loops in query parameter serialization
+            // Skipping unused_outputs in query parameter serialization
+            // Skipping unreachable_services in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PipelineValidationErrors value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PipelineValidationErrors {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub invalid_services: Vec<Vec<models::InvalidServiceError>>,
+            pub unmet_streams: Vec<Vec<models::UnmetStreamError>>,
+            pub unmet_services: Vec<Vec<models::UnmetServiceError>>,
+            pub duplicate_service: Vec<Vec<models::DuplicateServiceError>>,
+            pub cycles: Vec<Vec<models::CycleWarning>>,
+            pub self_loops: Vec<Vec<models::SelfLoopError>>,
+            pub unused_outputs: Vec<Vec<models::UnusedOutputWarning>>,
+            pub unreachable_services: Vec<Vec<models::UnreachableServiceWarning>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing PipelineValidationErrors".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "invalid_services" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "unmet_streams" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "unmet_services" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "duplicate_service" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "cycles" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "self_loops" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "unused_outputs" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    "unreachable_services" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelineValidationErrors".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PipelineValidationErrors".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PipelineValidationErrors {
+            invalid_services: intermediate_rep.invalid_services.into_iter().next(),
+            unmet_streams: intermediate_rep.unmet_streams.into_iter().next(),
+            unmet_services: intermediate_rep.unmet_services.into_iter().next(),
+            duplicate_service: intermediate_rep.duplicate_service.into_iter().next(),
+            cycles: intermediate_rep.cycles.into_iter().next(),
+            self_loops: intermediate_rep.self_loops.into_iter().next(),
+            unused_outputs: intermediate_rep.unused_outputs.into_iter().next(),
+            unreachable_services: intermediate_rep.unreachable_services.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PipelineValidationErrors> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PipelineValidationErrors>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<PipelineValidationErrors>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for PipelineValidationErrors - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<PipelineGet200ResponseEnabledInner>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelineValidationErrors> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-             std::result::Result::Ok(value) => {
-                    match <PipelineGet200ResponseEnabledInner as std::str::FromStr>::from_str(value) {
-                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
-                        std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into PipelineGet200ResponseEnabledInner - {}",
-                                value, err))
+            std::result::Result::Ok(value) => {
+                match <PipelineValidationErrors as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
-             },
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Unable to convert header: {:?} to string: {}",
-                     hdr_value, e))
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into PipelineValidationErrors - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
-/// The last process that was started for this service (instantiated from the service). This can be undefined if the pipeline was not started before.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelineGet200ResponseEnabledInnerProcess {
-    /// The process ID. Depending on the status, this PID might not exist anymore
-    #[serde(rename = "pid")]
-    pub pid: i32,
+/// The status of a process in the pipeline
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ProcessStatus {
+    #[serde(rename = "running")]
+    Running,
+    #[serde(rename = "stopped")]
+    Stopped,
+    #[serde(rename = "terminated")]
+    Terminated,
+    #[serde(rename = "killed")]
+    Killed,
+}
 
-    #[serde(rename = "status")]
-    pub status: models::ProcessStatus,
+impl std::fmt::Display for ProcessStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ProcessStatus::Running => write!(f, "running"),
+            ProcessStatus::Stopped => write!(f, "stopped"),
+            ProcessStatus::Terminated => write!(f, "terminated"),
+            ProcessStatus::Killed => write!(f, "killed"),
+        }
+    }
+}
 
-    /// The number of milliseconds the process has been running
-    #[serde(rename = "uptime")]
-    pub uptime: i64,
+impl std::str::FromStr for ProcessStatus {
+    type Err = String;
 
-    /// The amount of memory used by the process in megabytes
-    #[serde(rename = "memory")]
-    pub memory: i32,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "running" => std::result::Result::Ok(ProcessStatus::Running),
+            "stopped" => std::result::Result::Ok(ProcessStatus::Stopped),
+            "terminated" => std::result::Result::Ok(ProcessStatus::Terminated),
+            "killed" => std::result::Result::Ok(ProcessStatus::Killed),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
 
-    /// The percentage of CPU used by the process
-    #[serde(rename = "cpu")]
-    pub cpu: i32,
+/// A named pipeline stored on the rover
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Profile {
+    /// The name of the profile
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "services")]
+    pub services: Vec<models::ProfileService>,
 }
 
-impl PipelineGet200ResponseEnabledInnerProcess {
+impl Profile {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        pid: i32,
-        status: models::ProcessStatus,
-        uptime: i64,
-        memory: i32,
-        cpu: i32,
-    ) -> PipelineGet200ResponseEnabledInnerProcess {
-        PipelineGet200ResponseEnabledInnerProcess {
-            pid,
-            status,
-            uptime,
-            memory,
-            cpu,
-        }
+    pub fn new(name: String, services: Vec<models::ProfileService>) -> Profile {
+        Profile { name, services }
     }
 }
 
-/// Converts the PipelineGet200ResponseEnabledInnerProcess value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Profile value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelineGet200ResponseEnabledInnerProcess {
+impl std::fmt::Display for Profile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("pid".to_string()),
-            Some(self.pid.to_string()),
-            // Skipping status in query parameter serialization
-            Some("uptime".to_string()),
-            Some(self.uptime.to_string()),
-            Some("memory".to_string()),
-            Some(self.memory.to_string()),
-            Some("cpu".to_string()),
-            Some(self.cpu.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping services in query parameter serialization
         ];
 
         write!(
@@ -1224,10 +3858,10 @@ impl std::fmt::Display for PipelineGet200ResponseEnabledInnerProcess {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200ResponseEnabledInnerProcess value
+/// Converts Query Parameters representation (style=form, explode=false) to a Profile value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelineGet200ResponseEnabledInnerProcess {
+impl std::str::FromStr for Profile {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1235,11 +3869,8 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerProcess {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub pid: Vec<i32>,
-            pub status: Vec<models::ProcessStatus>,
-            pub uptime: Vec<i64>,
-            pub memory: Vec<i32>,
-            pub cpu: Vec<i32>,
+            pub name: Vec<String>,
+            pub services: Vec<Vec<models::ProfileService>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1253,8 +3884,7 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerProcess {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelineGet200ResponseEnabledInnerProcess"
-                            .to_string(),
+                        "Missing value while parsing Profile".to_string(),
                     )
                 }
             };
@@ -1263,30 +3893,20 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerProcess {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "pid" => intermediate_rep.pid.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
-                        <models::ProcessStatus as std::str::FromStr>::from_str(val)
-                            .map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "uptime" => intermediate_rep.uptime.push(
-                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "memory" => intermediate_rep.memory.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "cpu" => intermediate_rep.cpu.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => return std::result::Result::Err(
-                        "Unexpected key while parsing PipelineGet200ResponseEnabledInnerProcess"
-                            .to_string(),
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "services" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Profile"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Profile".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -1295,74 +3915,70 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerProcess {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelineGet200ResponseEnabledInnerProcess {
-            pid: intermediate_rep.pid.into_iter().next().ok_or_else(|| {
-                "pid missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
-            })?,
-            status: intermediate_rep.status.into_iter().next().ok_or_else(|| {
-                "status missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
-            })?,
-            uptime: intermediate_rep.uptime.into_iter().next().ok_or_else(|| {
-                "uptime missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
-            })?,
-            memory: intermediate_rep.memory.into_iter().next().ok_or_else(|| {
-                "memory missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
-            })?,
-            cpu: intermediate_rep.cpu.into_iter().next().ok_or_else(|| {
-                "cpu missing in PipelineGet200ResponseEnabledInnerProcess".to_string()
-            })?,
+        std::result::Result::Ok(Profile {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in Profile".to_string())?,
+            services: intermediate_rep
+                .services
+                .into_iter()
+                .next()
+                .ok_or_else(|| "services missing in Profile".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<Profile> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<Profile>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess>,
+        hdr_value: header::IntoHeaderValue<Profile>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
-             std::result::Result::Ok(value) => std::result::Result::Ok(value),
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for PipelineGet200ResponseEnabledInnerProcess - value: {} is invalid {}",
-                     hdr_value, e))
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Profile - value: {} is invalid {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerProcess>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Profile> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-             std::result::Result::Ok(value) => {
-                    match <PipelineGet200ResponseEnabledInnerProcess as std::str::FromStr>::from_str(value) {
-                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
-                        std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into PipelineGet200ResponseEnabledInnerProcess - {}",
-                                value, err))
+            std::result::Result::Ok(value) => {
+                match <Profile as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
-             },
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Unable to convert header: {:?} to string: {}",
-                     hdr_value, e))
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Profile - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
-/// The fully qualified service that is enabled
+/// A service that is enabled in a profile
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelineGet200ResponseEnabledInnerService {
+pub struct ProfileService {
     /// The name of the service
     #[serde(rename = "name")]
     pub name: String,
@@ -1375,38 +3991,28 @@ pub struct PipelineGet200ResponseEnabledInnerService {
     #[serde(rename = "author")]
     pub author: String,
 
-    /// The number of faults that have occurred (causing the pipeline to restart) since pipeline.last_start
-    #[serde(rename = "faults")]
-    pub faults: i32,
-
-    /// The most recent exit code returned by the process.
-    #[serde(rename = "exit")]
-    pub exit: i32,
+    /// Configuration values that replace the values from the service.yaml of this service
+    #[serde(rename = "configuration")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<Vec<models::ConfigurationOverride>>,
 }
 
-impl PipelineGet200ResponseEnabledInnerService {
+impl ProfileService {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        name: String,
-        version: String,
-        author: String,
-        faults: i32,
-        exit: i32,
-    ) -> PipelineGet200ResponseEnabledInnerService {
-        PipelineGet200ResponseEnabledInnerService {
+    pub fn new(name: String, version: String, author: String) -> ProfileService {
+        ProfileService {
             name,
             version,
             author,
-            faults,
-            exit,
+            configuration: None,
         }
     }
 }
 
-/// Converts the PipelineGet200ResponseEnabledInnerService value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ProfileService value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelineGet200ResponseEnabledInnerService {
+impl std::fmt::Display for ProfileService {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
             Some("name".to_string()),
@@ -1415,10 +4021,7 @@ impl std::fmt::Display for PipelineGet200ResponseEnabledInnerService {
             Some(self.version.to_string()),
             Some("author".to_string()),
             Some(self.author.to_string()),
-            Some("faults".to_string()),
-            Some(self.faults.to_string()),
-            Some("exit".to_string()),
-            Some(self.exit.to_string()),
+            // Skipping configuration in query parameter serialization
         ];
 
         write!(
@@ -1429,10 +4032,10 @@ impl std::fmt::Display for PipelineGet200ResponseEnabledInnerService {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelineGet200ResponseEnabledInnerService value
+/// Converts Query Parameters representation (style=form, explode=false) to a ProfileService value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelineGet200ResponseEnabledInnerService {
+impl std::str::FromStr for ProfileService {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1443,8 +4046,7 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerService {
             pub name: Vec<String>,
             pub version: Vec<String>,
             pub author: Vec<String>,
-            pub faults: Vec<i32>,
-            pub exit: Vec<i32>,
+            pub configuration: Vec<Vec<models::ConfigurationOverride>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1458,8 +4060,7 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerService {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelineGet200ResponseEnabledInnerService"
-                            .to_string(),
+                        "Missing value while parsing ProfileService".to_string(),
                     )
                 }
             };
@@ -1479,18 +4080,17 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerService {
                     "author" => intermediate_rep.author.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
-                    #[allow(clippy::redundant_clone)]
-                    "faults" => intermediate_rep.faults.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "exit" => intermediate_rep.exit.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => return std::result::Result::Err(
-                        "Unexpected key while parsing PipelineGet200ResponseEnabledInnerService"
-                            .to_string(),
-                    ),
+                    "configuration" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in ProfileService"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ProfileService".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -1499,105 +4099,97 @@ impl std::str::FromStr for PipelineGet200ResponseEnabledInnerService {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelineGet200ResponseEnabledInnerService {
-            name: intermediate_rep.name.into_iter().next().ok_or_else(|| {
-                "name missing in PipelineGet200ResponseEnabledInnerService".to_string()
-            })?,
-            version: intermediate_rep.version.into_iter().next().ok_or_else(|| {
-                "version missing in PipelineGet200ResponseEnabledInnerService".to_string()
-            })?,
-            author: intermediate_rep.author.into_iter().next().ok_or_else(|| {
-                "author missing in PipelineGet200ResponseEnabledInnerService".to_string()
-            })?,
-            faults: intermediate_rep.faults.into_iter().next().ok_or_else(|| {
-                "faults missing in PipelineGet200ResponseEnabledInnerService".to_string()
-            })?,
-            exit: intermediate_rep.exit.into_iter().next().ok_or_else(|| {
-                "exit missing in PipelineGet200ResponseEnabledInnerService".to_string()
-            })?,
+        std::result::Result::Ok(ProfileService {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in ProfileService".to_string())?,
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in ProfileService".to_string())?,
+            author: intermediate_rep
+                .author
+                .into_iter()
+                .next()
+                .ok_or_else(|| "author missing in ProfileService".to_string())?,
+            configuration: intermediate_rep.configuration.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ProfileService> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<ProfileService>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService>,
+        hdr_value: header::IntoHeaderValue<ProfileService>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
-             std::result::Result::Ok(value) => std::result::Result::Ok(value),
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for PipelineGet200ResponseEnabledInnerService - value: {} is invalid {}",
-                     hdr_value, e))
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ProfileService - value: {} is invalid {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<PipelineGet200ResponseEnabledInnerService>
-{
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProfileService> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-             std::result::Result::Ok(value) => {
-                    match <PipelineGet200ResponseEnabledInnerService as std::str::FromStr>::from_str(value) {
-                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
-                        std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into PipelineGet200ResponseEnabledInnerService - {}",
-                                value, err))
+            std::result::Result::Ok(value) => {
+                match <ProfileService as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
-             },
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Unable to convert header: {:?} to string: {}",
-                     hdr_value, e))
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ProfileService - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelinePost400Response {
-    /// Additional information
-    #[serde(rename = "message")]
+pub struct ReferencedService {
+    /// Fully qualified download url.
+    #[serde(rename = "url")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
-
-    #[serde(rename = "validation_errors")]
-    pub validation_errors: models::PipelinePost400ResponseValidationErrors,
+    pub url: Option<String>,
 }
 
-impl PipelinePost400Response {
+impl ReferencedService {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(
-        validation_errors: models::PipelinePost400ResponseValidationErrors,
-    ) -> PipelinePost400Response {
-        PipelinePost400Response {
-            message: None,
-            validation_errors,
-        }
+    pub fn new() -> ReferencedService {
+        ReferencedService { url: None }
     }
 }
 
-/// Converts the PipelinePost400Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ReferencedService value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelinePost400Response {
+impl std::fmt::Display for ReferencedService {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![
-            self.message
-                .as_ref()
-                .map(|message| ["message".to_string(), message.to_string()].join(",")),
-            // Skipping validation_errors in query parameter serialization
-        ];
+        let params: Vec<Option<String>> = vec![self
+            .url
+            .as_ref()
+            .map(|url| ["url".to_string(), url.to_string()].join(","))];
 
         write!(
             f,
@@ -1607,10 +4199,10 @@ impl std::fmt::Display for PipelinePost400Response {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePost400Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a ReferencedService value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelinePost400Response {
+impl std::str::FromStr for ReferencedService {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1618,8 +4210,7 @@ impl std::str::FromStr for PipelinePost400Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub message: Vec<String>,
-            pub validation_errors: Vec<models::PipelinePost400ResponseValidationErrors>,
+            pub url: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1633,7 +4224,7 @@ impl std::str::FromStr for PipelinePost400Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelinePost400Response".to_string(),
+                        "Missing value while parsing ReferencedService".to_string(),
                     )
                 }
             };
@@ -1642,10 +4233,14 @@ impl std::str::FromStr for PipelinePost400Response {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "validation_errors" => intermediate_rep.validation_errors.push(<models::PipelinePost400ResponseValidationErrors as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing PipelinePost400Response".to_string())
+                    "url" => intermediate_rep.url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ReferencedService".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -1654,33 +4249,26 @@ impl std::str::FromStr for PipelinePost400Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelinePost400Response {
-            message: intermediate_rep.message.into_iter().next(),
-            validation_errors: intermediate_rep
-                .validation_errors
-                .into_iter()
-                .next()
-                .ok_or_else(|| {
-                    "validation_errors missing in PipelinePost400Response".to_string()
-                })?,
+        std::result::Result::Ok(ReferencedService {
+            url: intermediate_rep.url.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelinePost400Response> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<ReferencedService> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePost400Response>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ReferencedService>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelinePost400Response>,
+        hdr_value: header::IntoHeaderValue<ReferencedService>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for PipelinePost400Response - value: {} is invalid {}",
+                "Invalid header value for ReferencedService - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1688,18 +4276,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePost400Response>> for
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelinePost400Response> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ReferencedService> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PipelinePost400Response as std::str::FromStr>::from_str(value) {
+                match <ReferencedService as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into PipelinePost400Response - {}",
+                        "Unable to convert header value '{}' into ReferencedService - {}",
                         value, err
                     )),
                 }
@@ -1712,55 +4300,28 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelinePost
     }
 }
 
-/// The validation errors that prevent the pipeline from being set
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelinePost400ResponseValidationErrors {
-    #[serde(rename = "unmet_streams")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmet_streams: Option<Vec<models::UnmetStreamError>>,
-
-    #[serde(rename = "unmet_services")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub unmet_services: Option<Vec<models::UnmetServiceError>>,
-
-    #[serde(rename = "duplicate_service")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub duplicate_service: Option<Vec<models::DuplicateServiceError>>,
+pub struct RenamePostRequest {
+    /// The new name of the rover, consisting of letters, digits, dashes and underscores
+    #[serde(rename = "name")]
+    pub name: String,
 }
 
-impl PipelinePost400ResponseValidationErrors {
+impl RenamePostRequest {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> PipelinePost400ResponseValidationErrors {
-        PipelinePost400ResponseValidationErrors {
-            unmet_streams: None,
-            unmet_services: None,
-            duplicate_service: None,
-        }
+    pub fn new(name: String) -> RenamePostRequest {
+        RenamePostRequest { name }
     }
 }
 
-/// Converts the PipelinePost400ResponseValidationErrors value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RenamePostRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelinePost400ResponseValidationErrors {
+impl std::fmt::Display for RenamePostRequest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![
-            // Skipping unmet_streams in query parameter serialization
-
-            // Skipping unmet_services in query parameter serialization
-            self.duplicate_service.as_ref().map(|duplicate_service| {
-                [
-                    "duplicate_service".to_string(),
-                    duplicate_service
-                        .iter()
-                        .map(|x| x.to_string())
-                        .collect::<Vec<_>>()
-                        .join(","),
-                ]
-                .join(",")
-            }),
-        ];
+        let params: Vec<Option<String>> =
+            vec![Some("name".to_string()), Some(self.name.to_string())];
 
         write!(
             f,
@@ -1770,10 +4331,10 @@ impl std::fmt::Display for PipelinePost400ResponseValidationErrors {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePost400ResponseValidationErrors value
+/// Converts Query Parameters representation (style=form, explode=false) to a RenamePostRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelinePost400ResponseValidationErrors {
+impl std::str::FromStr for RenamePostRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1781,9 +4342,7 @@ impl std::str::FromStr for PipelinePost400ResponseValidationErrors {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub unmet_streams: Vec<Vec<models::UnmetStreamError>>,
-            pub unmet_services: Vec<Vec<models::UnmetServiceError>>,
-            pub duplicate_service: Vec<Vec<models::DuplicateServiceError>>,
+            pub name: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1797,8 +4356,7 @@ impl std::str::FromStr for PipelinePost400ResponseValidationErrors {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelinePost400ResponseValidationErrors"
-                            .to_string(),
+                        "Missing value while parsing RenamePostRequest".to_string(),
                     )
                 }
             };
@@ -1806,10 +4364,15 @@ impl std::str::FromStr for PipelinePost400ResponseValidationErrors {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
-                    "unmet_streams" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelinePost400ResponseValidationErrors".to_string()),
-                    "unmet_services" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelinePost400ResponseValidationErrors".to_string()),
-                    "duplicate_service" => return std::result::Result::Err("Parsing a container in this style is not supported in PipelinePost400ResponseValidationErrors".to_string()),
-                    _ => return std::result::Result::Err("Unexpected key while parsing PipelinePost400ResponseValidationErrors".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing RenamePostRequest".to_string(),
+                        )
+                    }
                 }
             }
 
@@ -1818,97 +4381,155 @@ impl std::str::FromStr for PipelinePost400ResponseValidationErrors {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelinePost400ResponseValidationErrors {
-            unmet_streams: intermediate_rep.unmet_streams.into_iter().next(),
-            unmet_services: intermediate_rep.unmet_services.into_iter().next(),
-            duplicate_service: intermediate_rep.duplicate_service.into_iter().next(),
+        std::result::Result::Ok(RenamePostRequest {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in RenamePostRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelinePost400ResponseValidationErrors> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RenamePostRequest> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePost400ResponseValidationErrors>>
-    for HeaderValue
-{
+impl std::convert::TryFrom<header::IntoHeaderValue<RenamePostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<RenamePostRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for RenamePostRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RenamePostRequest> {
     type Error = String;
 
-    fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelinePost400ResponseValidationErrors>,
-    ) -> std::result::Result<Self, Self::Error> {
-        let hdr_value = hdr_value.to_string();
-        match HeaderValue::from_str(&hdr_value) {
-             std::result::Result::Ok(value) => std::result::Result::Ok(value),
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for PipelinePost400ResponseValidationErrors - value: {} is invalid {}",
-                     hdr_value, e))
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <RenamePostRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RenamePostRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// What an account or API token is allowed to do. A viewer can read everything, an operator can also control the pipeline and an admin can also install and delete services, shut down and update the rover and manage API tokens.
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum Role {
+    #[serde(rename = "viewer")]
+    Viewer,
+    #[serde(rename = "operator")]
+    Operator,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Role::Viewer => write!(f, "viewer"),
+            Role::Operator => write!(f, "operator"),
+            Role::Admin => write!(f, "admin"),
         }
     }
 }
 
-#[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue>
-    for header::IntoHeaderValue<PipelinePost400ResponseValidationErrors>
-{
-    type Error = String;
+impl std::str::FromStr for Role {
+    type Err = String;
 
-    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
-        match hdr_value.to_str() {
-             std::result::Result::Ok(value) => {
-                    match <PipelinePost400ResponseValidationErrors as std::str::FromStr>::from_str(value) {
-                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
-                        std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into PipelinePost400ResponseValidationErrors - {}",
-                                value, err))
-                    }
-             },
-             std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Unable to convert header: {:?} to string: {}",
-                     hdr_value, e))
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "viewer" => std::result::Result::Ok(Role::Viewer),
+            "operator" => std::result::Result::Ok(Role::Operator),
+            "admin" => std::result::Result::Ok(Role::Admin),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PipelinePostRequestInner {
-    /// The name of the service
-    #[serde(rename = "name")]
-    pub name: String,
+pub struct RunsGet200ResponseInner {
+    /// Identifier of the run, increases with every start of the pipeline
+    #[serde(rename = "id")]
+    pub id: i64,
 
-    /// The version of the service
-    #[serde(rename = "version")]
-    pub version: String,
+    /// Milliseconds since epoch when the run was started
+    #[serde(rename = "start")]
+    pub start: i64,
 
-    /// The author of the service
-    #[serde(rename = "author")]
-    pub author: String,
+    /// Milliseconds since epoch when the run ended, not set while it is running
+    #[serde(rename = "stop")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<i64>,
+
+    /// The services that were enabled during this run
+    #[serde(rename = "services")]
+    #[validate(nested)]
+    pub services: Vec<models::RunsGet200ResponseInnerServicesInner>,
 }
 
-impl PipelinePostRequestInner {
+impl RunsGet200ResponseInner {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(name: String, version: String, author: String) -> PipelinePostRequestInner {
-        PipelinePostRequestInner {
-            name,
-            version,
-            author,
+    pub fn new(
+        id: i64,
+        start: i64,
+        services: Vec<models::RunsGet200ResponseInnerServicesInner>,
+    ) -> RunsGet200ResponseInner {
+        RunsGet200ResponseInner {
+            id,
+            start,
+            stop: None,
+            services,
         }
     }
 }
 
-/// Converts the PipelinePostRequestInner value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RunsGet200ResponseInner value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PipelinePostRequestInner {
+impl std::fmt::Display for RunsGet200ResponseInner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-            Some("version".to_string()),
-            Some(self.version.to_string()),
-            Some("author".to_string()),
-            Some(self.author.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("start".to_string()),
+            Some(self.start.to_string()),
+            self.stop
+                .as_ref()
+                .map(|stop| ["stop".to_string(), stop.to_string()].join(",")),
+            // Skipping services in query parameter serialization
         ];
 
         write!(
@@ -1919,10 +4540,10 @@ impl std::fmt::Display for PipelinePostRequestInner {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PipelinePostRequestInner value
+/// Converts Query Parameters representation (style=form, explode=false) to a RunsGet200ResponseInner value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PipelinePostRequestInner {
+impl std::str::FromStr for RunsGet200ResponseInner {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1930,9 +4551,10 @@ impl std::str::FromStr for PipelinePostRequestInner {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub name: Vec<String>,
-            pub version: Vec<String>,
-            pub author: Vec<String>,
+            pub id: Vec<i64>,
+            pub start: Vec<i64>,
+            pub stop: Vec<i64>,
+            pub services: Vec<Vec<models::RunsGet200ResponseInnerServicesInner>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1946,7 +4568,7 @@ impl std::str::FromStr for PipelinePostRequestInner {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing PipelinePostRequestInner".to_string(),
+                        "Missing value while parsing RunsGet200ResponseInner".to_string(),
                     )
                 }
             };
@@ -1955,22 +4577,13 @@ impl std::str::FromStr for PipelinePostRequestInner {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
+                    "start" => intermediate_rep.start.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "author" => intermediate_rep.author.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    _ => {
-                        return std::result::Result::Err(
-                            "Unexpected key while parsing PipelinePostRequestInner".to_string(),
-                        )
-                    }
+                    "stop" => intermediate_rep.stop.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "services" => return std::result::Result::Err("Parsing a container in this style is not supported in RunsGet200ResponseInner".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RunsGet200ResponseInner".to_string())
                 }
             }
 
@@ -1979,40 +4592,41 @@ impl std::str::FromStr for PipelinePostRequestInner {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PipelinePostRequestInner {
-            name: intermediate_rep
-                .name
+        std::result::Result::Ok(RunsGet200ResponseInner {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "name missing in PipelinePostRequestInner".to_string())?,
-            version: intermediate_rep
-                .version
+                .ok_or_else(|| "id missing in RunsGet200ResponseInner".to_string())?,
+            start: intermediate_rep
+                .start
                 .into_iter()
                 .next()
-                .ok_or_else(|| "version missing in PipelinePostRequestInner".to_string())?,
-            author: intermediate_rep
-                .author
+                .ok_or_else(|| "start missing in RunsGet200ResponseInner".to_string())?,
+            stop: intermediate_rep.stop.into_iter().next(),
+            services: intermediate_rep
+                .services
                 .into_iter()
                 .next()
-                .ok_or_else(|| "author missing in PipelinePostRequestInner".to_string())?,
+                .ok_or_else(|| "services missing in RunsGet200ResponseInner".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PipelinePostRequestInner> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RunsGet200ResponseInner> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePostRequestInner>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RunsGet200ResponseInner>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<PipelinePostRequestInner>,
+        hdr_value: header::IntoHeaderValue<RunsGet200ResponseInner>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for PipelinePostRequestInner - value: {} is invalid {}",
+                "Invalid header value for RunsGet200ResponseInner - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -2020,18 +4634,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<PipelinePostRequestInner>> fo
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelinePostRequestInner> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RunsGet200ResponseInner> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <PipelinePostRequestInner as std::str::FromStr>::from_str(value) {
+                match <RunsGet200ResponseInner as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into PipelinePostRequestInner - {}",
+                        "Unable to convert header value '{}' into RunsGet200ResponseInner - {}",
                         value, err
                     )),
                 }
@@ -2044,119 +4658,72 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PipelinePost
     }
 }
 
-/// The status of the entire pipeline corresponding to a state machine
-/// Enumeration of values.
-/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
-/// which helps with FFI.
-#[allow(non_camel_case_types)]
-#[repr(C)]
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
-)]
-#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
-pub enum PipelineStatus {
-    #[serde(rename = "empty")]
-    Empty,
-    #[serde(rename = "startable")]
-    Startable,
-    #[serde(rename = "started")]
-    Started,
-}
-
-impl std::fmt::Display for PipelineStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            PipelineStatus::Empty => write!(f, "empty"),
-            PipelineStatus::Startable => write!(f, "startable"),
-            PipelineStatus::Started => write!(f, "started"),
-        }
-    }
-}
-
-impl std::str::FromStr for PipelineStatus {
-    type Err = String;
-
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "empty" => std::result::Result::Ok(PipelineStatus::Empty),
-            "startable" => std::result::Result::Ok(PipelineStatus::Startable),
-            "started" => std::result::Result::Ok(PipelineStatus::Started),
-            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
-        }
-    }
-}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RunsGet200ResponseInnerServicesInner {
+    #[serde(rename = "author")]
+    pub author: String,
 
-/// The status of a process in the pipeline
-/// Enumeration of values.
-/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
-/// which helps with FFI.
-#[allow(non_camel_case_types)]
-#[repr(C)]
-#[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
-)]
-#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
-pub enum ProcessStatus {
-    #[serde(rename = "running")]
-    Running,
-    #[serde(rename = "stopped")]
-    Stopped,
-    #[serde(rename = "terminated")]
-    Terminated,
-    #[serde(rename = "killed")]
-    Killed,
-}
+    #[serde(rename = "name")]
+    pub name: String,
 
-impl std::fmt::Display for ProcessStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            ProcessStatus::Running => write!(f, "running"),
-            ProcessStatus::Stopped => write!(f, "stopped"),
-            ProcessStatus::Terminated => write!(f, "terminated"),
-            ProcessStatus::Killed => write!(f, "killed"),
-        }
-    }
-}
+    #[serde(rename = "version")]
+    pub version: String,
 
-impl std::str::FromStr for ProcessStatus {
-    type Err = String;
+    /// The last exit code of the process, not set if it did not exit yet
+    #[serde(rename = "exit_code")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "running" => std::result::Result::Ok(ProcessStatus::Running),
-            "stopped" => std::result::Result::Ok(ProcessStatus::Stopped),
-            "terminated" => std::result::Result::Ok(ProcessStatus::Terminated),
-            "killed" => std::result::Result::Ok(ProcessStatus::Killed),
-            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
-        }
-    }
-}
+    /// The number of faults that occurred during this run
+    #[serde(rename = "faults")]
+    pub faults: i32,
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ReferencedService {
-    /// Fully qualified download url.
-    #[serde(rename = "url")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
+    /// The number of times the process was restarted during this run
+    #[serde(rename = "restarts")]
+    pub restarts: i32,
 }
 
-impl ReferencedService {
+impl RunsGet200ResponseInnerServicesInner {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> ReferencedService {
-        ReferencedService { url: None }
+    pub fn new(
+        author: String,
+        name: String,
+        version: String,
+        faults: i32,
+        restarts: i32,
+    ) -> RunsGet200ResponseInnerServicesInner {
+        RunsGet200ResponseInnerServicesInner {
+            author,
+            name,
+            version,
+            exit_code: None,
+            faults,
+            restarts,
+        }
     }
 }
 
-/// Converts the ReferencedService value to the Query Parameters representation (style=form, explode=false)
+/// Converts the RunsGet200ResponseInnerServicesInner value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ReferencedService {
+impl std::fmt::Display for RunsGet200ResponseInnerServicesInner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![self
-            .url
-            .as_ref()
-            .map(|url| ["url".to_string(), url.to_string()].join(","))];
+        let params: Vec<Option<String>> = vec![
+            Some("author".to_string()),
+            Some(self.author.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            self.exit_code
+                .as_ref()
+                .map(|exit_code| ["exit_code".to_string(), exit_code.to_string()].join(",")),
+            Some("faults".to_string()),
+            Some(self.faults.to_string()),
+            Some("restarts".to_string()),
+            Some(self.restarts.to_string()),
+        ];
 
         write!(
             f,
@@ -2166,10 +4733,10 @@ impl std::fmt::Display for ReferencedService {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ReferencedService value
+/// Converts Query Parameters representation (style=form, explode=false) to a RunsGet200ResponseInnerServicesInner value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ReferencedService {
+impl std::str::FromStr for RunsGet200ResponseInnerServicesInner {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -2177,7 +4744,12 @@ impl std::str::FromStr for ReferencedService {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub url: Vec<String>,
+            pub author: Vec<String>,
+            pub name: Vec<String>,
+            pub version: Vec<String>,
+            pub exit_code: Vec<i32>,
+            pub faults: Vec<i32>,
+            pub restarts: Vec<i32>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -2191,7 +4763,8 @@ impl std::str::FromStr for ReferencedService {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ReferencedService".to_string(),
+                        "Missing value while parsing RunsGet200ResponseInnerServicesInner"
+                            .to_string(),
                     )
                 }
             };
@@ -2200,12 +4773,33 @@ impl std::str::FromStr for ReferencedService {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "url" => intermediate_rep.url.push(
+                    "author" => intermediate_rep.author.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "exit_code" => intermediate_rep.exit_code.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "faults" => intermediate_rep.faults.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "restarts" => intermediate_rep.restarts.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ReferencedService".to_string(),
+                            "Unexpected key while parsing RunsGet200ResponseInnerServicesInner"
+                                .to_string(),
                         )
                     }
                 }
@@ -2215,58 +4809,125 @@ impl std::str::FromStr for ReferencedService {
             key_result = string_iter.next();
         }
 
-        // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ReferencedService {
-            url: intermediate_rep.url.into_iter().next(),
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RunsGet200ResponseInnerServicesInner {
+            author: intermediate_rep.author.into_iter().next().ok_or_else(|| {
+                "author missing in RunsGet200ResponseInnerServicesInner".to_string()
+            })?,
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| {
+                "name missing in RunsGet200ResponseInnerServicesInner".to_string()
+            })?,
+            version: intermediate_rep.version.into_iter().next().ok_or_else(|| {
+                "version missing in RunsGet200ResponseInnerServicesInner".to_string()
+            })?,
+            exit_code: intermediate_rep.exit_code.into_iter().next(),
+            faults: intermediate_rep.faults.into_iter().next().ok_or_else(|| {
+                "faults missing in RunsGet200ResponseInnerServicesInner".to_string()
+            })?,
+            restarts: intermediate_rep
+                .restarts
+                .into_iter()
+                .next()
+                .ok_or_else(|| {
+                    "restarts missing in RunsGet200ResponseInnerServicesInner".to_string()
+                })?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ReferencedService> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<RunsGet200ResponseInnerServicesInner> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<ReferencedService>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<RunsGet200ResponseInnerServicesInner>>
+    for HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ReferencedService>,
+        hdr_value: header::IntoHeaderValue<RunsGet200ResponseInnerServicesInner>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
-            std::result::Result::Ok(value) => std::result::Result::Ok(value),
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ReferencedService - value: {} is invalid {}",
-                hdr_value, e
-            )),
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RunsGet200ResponseInnerServicesInner - value: {} is invalid {}",
+                     hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ReferencedService> {
+impl std::convert::TryFrom<HeaderValue>
+    for header::IntoHeaderValue<RunsGet200ResponseInnerServicesInner>
+{
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
-            std::result::Result::Ok(value) => {
-                match <ReferencedService as std::str::FromStr>::from_str(value) {
-                    std::result::Result::Ok(value) => {
-                        std::result::Result::Ok(header::IntoHeaderValue(value))
+             std::result::Result::Ok(value) => {
+                    match <RunsGet200ResponseInnerServicesInner as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RunsGet200ResponseInnerServicesInner - {}",
+                                value, err))
                     }
-                    std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ReferencedService - {}",
-                        value, err
-                    )),
-                }
-            }
-            std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Unable to convert header: {:?} to string: {}",
-                hdr_value, e
-            )),
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
         }
     }
 }
 
+/// The service that takes its own output as input
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SelfLoopError(pub String);
+
+impl validator::Validate for SelfLoopError {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::convert::From<String> for SelfLoopError {
+    fn from(x: String) -> Self {
+        SelfLoopError(x)
+    }
+}
+
+impl std::fmt::Display for SelfLoopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl std::str::FromStr for SelfLoopError {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(SelfLoopError(x.to_string()))
+    }
+}
+
+impl std::convert::From<SelfLoopError> for String {
+    fn from(x: SelfLoopError) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for SelfLoopError {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for SelfLoopError {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
 /// The status of any given service is either enabled or disabled
 /// Enumeration of values.
 /// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
@@ -3166,6 +5827,11 @@ pub struct StatusGet200Response {
     /// The CPU usage of the roverd process
     #[serde(rename = "cpu")]
     pub cpu: Vec<models::StatusGet200ResponseCpuInner>,
+
+    /// The SHA-256 fingerprint of the TLS certificate (hex encoded), only present if the HTTPS listener is enabled
+    #[serde(rename = "tls_fingerprint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_fingerprint: Option<String>,
 }
 
 impl StatusGet200Response {
@@ -3190,6 +5856,7 @@ impl StatusGet200Response {
             rover_name: None,
             memory,
             cpu,
+            tls_fingerprint: None,
         }
     }
 }
@@ -3221,6 +5888,9 @@ impl std::fmt::Display for StatusGet200Response {
             // Skipping memory in query parameter serialization
 
             // Skipping cpu in query parameter serialization
+            self.tls_fingerprint.as_ref().map(|tls_fingerprint| {
+                ["tls_fingerprint".to_string(), tls_fingerprint.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -3252,6 +5922,7 @@ impl std::str::FromStr for StatusGet200Response {
             pub rover_name: Vec<String>,
             pub memory: Vec<models::StatusGet200ResponseMemory>,
             pub cpu: Vec<Vec<models::StatusGet200ResponseCpuInner>>,
+            pub tls_fingerprint: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -3292,6 +5963,8 @@ impl std::str::FromStr for StatusGet200Response {
                     #[allow(clippy::redundant_clone)]
                     "memory" => intermediate_rep.memory.push(<models::StatusGet200ResponseMemory as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     "cpu" => return std::result::Result::Err("Parsing a container in this style is not supported in StatusGet200Response".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "tls_fingerprint" => intermediate_rep.tls_fingerprint.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     _ => return std::result::Result::Err("Unexpected key while parsing StatusGet200Response".to_string())
                 }
             }
@@ -3340,6 +6013,7 @@ impl std::str::FromStr for StatusGet200Response {
                 .into_iter()
                 .next()
                 .ok_or_else(|| "cpu missing in StatusGet200Response".to_string())?,
+            tls_fingerprint: intermediate_rep.tls_fingerprint.into_iter().next(),
         })
     }
 }
@@ -4028,3 +6702,204 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UnmetStreamE
         }
     }
 }
+
+/// The service that is not connected to any other service in the pipeline
+#[derive(Debug, Clone, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UnreachableServiceWarning(pub String);
+
+impl validator::Validate for UnreachableServiceWarning {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::convert::From<String> for UnreachableServiceWarning {
+    fn from(x: String) -> Self {
+        UnreachableServiceWarning(x)
+    }
+}
+
+impl std::fmt::Display for UnreachableServiceWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl std::str::FromStr for UnreachableServiceWarning {
+    type Err = std::string::ParseError;
+    fn from_str(x: &str) -> std::result::Result<Self, Self::Err> {
+        std::result::Result::Ok(UnreachableServiceWarning(x.to_string()))
+    }
+}
+
+impl std::convert::From<UnreachableServiceWarning> for String {
+    fn from(x: UnreachableServiceWarning) -> Self {
+        x.0
+    }
+}
+
+impl std::ops::Deref for UnreachableServiceWarning {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for UnreachableServiceWarning {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.0
+    }
+}
+
+/// An output stream that no service in the pipeline consumes
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UnusedOutputWarning {
+    #[serde(rename = "service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+
+    #[serde(rename = "stream")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+}
+
+impl UnusedOutputWarning {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> UnusedOutputWarning {
+        UnusedOutputWarning {
+            service: None,
+            stream: None,
+        }
+    }
+}
+
+/// Converts the UnusedOutputWarning value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for UnusedOutputWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.service
+                .as_ref()
+                .map(|service| ["service".to_string(), service.to_string()].join(",")),
+            self.stream
+                .as_ref()
+                .map(|stream| ["stream".to_string(), stream.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a UnusedOutputWarning value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for UnusedOutputWarning {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub service: Vec<String>,
+            pub stream: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing UnusedOutputWarning".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "service" => intermediate_rep.service.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "stream" => intermediate_rep.stream.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing UnusedOutputWarning".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(UnusedOutputWarning {
+            service: intermediate_rep.service.into_iter().next(),
+            stream: intermediate_rep.stream.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<UnusedOutputWarning> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<UnusedOutputWarning>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<UnusedOutputWarning>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for UnusedOutputWarning - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UnusedOutputWarning> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <UnusedOutputWarning as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into UnusedOutputWarning - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
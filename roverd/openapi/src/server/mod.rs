@@ -16,11 +16,21 @@ use crate::{apis, models};
 pub fn new<I, A>(api_impl: I) -> Router
 where
     I: AsRef<A> + Clone + Send + Sync + 'static,
-    A: apis::health::Health + apis::pipeline::Pipeline + apis::services::Services + 'static,
+    A: apis::auth::Auth
+        + apis::health::Health
+        + apis::pipeline::Pipeline
+        + apis::services::Services
+        + 'static,
 {
     // build our application with a route
     Router::new()
         .route("/", get(root_get::<I, A>))
+        .route("/auth/password", post(auth_password_post::<I, A>))
+        .route(
+            "/auth/tokens",
+            get(auth_tokens_get::<I, A>).post(auth_tokens_post::<I, A>),
+        )
+        .route("/auth/tokens/:id", delete(auth_tokens_id_delete::<I, A>))
         .route("/fetch", post(fetch_post::<I, A>))
         .route("/fqns", get(fqns_get::<I, A>))
         .route(
@@ -31,8 +41,24 @@ where
             "/pipeline",
             get(pipeline_get::<I, A>).post(pipeline_post::<I, A>),
         )
+        .route("/pipeline/profiles", get(pipeline_profiles_get::<I, A>))
+        .route(
+            "/pipeline/profiles/:name",
+            delete(pipeline_profiles_name_delete::<I, A>).post(pipeline_profiles_name_post::<I, A>),
+        )
+        .route(
+            "/pipeline/profiles/:name/activate",
+            post(pipeline_profiles_name_activate_post::<I, A>),
+        )
         .route("/pipeline/start", post(pipeline_start_post::<I, A>))
         .route("/pipeline/stop", post(pipeline_stop_post::<I, A>))
+        .route("/pipeline/tuning", post(pipeline_tuning_post::<I, A>))
+        .route("/rename", post(rename_post::<I, A>))
+        .route("/runs", get(runs_get::<I, A>))
+        .route(
+            "/runs/:id/logs/:author/:name/:version",
+            get(runs_id_logs_author_name_version_get::<I, A>),
+        )
         .route("/services", get(services_get::<I, A>))
         .route("/services/:author", get(services_author_get::<I, A>))
         .route(
@@ -52,139 +78,61 @@ where
         .with_state(api_impl)
 }
 
-#[tracing::instrument(skip_all)]
-fn root_get_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
-}
-/// RootGet - GET /
-#[tracing::instrument(skip_all)]
-async fn root_get<I, A>(
-    method: Method,
-    host: Host,
-    cookies: CookieJar,
-    State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::health::Health,
-{
-    #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || root_get_validation())
-        .await
-        .unwrap();
-
-    let Ok(()) = validation else {
-        return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-    };
-
-    let result = api_impl.as_ref().root_get(method, host, cookies).await;
-
-    let mut response = Response::builder();
-
-    let resp = match result {
-        Ok(rsp) => match rsp {
-            apis::health::RootGetResponse::Status200_TheHealthAndVersioningInformation(body) => {
-                let mut response = response.status(200);
-                {
-                    let mut response_headers = response.headers_mut().unwrap();
-                    response_headers.insert(
-                        CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
-                            error!(error = ?e);
-                            StatusCode::INTERNAL_SERVER_ERROR
-                        })?,
-                    );
-                }
-
-                let body_content = tokio::task::spawn_blocking(move || {
-                    serde_json::to_vec(&body).map_err(|e| {
-                        error!(error = ?e);
-                        StatusCode::INTERNAL_SERVER_ERROR
-                    })
-                })
-                .await
-                .unwrap()?;
-                response.body(Body::from(body_content))
-            }
-            apis::health::RootGetResponse::Status400_AnErrorOccurred(body) => {
-                let mut response = response.status(400);
-                {
-                    let mut response_headers = response.headers_mut().unwrap();
-                    response_headers.insert(
-                        CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
-                            error!(error = ?e);
-                            StatusCode::INTERNAL_SERVER_ERROR
-                        })?,
-                    );
-                }
-
-                let body_content = tokio::task::spawn_blocking(move || {
-                    serde_json::to_vec(&body).map_err(|e| {
-                        error!(error = ?e);
-                        StatusCode::INTERNAL_SERVER_ERROR
-                    })
-                })
-                .await
-                .unwrap()?;
-                response.body(Body::from(body_content))
-            }
-        },
-        Err(_) => {
-            // Application code returned an error. This should not happen, as the implementation should
-            // return a valid response.
-            response.status(500).body(Body::empty())
-        }
-    };
-
-    resp.map_err(|e| {
-        error!(error = ?e);
-        StatusCode::INTERNAL_SERVER_ERROR
-    })
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct AuthPasswordPostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::AuthPasswordPostRequest,
 }
 
 #[tracing::instrument(skip_all)]
-fn shutdown_post_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn auth_password_post_validation(
+    body: models::AuthPasswordPostRequest,
+) -> std::result::Result<(models::AuthPasswordPostRequest,), ValidationErrors> {
+    let b = AuthPasswordPostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
 }
-/// ShutdownPost - POST /shutdown
+/// AuthPasswordPost - POST /auth/password
 #[tracing::instrument(skip_all)]
-async fn shutdown_post<I, A>(
+async fn auth_password_post<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
     State(api_impl): State<I>,
+    Json(body): Json<models::AuthPasswordPostRequest>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::health::Health,
+    A: apis::auth::Auth,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || shutdown_post_validation())
+    let validation = tokio::task::spawn_blocking(move || auth_password_post_validation(body))
         .await
         .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl.as_ref().shutdown_post(method, host, cookies).await;
+    let result = api_impl
+        .as_ref()
+        .auth_password_post(method, host, cookies, body)
+        .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::health::ShutdownPostResponse::Status200_RoverShutdownSuccessfully => {
+            apis::auth::AuthPasswordPostResponse::Status200_ThePasswordWasChanged => {
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::health::ShutdownPostResponse::Status400_AnErrorOccurred(body) => {
+            apis::auth::AuthPasswordPostResponse::Status400_AnErrorOccurred(body) => {
                 let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -207,10 +155,18 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::health::ShutdownPostResponse::Status401_UnauthorizedAccess => {
+            apis::auth::AuthPasswordPostResponse::Status401_UnauthorizedAccess => {
                 let mut response = response.status(401);
                 response.body(Body::empty())
             }
+            apis::auth::AuthPasswordPostResponse::Status403_OnlyAdminsCanChangeOtherPasswords => {
+                let mut response = response.status(403);
+                response.body(Body::empty())
+            }
+            apis::auth::AuthPasswordPostResponse::Status429_TooManyFailedLogins => {
+                let mut response = response.status(429);
+                response.body(Body::empty())
+            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -226,12 +182,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn status_get_validation() -> std::result::Result<(), ValidationErrors> {
+fn auth_tokens_get_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// StatusGet - GET /status
+/// AuthTokensGet - GET /auth/tokens
 #[tracing::instrument(skip_all)]
-async fn status_get<I, A>(
+async fn auth_tokens_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
@@ -239,10 +195,10 @@ async fn status_get<I, A>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::health::Health,
+    A: apis::auth::Auth,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || status_get_validation())
+    let validation = tokio::task::spawn_blocking(move || auth_tokens_get_validation())
         .await
         .unwrap();
 
@@ -253,13 +209,16 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl.as_ref().status_get(method, host, cookies).await;
+    let result = api_impl
+        .as_ref()
+        .auth_tokens_get(method, host, cookies)
+        .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::health::StatusGetResponse::Status200_TheHealthAndVersioningInformation(body) => {
+            apis::auth::AuthTokensGetResponse::Status200_TheAPITokens(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -282,7 +241,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::health::StatusGetResponse::Status400_AnErrorOccurred(body) => {
+            apis::auth::AuthTokensGetResponse::Status400_AnErrorOccurred(body) => {
                 let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -305,6 +264,10 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
+            apis::auth::AuthTokensGetResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -320,44 +283,53 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn update_post_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn auth_tokens_id_delete_validation(
+    path_params: models::AuthTokensIdDeletePathParams,
+) -> std::result::Result<(models::AuthTokensIdDeletePathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
 }
-/// UpdatePost - POST /update
+/// AuthTokensIdDelete - DELETE /auth/tokens/{id}
 #[tracing::instrument(skip_all)]
-async fn update_post<I, A>(
+async fn auth_tokens_id_delete<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Path(path_params): Path<models::AuthTokensIdDeletePathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::health::Health,
+    A: apis::auth::Auth,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || update_post_validation())
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || auth_tokens_id_delete_validation(path_params))
+            .await
+            .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl.as_ref().update_post(method, host, cookies).await;
+    let result = api_impl
+        .as_ref()
+        .auth_tokens_id_delete(method, host, cookies, path_params)
+        .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::health::UpdatePostResponse::Status200_TheRoverdDaemonProcessInitiatedASelf => {
+            apis::auth::AuthTokensIdDeleteResponse::Status200_TheTokenWasRevoked => {
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::health::UpdatePostResponse::Status400_AnErrorOccurred(body) => {
+            apis::auth::AuthTokensIdDeleteResponse::Status400_AnErrorOccurred(body) => {
                 let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -380,10 +352,14 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::health::UpdatePostResponse::Status401_UnauthorizedAccess => {
+            apis::auth::AuthTokensIdDeleteResponse::Status401_UnauthorizedAccess => {
                 let mut response = response.status(401);
                 response.body(Body::empty())
             }
+            apis::auth::AuthTokensIdDeleteResponse::Status404_EntityNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -398,44 +374,41 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct AuthTokensPostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::AuthTokensPostRequest,
+}
+
 #[tracing::instrument(skip_all)]
-fn logs_author_name_version_get_validation(
-    path_params: models::LogsAuthorNameVersionGetPathParams,
-    query_params: models::LogsAuthorNameVersionGetQueryParams,
-) -> std::result::Result<
-    (
-        models::LogsAuthorNameVersionGetPathParams,
-        models::LogsAuthorNameVersionGetQueryParams,
-    ),
-    ValidationErrors,
-> {
-    path_params.validate()?;
-    query_params.validate()?;
+fn auth_tokens_post_validation(
+    body: models::AuthTokensPostRequest,
+) -> std::result::Result<(models::AuthTokensPostRequest,), ValidationErrors> {
+    let b = AuthTokensPostBodyValidator { body: &body };
+    b.validate()?;
 
-    Ok((path_params, query_params))
+    Ok((body,))
 }
-/// LogsAuthorNameVersionGet - GET /logs/{author}/{name}/{version}
+/// AuthTokensPost - POST /auth/tokens
 #[tracing::instrument(skip_all)]
-async fn logs_author_name_version_get<I, A>(
+async fn auth_tokens_post<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::LogsAuthorNameVersionGetPathParams>,
-    Query(query_params): Query<models::LogsAuthorNameVersionGetQueryParams>,
     State(api_impl): State<I>,
+    Json(body): Json<models::AuthTokensPostRequest>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::pipeline::Pipeline,
+    A: apis::auth::Auth,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || {
-        logs_author_name_version_get_validation(path_params, query_params)
-    })
-    .await
-    .unwrap();
+    let validation = tokio::task::spawn_blocking(move || auth_tokens_post_validation(body))
+        .await
+        .unwrap();
 
-    let Ok((path_params, query_params)) = validation else {
+    let Ok((body,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -444,16 +417,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .logs_author_name_version_get(method, host, cookies, path_params, query_params)
+        .auth_tokens_post(method, host, cookies, body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::pipeline::LogsAuthorNameVersionGetResponse::Status200_TheCollectionOfLogs(
-                body,
-            ) => {
+            apis::auth::AuthTokensPostResponse::Status200_TheTokenWasCreated(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -476,7 +447,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::pipeline::LogsAuthorNameVersionGetResponse::Status400_AnErrorOccurred(body) => {
+            apis::auth::AuthTokensPostResponse::Status400_AnErrorOccurred(body) => {
                 let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -499,11 +470,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::pipeline::LogsAuthorNameVersionGetResponse::Status404_EntityNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::pipeline::LogsAuthorNameVersionGetResponse::Status401_UnauthorizedAccess => {
+            apis::auth::AuthTokensPostResponse::Status401_UnauthorizedAccess => {
                 let mut response = response.status(401);
                 response.body(Body::empty())
             }
@@ -522,12 +489,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn pipeline_get_validation() -> std::result::Result<(), ValidationErrors> {
+fn root_get_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// PipelineGet - GET /pipeline
+/// RootGet - GET /
 #[tracing::instrument(skip_all)]
-async fn pipeline_get<I, A>(
+async fn root_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
@@ -535,10 +502,10 @@ async fn pipeline_get<I, A>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::pipeline::Pipeline,
+    A: apis::health::Health,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || pipeline_get_validation())
+    let validation = tokio::task::spawn_blocking(move || root_get_validation())
         .await
         .unwrap();
 
@@ -549,15 +516,13 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl.as_ref().pipeline_get(method, host, cookies).await;
+    let result = api_impl.as_ref().root_get(method, host, cookies).await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::pipeline::PipelineGetResponse::Status200_PipelineStatusAndAnArrayOfProcesses(
-                body,
-            ) => {
+            apis::health::RootGetResponse::Status200_TheHealthAndVersioningInformation(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -580,7 +545,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::pipeline::PipelineGetResponse::Status400_AnErrorOccurred(body) => {
+            apis::health::RootGetResponse::Status400_AnErrorOccurred(body) => {
                 let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -603,10 +568,6 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::pipeline::PipelineGetResponse::Status401_UnauthorizedAccess => {
-                let mut response = response.status(401);
-                response.body(Body::empty())
-            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -623,35 +584,35 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct PipelinePostBodyValidator<'a> {
+struct RenamePostBodyValidator<'a> {
     #[validate(nested)]
-    body: &'a Vec<models::PipelinePostRequestInner>,
+    body: &'a models::RenamePostRequest,
 }
 
 #[tracing::instrument(skip_all)]
-fn pipeline_post_validation(
-    body: Vec<models::PipelinePostRequestInner>,
-) -> std::result::Result<(Vec<models::PipelinePostRequestInner>,), ValidationErrors> {
-    let b = PipelinePostBodyValidator { body: &body };
+fn rename_post_validation(
+    body: models::RenamePostRequest,
+) -> std::result::Result<(models::RenamePostRequest,), ValidationErrors> {
+    let b = RenamePostBodyValidator { body: &body };
     b.validate()?;
 
     Ok((body,))
 }
-/// PipelinePost - POST /pipeline
+/// RenamePost - POST /rename
 #[tracing::instrument(skip_all)]
-async fn pipeline_post<I, A>(
+async fn rename_post<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
     State(api_impl): State<I>,
-    Json(body): Json<Vec<models::PipelinePostRequestInner>>,
+    Json(body): Json<models::RenamePostRequest>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::pipeline::Pipeline,
+    A: apis::health::Health,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || pipeline_post_validation(body))
+    let validation = tokio::task::spawn_blocking(move || rename_post_validation(body))
         .await
         .unwrap();
 
@@ -664,48 +625,1255 @@ where
 
     let result = api_impl
         .as_ref()
-        .pipeline_post(method, host, cookies, body)
+        .rename_post(method, host, cookies, body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::pipeline::PipelinePostResponse::Status200_ThePipelineWasUpdatedSuccessfully
-                                                => {
-                                                  let mut response = response.status(200);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::pipeline::PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(400);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
+        Ok(rsp) => match rsp {
+            apis::health::RenamePostResponse::Status200_TheRoverWasRenamed => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::health::RenamePostResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::health::RenamePostResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn shutdown_post_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// ShutdownPost - POST /shutdown
+#[tracing::instrument(skip_all)]
+async fn shutdown_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::health::Health,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || shutdown_post_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl.as_ref().shutdown_post(method, host, cookies).await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::health::ShutdownPostResponse::Status200_RoverShutdownSuccessfully => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::health::ShutdownPostResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::health::ShutdownPostResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn status_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// StatusGet - GET /status
+#[tracing::instrument(skip_all)]
+async fn status_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::health::Health,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || status_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl.as_ref().status_get(method, host, cookies).await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::health::StatusGetResponse::Status200_TheHealthAndVersioningInformation(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::health::StatusGetResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn update_post_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// UpdatePost - POST /update
+#[tracing::instrument(skip_all)]
+async fn update_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::health::Health,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || update_post_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl.as_ref().update_post(method, host, cookies).await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::health::UpdatePostResponse::Status200_TheRoverdDaemonProcessInitiatedASelf => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::health::UpdatePostResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::health::UpdatePostResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn logs_author_name_version_get_validation(
+    path_params: models::LogsAuthorNameVersionGetPathParams,
+    query_params: models::LogsAuthorNameVersionGetQueryParams,
+) -> std::result::Result<
+    (
+        models::LogsAuthorNameVersionGetPathParams,
+        models::LogsAuthorNameVersionGetQueryParams,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    query_params.validate()?;
+
+    Ok((path_params, query_params))
+}
+/// LogsAuthorNameVersionGet - GET /logs/{author}/{name}/{version}
+#[tracing::instrument(skip_all)]
+async fn logs_author_name_version_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::LogsAuthorNameVersionGetPathParams>,
+    Query(query_params): Query<models::LogsAuthorNameVersionGetQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        logs_author_name_version_get_validation(path_params, query_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((path_params, query_params)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .logs_author_name_version_get(method, host, cookies, path_params, query_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::LogsAuthorNameVersionGetResponse::Status200_TheCollectionOfLogs(
+                body,
+            ) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::LogsAuthorNameVersionGetResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::LogsAuthorNameVersionGetResponse::Status404_EntityNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::pipeline::LogsAuthorNameVersionGetResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// PipelineGet - GET /pipeline
+#[tracing::instrument(skip_all)]
+async fn pipeline_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || pipeline_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl.as_ref().pipeline_get(method, host, cookies).await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::PipelineGetResponse::Status200_PipelineStatusAndAnArrayOfProcesses(
+                body,
+            ) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineGetResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineGetResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct PipelinePostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a Vec<models::PipelinePostRequestInner>,
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_post_validation(
+    body: Vec<models::PipelinePostRequestInner>,
+) -> std::result::Result<(Vec<models::PipelinePostRequestInner>,), ValidationErrors> {
+    let b = PipelinePostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// PipelinePost - POST /pipeline
+#[tracing::instrument(skip_all)]
+async fn pipeline_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+    Json(body): Json<Vec<models::PipelinePostRequestInner>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || pipeline_post_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_post(method, host, cookies, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::pipeline::PipelinePostResponse::Status200_ThePipelineWasUpdatedSuccessfully
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::pipeline::PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::pipeline::PipelinePostResponse::Status401_UnauthorizedAccess
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                response.status(500).body(Body::empty())
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_profiles_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// PipelineProfilesGet - GET /pipeline/profiles
+#[tracing::instrument(skip_all)]
+async fn pipeline_profiles_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || pipeline_profiles_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_profiles_get(method, host, cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::PipelineProfilesGetResponse::Status200_TheStoredProfiles(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineProfilesGetResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineProfilesGetResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_profiles_name_activate_post_validation(
+    path_params: models::PipelineProfilesNameActivatePostPathParams,
+) -> std::result::Result<(models::PipelineProfilesNameActivatePostPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// PipelineProfilesNameActivatePost - POST /pipeline/profiles/{name}/activate
+#[tracing::instrument(skip_all)]
+async fn pipeline_profiles_name_activate_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::PipelineProfilesNameActivatePostPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        pipeline_profiles_name_activate_post_validation(path_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_profiles_name_activate_post(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::PipelineProfilesNameActivatePostResponse::Status200_ThePipelineWasSetToTheProfile => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::pipeline::PipelineProfilesNameActivatePostResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineProfilesNameActivatePostResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_profiles_name_delete_validation(
+    path_params: models::PipelineProfilesNameDeletePathParams,
+) -> std::result::Result<(models::PipelineProfilesNameDeletePathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// PipelineProfilesNameDelete - DELETE /pipeline/profiles/{name}
+#[tracing::instrument(skip_all)]
+async fn pipeline_profiles_name_delete<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::PipelineProfilesNameDeletePathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || pipeline_profiles_name_delete_validation(path_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_profiles_name_delete(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::PipelineProfilesNameDeleteResponse::Status200_TheProfileWasDeleted => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::pipeline::PipelineProfilesNameDeleteResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineProfilesNameDeleteResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct PipelineProfilesNamePostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a Vec<models::ProfileService>,
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_profiles_name_post_validation(
+    path_params: models::PipelineProfilesNamePostPathParams,
+    body: Vec<models::ProfileService>,
+) -> std::result::Result<
+    (
+        models::PipelineProfilesNamePostPathParams,
+        Vec<models::ProfileService>,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    let b = PipelineProfilesNamePostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((path_params, body))
+}
+/// PipelineProfilesNamePost - POST /pipeline/profiles/{name}
+#[tracing::instrument(skip_all)]
+async fn pipeline_profiles_name_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::PipelineProfilesNamePostPathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<Vec<models::ProfileService>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        pipeline_profiles_name_post_validation(path_params, body)
+    })
+    .await
+    .unwrap();
+
+    let Ok((path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_profiles_name_post(method, host, cookies, path_params, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::PipelineProfilesNamePostResponse::Status200_TheProfileWasSaved => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::pipeline::PipelineProfilesNamePostResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineProfilesNamePostResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_start_post_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// PipelineStartPost - POST /pipeline/start
+#[tracing::instrument(skip_all)]
+async fn pipeline_start_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || pipeline_start_post_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_start_post(method, host, cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::pipeline::PipelineStartPostResponse::Status200_ThePipelineWasStartedSuccessfully
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::pipeline::PipelineStartPostResponse::Status400_AnErrorOccurred
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::pipeline::PipelineStartPostResponse::Status401_UnauthorizedAccess
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                response.status(500).body(Body::empty())
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_stop_post_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// PipelineStopPost - POST /pipeline/stop
+#[tracing::instrument(skip_all)]
+async fn pipeline_stop_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || pipeline_stop_post_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_stop_post(method, host, cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::pipeline::PipelineStopPostResponse::Status200_ThePipelineWasStoppedSuccessfully
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::pipeline::PipelineStopPostResponse::Status400_AnErrorOccurred
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
                                                         CONTENT_TYPE,
                                                         HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
                                                   }
 
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::pipeline::PipelinePostResponse::Status401_UnauthorizedAccess
-                                                => {
-                                                  let mut response = response.status(401);
-                                                  response.body(Body::empty())
-                                                },
-                                            },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
-                                            },
-                                        };
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::pipeline::PipelineStopPostResponse::Status401_UnauthorizedAccess
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                response.status(500).body(Body::empty())
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct PipelineTuningPostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a Vec<models::PipelineTuningPostRequestInner>,
+}
+
+#[tracing::instrument(skip_all)]
+fn pipeline_tuning_post_validation(
+    body: Vec<models::PipelineTuningPostRequestInner>,
+) -> std::result::Result<(Vec<models::PipelineTuningPostRequestInner>,), ValidationErrors> {
+    let b = PipelineTuningPostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// PipelineTuningPost - POST /pipeline/tuning
+#[tracing::instrument(skip_all)]
+async fn pipeline_tuning_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+    Json(body): Json<Vec<models::PipelineTuningPostRequestInner>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::pipeline::Pipeline,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || pipeline_tuning_post_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .pipeline_tuning_post(method, host, cookies, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::pipeline::PipelineTuningPostResponse::Status200_TheValuesWerePublishedToTheRunningServices => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::pipeline::PipelineTuningPostResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::PipelineTuningPostResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
 
     resp.map_err(|e| {
         error!(error = ?e);
@@ -714,12 +1882,12 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn pipeline_start_post_validation() -> std::result::Result<(), ValidationErrors> {
+fn runs_get_validation() -> std::result::Result<(), ValidationErrors> {
     Ok(())
 }
-/// PipelineStartPost - POST /pipeline/start
+/// RunsGet - GET /runs
 #[tracing::instrument(skip_all)]
-async fn pipeline_start_post<I, A>(
+async fn runs_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
@@ -730,7 +1898,7 @@ where
     A: apis::pipeline::Pipeline,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || pipeline_start_post_validation())
+    let validation = tokio::task::spawn_blocking(move || runs_get_validation())
         .await
         .unwrap();
 
@@ -741,50 +1909,69 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl
-        .as_ref()
-        .pipeline_start_post(method, host, cookies)
-        .await;
+    let result = api_impl.as_ref().runs_get(method, host, cookies).await;
 
     let mut response = Response::builder();
 
     let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::pipeline::PipelineStartPostResponse::Status200_ThePipelineWasStartedSuccessfully
-                                                => {
-                                                  let mut response = response.status(200);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::pipeline::PipelineStartPostResponse::Status400_AnErrorOccurred
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(400);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
-                                                  }
+        Ok(rsp) => match rsp {
+            apis::pipeline::RunsGetResponse::Status200_TheRecordedRuns(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::pipeline::PipelineStartPostResponse::Status401_UnauthorizedAccess
-                                                => {
-                                                  let mut response = response.status(401);
-                                                  response.body(Body::empty())
-                                                },
-                                            },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
-                                            },
-                                        };
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::RunsGetResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::RunsGetResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
 
     resp.map_err(|e| {
         error!(error = ?e);
@@ -793,15 +1980,29 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn pipeline_stop_post_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn runs_id_logs_author_name_version_get_validation(
+    path_params: models::RunsIdLogsAuthorNameVersionGetPathParams,
+    query_params: models::RunsIdLogsAuthorNameVersionGetQueryParams,
+) -> std::result::Result<
+    (
+        models::RunsIdLogsAuthorNameVersionGetPathParams,
+        models::RunsIdLogsAuthorNameVersionGetQueryParams,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    query_params.validate()?;
+
+    Ok((path_params, query_params))
 }
-/// PipelineStopPost - POST /pipeline/stop
+/// RunsIdLogsAuthorNameVersionGet - GET /runs/{id}/logs/{author}/{name}/{version}
 #[tracing::instrument(skip_all)]
-async fn pipeline_stop_post<I, A>(
+async fn runs_id_logs_author_name_version_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Path(path_params): Path<models::RunsIdLogsAuthorNameVersionGetPathParams>,
+    Query(query_params): Query<models::RunsIdLogsAuthorNameVersionGetQueryParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -809,11 +2010,13 @@ where
     A: apis::pipeline::Pipeline,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || pipeline_stop_post_validation())
-        .await
-        .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        runs_id_logs_author_name_version_get_validation(path_params, query_params)
+    })
+    .await
+    .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params, query_params)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -822,48 +2025,74 @@ where
 
     let result = api_impl
         .as_ref()
-        .pipeline_stop_post(method, host, cookies)
+        .runs_id_logs_author_name_version_get(method, host, cookies, path_params, query_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::pipeline::PipelineStopPostResponse::Status200_ThePipelineWasStoppedSuccessfully
-                                                => {
-                                                  let mut response = response.status(200);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::pipeline::PipelineStopPostResponse::Status400_AnErrorOccurred
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(400);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
-                                                  }
+        Ok(rsp) => match rsp {
+            apis::pipeline::RunsIdLogsAuthorNameVersionGetResponse::Status200_TheCollectionOfLogs(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::pipeline::PipelineStopPostResponse::Status401_UnauthorizedAccess
-                                                => {
-                                                  let mut response = response.status(401);
-                                                  response.body(Body::empty())
-                                                },
-                                            },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
-                                            },
-                                        };
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::RunsIdLogsAuthorNameVersionGetResponse::Status400_AnErrorOccurred(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::pipeline::RunsIdLogsAuthorNameVersionGetResponse::Status404_EntityNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::pipeline::RunsIdLogsAuthorNameVersionGetResponse::Status401_UnauthorizedAccess => {
+                let mut response = response.status(401);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
 
     resp.map_err(|e| {
         error!(error = ?e);
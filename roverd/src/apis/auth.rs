@@ -0,0 +1,120 @@
+use axum::async_trait;
+use axum::extract::Host;
+use axum::http::Method;
+use axum_extra::extract::CookieJar;
+
+use openapi::apis::auth::*;
+use openapi::models::*;
+
+use tracing::warn;
+
+use crate::app::accounts::{Caller, CALLER};
+use crate::error::Error;
+use crate::{app::Roverd, warn_generic};
+
+#[async_trait]
+impl Auth for Roverd {
+    /// Change the password of an account, which can be the rover user or an account from users.yaml.
+    /// Only admins can change the password of another account.
+    /// `RoverState` - This function can run *always*
+    /// AuthPasswordPost - POST /auth/password
+    async fn auth_password_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        body: AuthPasswordPostRequest,
+    ) -> Result<AuthPasswordPostResponse, ()> {
+        let Ok(caller) = CALLER.try_with(Caller::clone) else {
+            return Ok(AuthPasswordPostResponse::Status401_UnauthorizedAccess);
+        };
+
+        if caller.role < Role::Admin && caller.name.as_deref() != Some(&body.username) {
+            warn!(
+                "{:?} is not allowed to change the password of {}",
+                caller.name, body.username
+            );
+            return Ok(AuthPasswordPostResponse::Status403_OnlyAdminsCanChangeOtherPasswords);
+        }
+
+        // Only the account itself needs to know its current password, an admin can reset the
+        // password of another account (for instance when it was forgotten)
+        let password = match caller.name.as_deref() == Some(&body.username) {
+            true => Some(body.password.as_str()),
+            false => None,
+        };
+
+        let result = self
+            .app
+            .change_password(
+                &self.info,
+                caller.client,
+                &body.username,
+                password,
+                &body.new_password,
+            )
+            .await;
+        if let Err(Error::PasswordGuessesLockedOut) = result {
+            warn!(
+                "rejected password change from {:?}, locked out",
+                caller.client
+            );
+            return Ok(AuthPasswordPostResponse::Status429_TooManyFailedLogins);
+        }
+        warn_generic!(result, AuthPasswordPostResponse);
+
+        Ok(AuthPasswordPostResponse::Status200_ThePasswordWasChanged)
+    }
+
+    /// Retrieve the API tokens of the rover, without their secrets.
+    /// `RoverState` - This function can run *always*
+    /// AuthTokensGet - GET /auth/tokens
+    async fn auth_tokens_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+    ) -> Result<AuthTokensGetResponse, ()> {
+        let tokens = self.app.get_tokens().await;
+
+        Ok(AuthTokensGetResponse::Status200_TheAPITokens(tokens))
+    }
+
+    /// Revoke an API token.
+    /// `RoverState` - This function can run *always*
+    /// AuthTokensIdDelete - DELETE /auth/tokens/{id}
+    async fn auth_tokens_id_delete(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        path_params: AuthTokensIdDeletePathParams,
+    ) -> Result<AuthTokensIdDeleteResponse, ()> {
+        warn_generic!(
+            self.app.revoke_token(&path_params.id).await,
+            AuthTokensIdDeleteResponse
+        );
+
+        Ok(AuthTokensIdDeleteResponse::Status200_TheTokenWasRevoked)
+    }
+
+    /// Create a new API token, which can be used as a bearer token. The secret is only returned once..
+    /// `RoverState` - This function can run *always*
+    /// AuthTokensPost - POST /auth/tokens
+    async fn auth_tokens_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        body: AuthTokensPostRequest,
+    ) -> Result<AuthTokensPostResponse, ()> {
+        let (id, token) = warn_generic!(
+            self.app.create_token(&body.name, body.role).await,
+            AuthTokensPostResponse
+        );
+
+        Ok(AuthTokensPostResponse::Status200_TheTokenWasCreated(
+            AuthTokensPost200Response::new(id, token),
+        ))
+    }
+}
@@ -54,20 +54,23 @@ impl Health for Roverd {
         _host: Host,
         _cookies: CookieJar,
     ) -> Result<StatusGetResponse, ()> {
+        let info = self.info.read().await.clone();
+
         let uptime = SystemTime::now()
-            .duration_since(self.info.start_time)
+            .duration_since(info.start_time)
             .unwrap()
             .as_millis() as i64;
 
         let time_now = time_now!() as i64;
 
-        let error_message = match self.info.status {
+        let error_message = match info.status {
             DaemonStatus::Unrecoverable => Some("❌ check logs and restart roverd".to_string()),
-            DaemonStatus::Recoverable => Some(match &self.info.error_msg {
+            DaemonStatus::Recoverable => Some(match &info.error_msg {
                 Some(msg) => format!("⚠️ {}", msg),
                 None => "⚠️ recoverable error, check logs".to_string(),
             }),
-            DaemonStatus::Operational => None,
+            // Problems that do not affect the API (such as the https listener) are shown as well
+            DaemonStatus::Operational => info.error_msg.as_ref().map(|msg| format!("⚠️ {}", msg)),
         };
 
         let mut sysinfo = self.app.sysinfo.write().await;
@@ -96,16 +99,17 @@ impl Health for Roverd {
         Ok(
             StatusGetResponse::Status200_TheHealthAndVersioningInformation(
                 models::StatusGet200Response {
-                    status: self.info.status,
+                    status: info.status,
                     error_message,
-                    os: self.info.os.clone(),
-                    rover_id: self.info.rover_id,
-                    rover_name: self.info.rover_name.clone(),
+                    os: info.os,
+                    rover_id: info.rover_id,
+                    rover_name: info.rover_name,
                     uptime,
-                    version: self.info.version.clone(),
+                    version: info.version,
                     systime: time_now,
                     cpu: cpus,
                     memory,
+                    tls_fingerprint: info.tls_fingerprint,
                 },
             ),
         )
@@ -129,6 +133,24 @@ impl Health for Roverd {
         }
     }
 
+    /// Rename the rover, the new name is written to info.txt and shown by /status right away.
+    /// `RoverState` - This function can run *always*
+    /// RenamePost - POST /rename
+    async fn rename_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        body: models::RenamePostRequest,
+    ) -> Result<RenamePostResponse, ()> {
+        warn_generic!(
+            self.app.rename_rover(&self.info, &body.name).await,
+            RenamePostResponse
+        );
+
+        Ok(RenamePostResponse::Status200_TheRoverWasRenamed)
+    }
+
     /// Shutdown the rover..
     /// `RoverState` - This function can run *only when dormant*
     /// TODO: fs_lock
@@ -1,3 +1,5 @@
+mod auth;
 mod health;
 mod pipeline;
 mod services;
+pub mod streams;
@@ -51,6 +51,19 @@ impl Pipeline for Roverd {
     ) -> Result<PipelineGetResponse, ()> {
         let enabled: Vec<PipelineGet200ResponseEnabledInner> =
             warn_generic!(self.app.get_pipeline().await, PipelineGetResponse);
+
+        // An invalid pipeline is kept, so tell the user what needs to be fixed
+        let invalid = self.app.stats.read().await.status == PipelineStatus::Invalid;
+        let validation_errors = match invalid {
+            true => match self.app.get_valid_pipeline().await {
+                Err(Error::PipelineValidation(invalid_services, errors)) => {
+                    Some(pipeline_validation_errors(invalid_services, errors).1)
+                }
+                _ => None,
+            },
+            false => None,
+        };
+
         let stats = self.app.stats.read().await;
         Ok(
             PipelineGetResponse::Status200_PipelineStatusAndAnArrayOfProcesses(
@@ -60,6 +73,7 @@ impl Pipeline for Roverd {
                     last_stop: stats.last_stop,
                     last_restart: stats.last_restart,
                     enabled,
+                    validation_errors,
                 },
             ),
         )
@@ -78,126 +92,115 @@ impl Pipeline for Roverd {
         body: Vec<PipelinePostRequestInner>,
     ) -> Result<PipelinePostResponse, ()> {
         if let Some(rover_state) = self.try_get_dormant().await {
-            let _ = match self.app.set_pipeline(body, rover_state).await {
-                Ok(a) => a,
-                Err(e) => match e {
-                    Error::Validation(val_errors) => {
-                        let mut pipeline_errors = vec![];
-                        let mut string_errors = vec![];
-
-                        for val_error in val_errors {
-                            match val_error {
-                                rovervalidate::error::Error::PipelineValidationError(
-                                    pipeline_validation_error,
-                                ) => pipeline_errors.push(pipeline_validation_error),
-                                e => string_errors.push(e.to_string()),
-                            }
-                        }
-
-                        let mut unmet_streams = vec![];
-                        let mut unmet_services = vec![];
-                        let mut duplicate_service = vec![];
-
-                        for i in pipeline_errors {
-                            match i {
-                                    rovervalidate::error::PipelineValidationError::UnmetDependencyError(unmet_dependency_error) => {
-                                        match unmet_dependency_error {
-                                            rovervalidate::error::UnmetDependencyError::UnmetStream(unmet_stream_error) => {
-                                                unmet_streams.push(
-                                                    UnmetStreamError {
-                                                        source: Some(unmet_stream_error.source),
-                                                        target: Some(unmet_stream_error.target),
-                                                        stream: Some(unmet_stream_error.stream),
-                                                    }
-                                                );
-                                            },
-                                            rovervalidate::error::UnmetDependencyError::UnmetService(unmet_service_error) => {
-                                                unmet_services.push(
-                                                    UnmetServiceError {
-                                                        source: Some(unmet_service_error.source),
-                                                        target: Some(unmet_service_error.target)
-                                                    }
-                                                )
-                                            },
-                                        }
-                                    },
-                                    rovervalidate::error::PipelineValidationError::DuplicateServiceError(s) => {
-                                        duplicate_service.push(openapi::models::DuplicateServiceError(s));
-                                    },
-                                }
-                        }
-
-                        let string_errors = if !string_errors.is_empty() {
-                            Some(string_errors.concat().to_string())
-                        } else {
-                            None
-                        };
-
-                        let unmet_streams = if !unmet_streams.is_empty() {
-                            Some(unmet_streams)
-                        } else {
-                            None
-                        };
-
-                        let unmet_services = if !unmet_services.is_empty() {
-                            Some(unmet_services)
-                        } else {
-                            None
-                        };
-
-                        let duplicate_service = if !duplicate_service.is_empty() {
-                            Some(duplicate_service)
-                        } else {
-                            None
-                        };
-
-                        return Ok(
-                            PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet(
-                                PipelinePost400Response {
-                                    message: string_errors,
-                                    validation_errors: PipelinePost400ResponseValidationErrors {
-                                        unmet_streams,
-                                        unmet_services,
-                                        duplicate_service,
-                                    },
-                                },
-                            ),
-                        );
-                    }
-                    all_other_errors => {
-                        return Ok(
-                            PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet(
-                                PipelinePost400Response {
-                                    message: Some(format!("{:?}", all_other_errors)),
-                                    validation_errors: PipelinePost400ResponseValidationErrors {
-                                        unmet_streams: None,
-                                        unmet_services: None,
-                                        duplicate_service: None,
-                                    },
-                                },
-                            ),
-                        );
-                    }
-                },
-            };
-
-            Ok(PipelinePostResponse::Status200_ThePipelineWasUpdatedSuccessfully)
+            match self.app.set_pipeline(body, rover_state).await {
+                Ok(()) => Ok(PipelinePostResponse::Status200_ThePipelineWasUpdatedSuccessfully),
+                Err(Error::PipelineValidation(invalid_services, errors)) => {
+                    let (message, validation_errors) =
+                        pipeline_validation_errors(invalid_services, errors);
+                    Ok(
+                        PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet(
+                            PipelinePost400Response {
+                                message,
+                                validation_errors,
+                            },
+                        ),
+                    )
+                }
+                Err(e) => Ok(
+                    PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet(
+                        PipelinePost400Response {
+                            message: Some(format!("{:?}", e)),
+                            validation_errors: PipelineValidationErrors::new(),
+                        },
+                    ),
+                ),
+            }
         } else {
             Ok(
                 PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet(
                     PipelinePost400Response {
                         message: Some("".to_string()),
-                        validation_errors: PipelinePost400ResponseValidationErrors {
-                            unmet_streams: None,
-                            unmet_services: None,
-                            duplicate_service: None,
-                        },
+                        validation_errors: PipelineValidationErrors::new(),
                     },
                 ),
             )
         }
     }
 
+    /// Retrieve all pipeline profiles stored on the rover.
+    /// `RoverState` - This function can run *always*
+    /// PipelineProfilesGet - GET /pipeline/profiles
+    async fn pipeline_profiles_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+    ) -> Result<PipelineProfilesGetResponse, ()> {
+        let profiles = warn_generic!(self.app.get_profiles().await, PipelineProfilesGetResponse);
+
+        Ok(PipelineProfilesGetResponse::Status200_TheStoredProfiles(
+            profiles,
+        ))
+    }
+
+    /// Set the pipeline to the services of a profile. Only possible if the pipeline is not started..
+    /// `RoverState` - This function can run *on when dormant*
+    /// PipelineProfilesNameActivatePost - POST /pipeline/profiles/{name}/activate
+    async fn pipeline_profiles_name_activate_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        path_params: PipelineProfilesNameActivatePostPathParams,
+    ) -> Result<PipelineProfilesNameActivatePostResponse, ()> {
+        if let Some(rover_state) = self.try_get_dormant().await {
+            warn_generic!(
+                self.app
+                    .activate_profile(path_params.name, rover_state)
+                    .await,
+                PipelineProfilesNameActivatePostResponse
+            );
+            Ok(PipelineProfilesNameActivatePostResponse::Status200_ThePipelineWasSetToTheProfile)
+        } else {
+            rover_is_operating!(PipelineProfilesNameActivatePostResponse)
+        }
+    }
+
+    /// Delete a pipeline profile.
+    /// `RoverState` - This function can run *always*
+    /// PipelineProfilesNameDelete - DELETE /pipeline/profiles/{name}
+    async fn pipeline_profiles_name_delete(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        path_params: PipelineProfilesNameDeletePathParams,
+    ) -> Result<PipelineProfilesNameDeleteResponse, ()> {
+        warn_generic!(
+            self.app.delete_profile(path_params.name).await,
+            PipelineProfilesNameDeleteResponse
+        );
+        Ok(PipelineProfilesNameDeleteResponse::Status200_TheProfileWasDeleted)
+    }
+
+    /// Save a pipeline as a named profile, replacing the profile if it already exists. The pipeline must be valid..
+    /// `RoverState` - This function can run *always*
+    /// PipelineProfilesNamePost - POST /pipeline/profiles/{name}
+    async fn pipeline_profiles_name_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        path_params: PipelineProfilesNamePostPathParams,
+        body: Vec<ProfileService>,
+    ) -> Result<PipelineProfilesNamePostResponse, ()> {
+        warn_generic!(
+            self.app.save_profile(path_params.name, body).await,
+            PipelineProfilesNamePostResponse
+        );
+        Ok(PipelineProfilesNamePostResponse::Status200_TheProfileWasSaved)
+    }
+
     /// Start the pipeline.
     /// `RoverState` - This function can run *on when dormant*
     /// TODO: fs_lock
@@ -255,4 +258,242 @@ impl Pipeline for Roverd {
             rover_is_dormant!(PipelineStopPostResponse)
         }
     }
+
+    /// Change tunable configuration values of the running pipeline.
+    /// `RoverState` - This function can run *only when operating*
+    /// PipelineTuningPost - POST /pipeline/tuning
+    async fn pipeline_tuning_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        body: Vec<PipelineTuningPostRequestInner>,
+    ) -> Result<PipelineTuningPostResponse, ()> {
+        if let Some(rover_state) = self.try_get_operating().await {
+            let mut values = vec![];
+            for tuning in body {
+                let value = warn_generic!(
+                    serde_json::from_str::<rovervalidate::service::Value>(tuning.value.0.get()),
+                    PipelineTuningPostResponse
+                );
+                values.push((tuning.name, value));
+            }
+
+            warn_generic!(
+                self.app.tune(values, rover_state).await,
+                PipelineTuningPostResponse
+            );
+            Ok(PipelineTuningPostResponse::Status200_TheValuesWerePublishedToTheRunningServices)
+        } else {
+            rover_is_dormant!(PipelineTuningPostResponse)
+        }
+    }
+
+    /// Retrieve the history of pipeline runs, most recent first.
+    /// `RoverState` - This function can run *always*
+    /// RunsGet - GET /runs
+    async fn runs_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+    ) -> Result<RunsGetResponse, ()> {
+        let runs = warn_generic!(self.app.get_runs().await, RunsGetResponse);
+
+        let runs = runs
+            .into_iter()
+            .map(|run| RunsGet200ResponseInner {
+                id: run.id as i64,
+                start: run.start,
+                stop: run.stop,
+                services: run
+                    .services
+                    .into_iter()
+                    .map(|s| RunsGet200ResponseInnerServicesInner {
+                        author: s.author,
+                        name: s.name,
+                        version: s.version,
+                        exit_code: s.exit_code,
+                        faults: s.faults as i32,
+                        restarts: s.restarts as i32,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(RunsGetResponse::Status200_TheRecordedRuns(runs))
+    }
+
+    /// Retrieve the logs a service wrote during a given run.
+    /// `RoverState` - This function can run *always*
+    /// RunsIdLogsAuthorNameVersionGet - GET /runs/{id}/logs/{author}/{name}/{version}
+    async fn runs_id_logs_author_name_version_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        path_params: RunsIdLogsAuthorNameVersionGetPathParams,
+        query_params: RunsIdLogsAuthorNameVersionGetQueryParams,
+    ) -> Result<RunsIdLogsAuthorNameVersionGetResponse, ()> {
+        let fq = FqBuf::from(&path_params);
+        let lines = query_params.lines.unwrap_or(DEFAULT_LOG_LINES) as usize;
+
+        let logs = warn_generic!(
+            self.app
+                .get_run_logs(path_params.id as u64, fq, lines)
+                .await,
+            RunsIdLogsAuthorNameVersionGetResponse
+        );
+
+        Ok(RunsIdLogsAuthorNameVersionGetResponse::Status200_TheCollectionOfLogs(logs))
+    }
+}
+
+/// Converts the errors of an invalid pipeline into the structured errors of the API. Errors
+/// that are not about the pipeline itself are returned as a message.
+fn pipeline_validation_errors(
+    invalid: Vec<(FqBuf, String)>,
+    errors: Vec<rovervalidate::error::Error>,
+) -> (Option<String>, PipelineValidationErrors) {
+    let mut pipeline_errors = vec![];
+    let mut string_errors = vec![];
+
+    for val_error in errors {
+        match val_error {
+            rovervalidate::error::Error::PipelineValidationError(pipeline_validation_error) => {
+                pipeline_errors.push(pipeline_validation_error)
+            }
+            e => string_errors.push(e.to_string()),
+        }
+    }
+
+    let mut invalid_services = vec![];
+    for (fq, message) in invalid {
+        invalid_services.push(InvalidServiceError {
+            author: fq.author,
+            name: fq.name,
+            version: fq.version,
+            message,
+        });
+    }
+
+    let mut unmet_streams = vec![];
+    let mut unmet_services = vec![];
+    let mut duplicate_service = vec![];
+    let mut cycles = vec![];
+    let mut self_loops = vec![];
+    let mut unused_outputs = vec![];
+    let mut unreachable_services = vec![];
+
+    for i in pipeline_errors {
+        match i {
+            rovervalidate::error::PipelineValidationError::UnmetDependencyError(
+                unmet_dependency_error,
+            ) => match unmet_dependency_error {
+                rovervalidate::error::UnmetDependencyError::UnmetStream(unmet_stream_error) => {
+                    unmet_streams.push(UnmetStreamError {
+                        source: Some(unmet_stream_error.source),
+                        target: Some(unmet_stream_error.target),
+                        stream: Some(unmet_stream_error.stream),
+                    });
+                }
+                rovervalidate::error::UnmetDependencyError::UnmetService(unmet_service_error) => {
+                    unmet_services.push(UnmetServiceError {
+                        source: Some(unmet_service_error.source),
+                        target: Some(unmet_service_error.target),
+                    })
+                }
+            },
+            rovervalidate::error::PipelineValidationError::DuplicateServiceError(s) => {
+                duplicate_service.push(openapi::models::DuplicateServiceError(s));
+            }
+            rovervalidate::error::PipelineValidationError::CycleWarning(cycle_warning) => {
+                cycles.push(CycleWarning {
+                    services: Some(cycle_warning.services),
+                });
+            }
+            rovervalidate::error::PipelineValidationError::SelfLoopError(s) => {
+                self_loops.push(SelfLoopError(s));
+            }
+            rovervalidate::error::PipelineValidationError::UnusedOutputWarning(
+                unused_output_warning,
+            ) => {
+                unused_outputs.push(UnusedOutputWarning {
+                    service: Some(unused_output_warning.service),
+                    stream: Some(unused_output_warning.stream),
+                });
+            }
+            rovervalidate::error::PipelineValidationError::UnreachableServiceWarning(s) => {
+                unreachable_services.push(UnreachableServiceWarning(s));
+            }
+        }
+    }
+
+    let message = if !string_errors.is_empty() {
+        Some(string_errors.concat().to_string())
+    } else {
+        None
+    };
+
+    let invalid_services = if !invalid_services.is_empty() {
+        Some(invalid_services)
+    } else {
+        None
+    };
+
+    let unmet_streams = if !unmet_streams.is_empty() {
+        Some(unmet_streams)
+    } else {
+        None
+    };
+
+    let unmet_services = if !unmet_services.is_empty() {
+        Some(unmet_services)
+    } else {
+        None
+    };
+
+    let duplicate_service = if !duplicate_service.is_empty() {
+        Some(duplicate_service)
+    } else {
+        None
+    };
+
+    let cycles = if !cycles.is_empty() {
+        Some(cycles)
+    } else {
+        None
+    };
+
+    let self_loops = if !self_loops.is_empty() {
+        Some(self_loops)
+    } else {
+        None
+    };
+
+    let unused_outputs = if !unused_outputs.is_empty() {
+        Some(unused_outputs)
+    } else {
+        None
+    };
+
+    let unreachable_services = if !unreachable_services.is_empty() {
+        Some(unreachable_services)
+    } else {
+        None
+    };
+
+    (
+        message,
+        PipelineValidationErrors {
+            invalid_services,
+            unmet_streams,
+            unmet_services,
+            duplicate_service,
+            cycles,
+            self_loops,
+            unused_outputs,
+            unreachable_services,
+        },
+    )
 }
@@ -20,7 +20,7 @@ use crate::{rover_is_operating, warn_generic};
 
 #[async_trait]
 impl Services for Roverd {
-    /// Fetches the zip file from the given URL and installs the service onto the filesystem.
+    /// Fetches the service archive (ZIP, tar.gz or tar.zst) from the given URL and installs the service onto the filesystem.
     /// `RoverState` - This function can run *only when dormant*
     /// TODO: fs_lock
     /// FetchPost - POST /fetch
@@ -52,7 +52,7 @@ impl Services for Roverd {
         }
     }
 
-    /// Upload a new service or new version to the rover by uploading a ZIP file.
+    /// Upload a new service or new version to the rover by uploading a ZIP, tar.gz or tar.zst archive.
     /// `RoverState` - This function can run *only when dormant*
     /// TODO: fs_lock
     /// UploadPost - POST /upload
@@ -0,0 +1,108 @@
+use std::convert::Infallible;
+
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::routing::get;
+use axum::Router;
+use futures::Stream;
+use openapi::models::LogsAuthorNameVersionGetPathParams;
+use serde::Deserialize;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::warn;
+
+use crate::{app::Roverd, service::FqBuf};
+
+/// Endpoints that push data to the client as server-sent events. These cannot be described
+/// by the generated openapi server, so they are routed here and merged into its router.
+pub fn router(roverd: Roverd) -> Router {
+    Router::new()
+        .route("/events", get(events_stream))
+        .route("/logs/:author/:name/:version/stream", get(logs_stream))
+        .with_state(roverd)
+}
+
+/// Streams all pipeline, process and service state transitions as they happen, each event
+/// holds a JSON object with a `type` field (see app/events.rs). Clients that fall behind
+/// miss the events in between, so they should re-fetch the pipeline after a gap.
+/// `RoverState` - This function can run *always*
+/// EventsStream - GET /events
+async fn events_stream(
+    State(roverd): State<Roverd>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let rx = roverd.app.events.subscribe();
+
+    let stream = futures::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    let event = Event::default()
+                        .json_data(&event)
+                        .unwrap_or_else(|_| Event::default());
+                    return Some((Ok(event), rx));
+                }
+                Err(RecvError::Lagged(n)) => {
+                    warn!("event subscriber lagged behind, skipped {} events", n);
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LogsStreamQueryParams {
+    /// Byte offset to continue from, as sent in the id of the last received event.
+    pub since: Option<u64>,
+
+    /// Follow the build log instead of the log of the running service.
+    pub build: Option<bool>,
+}
+
+/// Streams the lines of a service's log (or build log) as they are written. Each event
+/// carries one line and has the byte offset after that line as its id, which can be passed
+/// as `since` (or is sent by the browser as Last-Event-ID) to resume after a reconnect.
+/// Without an offset, only lines written after subscribing are sent.
+/// `RoverState` - This function can run *always*
+/// LogsStream - GET /logs/{author}/{name}/{version}/stream
+async fn logs_stream(
+    State(roverd): State<Roverd>,
+    Path(path_params): Path<LogsAuthorNameVersionGetPathParams>,
+    Query(query_params): Query<LogsStreamQueryParams>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, StatusCode> {
+    let fq = FqBuf::from(&path_params);
+
+    let since = query_params.since.or_else(|| {
+        headers
+            .get("last-event-id")
+            .and_then(|id| id.to_str().ok())
+            .and_then(|id| id.parse().ok())
+    });
+
+    let tail = roverd
+        .app
+        .tail_service_logs(fq, query_params.build.unwrap_or(false), since)
+        .await
+        .map_err(|e| {
+            warn!("{:#?}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    let stream = futures::stream::unfold(tail, |mut tail| async move {
+        match tail.next_line().await {
+            Ok((offset, line)) => {
+                let event = Event::default().id(offset.to_string()).data(line);
+                Some((Ok(event), tail))
+            }
+            Err(e) => {
+                warn!("stopped streaming logs: {:#?}", e);
+                None
+            }
+        }
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
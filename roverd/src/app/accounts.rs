@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::net::IpAddr;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{anyhow, Context};
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use openapi::models::{ApiToken, Role};
+use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+
+use crate::constants::*;
+use crate::error::Error;
+use crate::settings::Settings;
+use crate::util::write_atomically;
+
+use super::info::{write_rover_info, Info};
+
+/// An account from users.yaml, these exist next to the rover user from info.txt (which is
+/// always an admin).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct User {
+    pub name: String,
+    pub role: Role,
+
+    /// The hash of the password, in the same format as in info.txt.
+    pub password_hash: String,
+}
+
+/// Who made the request that is being handled, as established by the auth middleware.
+#[derive(Debug, Clone)]
+pub struct Caller {
+    /// The name of the account, API tokens do not have one.
+    pub name: Option<String>,
+    pub role: Role,
+    pub client: Option<IpAddr>,
+}
+
+tokio::task_local! {
+    /// Set for the handlers of all authenticated requests, for those that need more than the
+    /// role of the caller.
+    pub static CALLER: Caller;
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UsersFile {
+    #[serde(default)]
+    users: Vec<User>,
+}
+
+/// An API token as it is stored, of the secret only its sha256 digest is kept.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Token {
+    pub id: String,
+    pub name: String,
+    pub role: Role,
+    pub created: i64,
+    pub hash: String,
+}
+
+impl From<&Token> for ApiToken {
+    fn from(token: &Token) -> Self {
+        ApiToken::new(
+            token.id.clone(),
+            token.name.clone(),
+            token.role,
+            token.created,
+        )
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TokensFile {
+    #[serde(default)]
+    tokens: Vec<Token>,
+}
+
+/// Everyone that may use the API besides the rover user. The users are maintained by hand in
+/// users.yaml, the tokens are managed through the API and kept in tokens.yaml.
+#[derive(Debug, Default)]
+pub struct Accounts {
+    /// The rover user from info.txt, if that file could be read.
+    pub rover: Option<User>,
+    pub users: Vec<User>,
+    pub tokens: Vec<Token>,
+
+    /// Credentials that were verified recently, keyed by the sha256 digest of "name:password".
+    verified: HashMap<String, (Role, Instant)>,
+}
+
+impl Accounts {
+    /// Reads both files, either of them is allowed to be missing.
+    pub fn load(settings: &Settings) -> Result<Accounts, Error> {
+        let users = match read_if_exists::<UsersFile>(&settings.users_file())? {
+            Some(file) => file.users,
+            None => vec![],
+        };
+        let tokens = match read_if_exists::<TokensFile>(&settings.tokens_file())? {
+            Some(file) => file.tokens,
+            None => vec![],
+        };
+
+        Ok(Accounts {
+            users,
+            tokens,
+            ..Default::default()
+        })
+    }
+
+    /// The rover user takes precedence over an account with the same name in users.yaml.
+    pub fn find_user(&self, name: &str) -> Option<&User> {
+        self.rover
+            .iter()
+            .chain(self.users.iter())
+            .find(|u| u.name == name)
+    }
+
+    pub fn recently_verified(&self, key: &str) -> Option<Role> {
+        self.verified
+            .get(key)
+            .filter(|(_, at)| at.elapsed() < VERIFIED_LOGIN_DURATION)
+            .map(|(role, _)| *role)
+    }
+
+    pub fn remember_verified(&mut self, key: String, role: Role) {
+        self.verified
+            .retain(|_, (_, at)| at.elapsed() < VERIFIED_LOGIN_DURATION);
+        self.verified.insert(key, (role, Instant::now()));
+    }
+
+    /// Verified credentials are no longer valid once a password has changed.
+    pub fn forget_verified(&mut self) {
+        self.verified.clear();
+    }
+
+    /// Replaces the stored password hash of the user, in info.txt for the rover user and in
+    /// users.yaml for everyone else.
+    pub fn set_password_hash(
+        &mut self,
+        settings: &Settings,
+        info: &Info,
+        name: &str,
+        hash: String,
+    ) -> Result<(), Error> {
+        if let Some(rover) = self.rover.as_mut().filter(|u| u.name == name) {
+            let (Some(id), Some(rover_name)) = (info.rover_id, &info.rover_name) else {
+                return Err(Error::RoverFileFormat);
+            };
+            write_rover_info(&settings.info_file, id, rover_name, &hash)?;
+            rover.password_hash = hash;
+        } else if let Some(index) = self.users.iter().position(|u| u.name == name) {
+            let old = std::mem::replace(&mut self.users[index].password_hash, hash);
+            if let Err(e) = self.save_users(settings) {
+                self.users[index].password_hash = old;
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn token_role(&self, token: &str) -> Option<Role> {
+        let hash = sha256::digest(token);
+        self.tokens.iter().find(|t| t.hash == hash).map(|t| t.role)
+    }
+
+    pub fn save_users(&self, settings: &Settings) -> Result<(), Error> {
+        let contents = serde_yaml::to_string(&UsersFile {
+            users: self.users.clone(),
+        })?;
+
+        write_atomically(settings.users_file(), contents.as_bytes())
+            .map_err(|_| Error::AccountsFileIO)
+    }
+
+    pub fn save_tokens(&self, settings: &Settings) -> Result<(), Error> {
+        let contents = serde_yaml::to_string(&TokensFile {
+            tokens: self.tokens.clone(),
+        })?;
+
+        write_atomically(settings.tokens_file(), contents.as_bytes())
+            .map_err(|_| Error::AccountsFileIO)
+    }
+}
+
+fn read_if_exists<T: for<'de> Deserialize<'de>>(file: &str) -> Result<Option<T>, Error> {
+    if !Path::new(file).exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(file).map_err(|_| Error::AccountsFileIO)?;
+    Ok(Some(serde_yaml::from_str(&contents)?))
+}
+
+/// Random bytes from the kernel, used for salts and token ids and secrets.
+fn random_bytes(bytes: usize) -> Result<Vec<u8>, Error> {
+    let mut buf = vec![0u8; bytes];
+    fs::File::open("/dev/urandom")
+        .and_then(|mut f| f.read_exact(&mut buf))
+        .context("failed to read /dev/urandom")?;
+
+    Ok(buf)
+}
+
+pub fn random_hex(bytes: usize) -> Result<String, Error> {
+    Ok(random_bytes(bytes)?
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// Hashes a password with argon2id and a random salt, the result is a PHC string
+/// (`$argon2id$v=19$...`) that contains the parameters and the salt as well.
+pub fn hash_password(password: &str) -> Result<String, Error> {
+    let salt = SaltString::encode_b64(&random_bytes(16)?)
+        .map_err(|e| anyhow!("failed to encode salt: {}", e))?;
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow!("failed to hash password: {}", e))?;
+
+    Ok(hash.to_string())
+}
+
+/// Older installations store an unsalted sha256 digest (64 hex characters) instead of an
+/// argon2 hash, these are still accepted but replaced on the first successful login.
+pub fn is_legacy_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    if is_legacy_hash(hash) {
+        // Compared in constant time, so the time it takes does not give away the digest
+        let digest = sha256::digest(password);
+        return digest
+            .as_bytes()
+            .ct_eq(hash.to_ascii_lowercase().as_bytes())
+            .into();
+    }
+
+    PasswordHash::new(hash).is_ok_and(|parsed| {
+        Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok()
+    })
+}
+
+/// Failed logins of a single client.
+#[derive(Debug)]
+struct FailedLogins {
+    count: u32,
+    last: Instant,
+    locked_until: Option<Instant>,
+}
+
+/// Keeps track of failed logins per client address, a client that fails too often in a row
+/// is locked out for a while.
+#[derive(Debug, Default)]
+pub struct LoginThrottle {
+    clients: HashMap<IpAddr, FailedLogins>,
+}
+
+impl LoginThrottle {
+    pub fn is_locked(&self, client: IpAddr) -> bool {
+        self.clients
+            .get(&client)
+            .and_then(|f| f.locked_until)
+            .is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Registers a failed login and returns the number of failures in a row, along with
+    /// whether the client is locked out because of it.
+    pub fn failed(&mut self, client: IpAddr) -> (u32, bool) {
+        let now = Instant::now();
+        self.clients.retain(|_, f| {
+            now.duration_since(f.last) < FAILED_LOGIN_WINDOW
+                || f.locked_until.is_some_and(|until| now < until)
+        });
+
+        let failed = self.clients.entry(client).or_insert(FailedLogins {
+            count: 0,
+            last: now,
+            locked_until: None,
+        });
+        failed.count += 1;
+        failed.last = now;
+
+        let locked = failed.count % MAX_FAILED_LOGINS == 0;
+        if locked {
+            failed.locked_until = Some(now + LOGIN_LOCKOUT);
+        }
+
+        (failed.count, locked)
+    }
+
+    pub fn succeeded(&mut self, client: IpAddr) {
+        self.clients.remove(&client);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const CLIENT: IpAddr = IpAddr::V4(std::net::Ipv4Addr::LOCALHOST);
+
+    #[test]
+    fn test_argon2_round_trip() {
+        let hash = hash_password("correct horse").unwrap();
+
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(!is_legacy_hash(&hash));
+        assert!(verify_password("correct horse", &hash));
+        assert!(!verify_password("wrong horse", &hash));
+
+        // Every hash has its own salt
+        assert_ne!(hash, hash_password("correct horse").unwrap());
+    }
+
+    #[test]
+    fn test_legacy_hash() {
+        let hash = sha256::digest("debix");
+
+        assert!(is_legacy_hash(&hash));
+        assert!(is_legacy_hash(&hash.to_uppercase()));
+        assert!(verify_password("debix", &hash));
+        assert!(verify_password("debix", &hash.to_uppercase()));
+        assert!(!verify_password("debi", &hash));
+
+        assert!(!is_legacy_hash(&hash[1..]));
+        assert!(!is_legacy_hash(&format!("{}g", &hash[1..])));
+        assert!(!verify_password("debix", "not a hash"));
+    }
+
+    #[test]
+    fn test_throttle_locks_after_max_failures() {
+        let mut throttle = LoginThrottle::default();
+
+        for count in 1..MAX_FAILED_LOGINS {
+            assert_eq!(throttle.failed(CLIENT), (count, false));
+            assert!(!throttle.is_locked(CLIENT));
+        }
+        assert_eq!(throttle.failed(CLIENT), (MAX_FAILED_LOGINS, true));
+        assert!(throttle.is_locked(CLIENT));
+
+        // Other clients are not affected
+        assert!(!throttle.is_locked(IpAddr::V4(std::net::Ipv4Addr::new(10, 0, 0, 1))));
+
+        throttle.succeeded(CLIENT);
+        assert!(!throttle.is_locked(CLIENT));
+        assert_eq!(throttle.failed(CLIENT), (1, false));
+    }
+
+    #[test]
+    fn test_throttle_lockout_expires() {
+        let mut throttle = LoginThrottle::default();
+        for _ in 0..MAX_FAILED_LOGINS {
+            throttle.failed(CLIENT);
+        }
+
+        let expired = Instant::now() - Duration::from_secs(1);
+        throttle.clients.get_mut(&CLIENT).unwrap().locked_until = Some(expired);
+        assert!(!throttle.is_locked(CLIENT));
+
+        // Failures keep counting within the window, the client is locked out again after
+        // another round of them
+        for count in MAX_FAILED_LOGINS + 1..2 * MAX_FAILED_LOGINS {
+            assert_eq!(throttle.failed(CLIENT), (count, false));
+        }
+        assert_eq!(throttle.failed(CLIENT), (2 * MAX_FAILED_LOGINS, true));
+        assert!(throttle.is_locked(CLIENT));
+    }
+
+    #[test]
+    fn test_throttle_forgets_old_failures() {
+        let mut throttle = LoginThrottle::default();
+        for _ in 1..MAX_FAILED_LOGINS {
+            throttle.failed(CLIENT);
+        }
+
+        let old = Instant::now() - FAILED_LOGIN_WINDOW - Duration::from_secs(1);
+        throttle.clients.get_mut(&CLIENT).unwrap().last = old;
+
+        assert_eq!(throttle.failed(CLIENT), (1, false));
+    }
+}
@@ -45,15 +45,16 @@ pub struct BootSpec {
 pub struct BootSpecs(pub HashMap<FqBuf, BootSpec>);
 
 impl BootSpecs {
-    pub fn new(mut services: Vec<ValidatedService>) -> Self {
-        // Transceiver outputs to START_PORT
+    pub fn new(mut services: Vec<ValidatedService>, tuner_bound: bool) -> Self {
+        // Transceiver outputs to START_PORT, without a transceiver roverd publishes the
+        // values set through the tuning endpoint there itself (if it could bind it)
         let mut tuning = BootSpecTuning {
-            enabled: false,
+            enabled: tuner_bound,
             address: format!("{}:{}", DATA_ADDRESS, START_PORT),
         };
 
         let transeiver_service = (0..services.len()).find_map(|i| {
-            if services[i].0.name == "transceiver" {
+            if services[i].0.name == TRANSCEIVER_NAME {
                 tuning.enabled = true;
                 Some(services.swap_remove(i))
             } else {
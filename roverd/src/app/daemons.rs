@@ -18,6 +18,7 @@ use tokio::{
 };
 use tracing::{error, info, warn};
 
+use crate::settings::Settings;
 use crate::util::*;
 use crate::{command::ParsedCommand, constants::*};
 use crate::{error::Error, time_now};
@@ -25,6 +26,7 @@ use crate::{error::Error, time_now};
 use super::bootspec::{Input, Stream};
 use super::{
     bootspec::{BootSpec, BootSpecTuning},
+    logs::rotate_log,
     process::Process,
 };
 
@@ -38,7 +40,7 @@ pub struct DaemonManager {
 /// via the API, but getters would be nice. For now, this is hardcoded and error prone,
 /// but it also will not change for the forseeable future.
 impl DaemonManager {
-    pub async fn new() -> Result<Self, Error> {
+    pub async fn new(settings: &Settings) -> Result<Self, Error> {
         let shutdown_tx = broadcast::channel::<()>(1).0;
 
         // Set up signal handler
@@ -66,23 +68,27 @@ impl DaemonManager {
         // First make sure the daemons are installed, this can fail which will
         // put roverd in a non operational state.
         let display_download = async move {
-            match download_and_install_service(&DISPLAY_FETCH_URL.to_string(), true).await {
+            match download_and_install_service(settings, &DISPLAY_FETCH_URL.to_string(), None, true)
+                .await
+            {
                 Ok(fq) => Ok(fq),
                 Err(e) => {
                     warn!("was not able to get latest daemon at {}", DISPLAY_FETCH_URL);
                     warn!("{:?}", e);
-                    find_latest_daemon("vu-ase", "display")
+                    find_latest_daemon(settings, "vu-ase", "display")
                 }
             }
         };
 
         let battery_download = async move {
-            match download_and_install_service(&BATTERY_FETCH_URL.to_string(), true).await {
+            match download_and_install_service(settings, &BATTERY_FETCH_URL.to_string(), None, true)
+                .await
+            {
                 Ok(fq) => Ok(fq),
                 Err(e) => {
                     warn!("was not able to get latest daemon at {}", BATTERY_FETCH_URL);
                     warn!("{:?}", e);
-                    find_latest_daemon("vu-ase", "battery")
+                    find_latest_daemon(settings, "vu-ase", "battery")
                 }
             }
         };
@@ -92,12 +98,20 @@ impl DaemonManager {
         let display_fq = display_result?;
         let battery_fq = battery_result?;
 
-        let display_service_file = std::fs::read_to_string(display_fq.path()).map_err(|_| {
-            Error::ServiceNotFound(format!("could not find {} on disk", display_fq.path()))
-        })?;
-        let battery_service_file = std::fs::read_to_string(battery_fq.path()).map_err(|_| {
-            Error::ServiceNotFound(format!("could not find {} on disk", battery_fq.path()))
-        })?;
+        let display_service_file =
+            std::fs::read_to_string(display_fq.path(settings)).map_err(|_| {
+                Error::ServiceNotFound(format!(
+                    "could not find {} on disk",
+                    display_fq.path(settings)
+                ))
+            })?;
+        let battery_service_file =
+            std::fs::read_to_string(battery_fq.path(settings)).map_err(|_| {
+                Error::ServiceNotFound(format!(
+                    "could not find {} on disk",
+                    battery_fq.path(settings)
+                ))
+            })?;
 
         let display_service: Service = serde_yaml::from_str(&display_service_file)
             .with_context(|| format!("failed to parse {}", display_service_file))?;
@@ -150,10 +164,15 @@ impl DaemonManager {
                 last_exit_code: 0,
                 name: display_service.0.name.clone(),
                 status: ProcessStatus::Stopped,
-                log_file: PathBuf::from(display_fq.log_file()),
+                log_file: PathBuf::from(display_fq.log_file(settings)),
                 injected_env: display_injected_env.clone(),
                 faults: 0,
                 start_time: time_now!() as i64,
+                restart: None,
+                inputs: vec![],
+                stop_timeout: Duration::from_millis(DEFAULT_STOP_TIMEOUT),
+                restarts: 0,
+                last_restart: None,
             },
             Process {
                 fq: battery_fq.clone(),
@@ -162,25 +181,35 @@ impl DaemonManager {
                 last_exit_code: 0,
                 name: battery_service.0.name.clone(),
                 status: ProcessStatus::Stopped,
-                log_file: PathBuf::from(battery_fq.log_file()),
+                log_file: PathBuf::from(battery_fq.log_file(settings)),
                 injected_env: battery_injected_env.clone(),
                 faults: 0,
                 start_time: time_now!() as i64,
+                restart: None,
+                inputs: vec![],
+                stop_timeout: Duration::from_millis(DEFAULT_STOP_TIMEOUT),
+                restarts: 0,
+                last_restart: None,
             },
         ];
 
         let daemon_manager = DaemonManager { shutdown_tx };
-        daemon_manager.start_daemons(procs).await?;
+        daemon_manager.start_daemons(settings, procs).await?;
 
         Ok(daemon_manager)
     }
 
     #[allow(unreachable_code)]
-    pub async fn start_daemons(&self, procs: Vec<Process>) -> Result<(), Error> {
+    pub async fn start_daemons(
+        &self,
+        settings: &Settings,
+        procs: Vec<Process>,
+    ) -> Result<(), Error> {
         for proc in procs {
             let parsed_command = ParsedCommand::try_from(&proc.command)?;
             let mut shutdown_rx = self.shutdown_tx.subscribe();
 
+            rotate_log(settings, &proc.log_file)?;
             let log_file = create_log_file(&proc.log_file)?;
             let stdout = Stdio::from(
                 log_file
@@ -188,14 +217,14 @@ impl DaemonManager {
                     .with_context(|| format!("failed to clone log file {:?}", log_file))?,
             );
             let stderr = Stdio::from(log_file);
-            let full_path = format!("{}/{}", proc.fq.dir(), &parsed_command.program);
+            let full_path = format!("{}/{}", proc.fq.dir(settings), &parsed_command.program);
             fs::set_permissions(&full_path, Permissions::from_mode(0o755))
                 .with_context(|| format!("failed to set 755 permissions to {}", full_path))?;
             let mut command = Command::new(&parsed_command.program);
             command
                 .args(&parsed_command.arguments)
                 .env(ENV_KEY, &proc.injected_env)
-                .current_dir(proc.fq.dir())
+                .current_dir(proc.fq.dir(settings))
                 .stdout(stdout)
                 .stderr(stderr);
 
@@ -0,0 +1,81 @@
+use std::net::IpAddr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use openapi::models::{PipelineStatus, ProcessStatus};
+use serde::Serialize;
+use tokio::sync::broadcast::Sender;
+
+use crate::service::FqBuf;
+use crate::time_now;
+
+/// A state transition inside roverd, sent to everyone subscribed to the event bus.
+#[derive(Debug, Clone, Serialize)]
+pub struct Event {
+    /// Milliseconds since epoch at which the event happened.
+    pub time: i64,
+
+    #[serde(flatten)]
+    pub kind: EventKind,
+}
+
+/// Serialized as a JSON object with a `type` field, e.g.
+/// `{"time": ..., "type": "process_exited", "service": {...}, "exit_code": 1, ...}`
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventKind {
+    /// The pipeline moved between Empty, Startable and Started.
+    PipelineStatus {
+        status: PipelineStatus,
+    },
+
+    /// A process of an enabled service changed its status (started, terminated, killed).
+    ProcessStatus {
+        service: FqBuf,
+        status: ProcessStatus,
+        pid: Option<u32>,
+    },
+
+    /// A process exited on its own, `crashed` is set if it did not exit successfully.
+    ProcessExited {
+        service: FqBuf,
+        exit_code: i32,
+        crashed: bool,
+        faults: u32,
+    },
+
+    /// A process was restarted according to its restart policy.
+    ProcessRestarted {
+        service: FqBuf,
+        pid: Option<u32>,
+        restarts: u32,
+    },
+
+    ServiceInstalled {
+        service: FqBuf,
+    },
+
+    ServiceDeleted {
+        service: FqBuf,
+    },
+
+    BuildFinished {
+        service: FqBuf,
+        success: bool,
+    },
+
+    /// A client failed to log in too many times in a row and is locked out for a while.
+    LoginLockout {
+        client: IpAddr,
+        failures: u32,
+    },
+}
+
+/// Sends an event to all subscribers. Having no subscribers is not an error, the event is
+/// then simply dropped.
+pub fn emit(events: &Sender<Event>, kind: EventKind) {
+    let event = Event {
+        time: time_now!() as i64,
+        kind,
+    };
+    events.send(event).ok();
+}
@@ -9,7 +9,8 @@ use tracing::error;
 // The script in src/build.rs populates a const containing the version
 include!(concat!(env!("OUT_DIR"), "/version.rs"));
 
-use crate::constants::*;
+use crate::settings::Settings;
+use crate::util::write_atomically;
 
 #[derive(Debug, Clone)]
 pub struct Info {
@@ -20,15 +21,18 @@ pub struct Info {
     pub rover_id: Option<i32>,
     pub rover_name: Option<String>,
     pub username: String,
-    pub password: Option<String>,
     pub error_msg: Option<String>,
+
+    /// Fingerprint of the certificate of the HTTPS listener, if it is running.
+    pub tls_fingerprint: Option<String>,
 }
 
 impl Info {
-    pub fn new() -> Self {
+    /// Also returns the password hash of the rover user, which is kept with the other accounts.
+    pub fn new(settings: &Settings) -> (Self, Option<String>) {
         let mut status = DaemonStatus::Operational;
 
-        let (id, name, hash) = match read_rover_info() {
+        let (id, name, hash) = match read_rover_info(&settings.info_file) {
             Ok((id, name, hash)) => (Some(id), Some(name), Some(hash)),
             Err(e) => {
                 error!("{:?}", e);
@@ -41,30 +45,32 @@ impl Info {
         let kernel_version = System::kernel_version().unwrap_or("".to_string());
         let os = format!("{} {}", distribution_name, kernel_version);
 
-        Self {
+        let info = Self {
             status,
             version: VERSION.to_string(),
             start_time: SystemTime::now(),
             os,
             rover_id: id,
             rover_name: name,
-            username: ROVER_USER.to_string(),
-            password: hash,
+            username: settings.user.clone(),
             error_msg: None,
-        }
+            tls_fingerprint: None,
+        };
+
+        (info, hash)
     }
 }
 
 /// Reads the /etc/rover file and parses out basic information. Expects to see
-/// the rover's id on the first line, the rover's name on the second, and a sha256
-/// hash of the user password on the last line.
-fn read_rover_info() -> Result<(i32, String, String), Error> {
-    if !Path::new(ROVER_INFO_FILE).exists() {
+/// the rover's id on the first line, the rover's name on the second, and the hash
+/// of the user password on the last line (argon2, or a legacy unsalted sha256 digest).
+fn read_rover_info(info_file: &str) -> Result<(i32, String, String), Error> {
+    if !Path::new(info_file).exists() {
         return Err(Error::RoverFileNotFound);
     }
 
-    let text = read_to_string(ROVER_INFO_FILE)
-        .with_context(|| format!("failed to read_to_string on file {}", ROVER_INFO_FILE))?;
+    let text = read_to_string(info_file)
+        .with_context(|| format!("failed to read_to_string on file {}", info_file))?;
 
     let text = text.split_whitespace().collect::<Vec<&str>>();
     if text.len() < 3 {
@@ -78,3 +84,29 @@ fn read_rover_info() -> Result<(i32, String, String), Error> {
 
     Ok((id, rover_name, pass_hash))
 }
+
+/// The name ends up in info.txt, which is split on whitespace, so only letters, digits,
+/// dashes and underscores are allowed.
+pub fn validate_rover_name(name: &str) -> Result<(), Error> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::RoverNameInvalid(name.to_string()))
+    }
+}
+
+/// Writes the /etc/rover file in the format read_rover_info expects, atomically so a crash
+/// can never leave the rover without its password.
+pub fn write_rover_info(info_file: &str, id: i32, name: &str, hash: &str) -> Result<(), Error> {
+    let contents = format!("{}\n{}\n{}\n", id, name, hash);
+
+    write_atomically(info_file, contents.as_bytes())
+        .with_context(|| format!("failed to write {}", info_file))?;
+
+    Ok(())
+}
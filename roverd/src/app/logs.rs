@@ -0,0 +1,442 @@
+use std::cmp;
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::Context;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tracing::info;
+
+use crate::constants::*;
+use crate::error::Error;
+use crate::settings::Settings;
+
+/// Follows a log file as it is being written to, similar to `tail -f`. Every line is returned
+/// together with the byte offset right after it, so a client that reconnects can continue where
+/// it left off by passing that offset back in.
+#[derive(Debug)]
+pub struct LogTail {
+    path: String,
+    /// Device and inode of the file that is followed, the log files in the log dir are
+    /// relinked to a new file for every run.
+    file: Option<(u64, u64)>,
+    offset: u64,
+    partial: Vec<u8>,
+    lines: VecDeque<(u64, String)>,
+}
+
+impl LogTail {
+    /// Starts following the file at byte offset `since`, or at the current end of the file if it
+    /// is not given. The file does not need to exist yet.
+    pub async fn new(path: String, since: Option<u64>) -> Result<Self, Error> {
+        let (len, file) = file_state(&path).await;
+        let offset = since.unwrap_or(len);
+
+        Ok(LogTail {
+            path,
+            file,
+            offset,
+            partial: vec![],
+            lines: VecDeque::new(),
+        })
+    }
+
+    /// Waits until a complete line has been written and returns it along with its end offset.
+    pub async fn next_line(&mut self) -> Result<(u64, String), Error> {
+        loop {
+            if let Some(line) = self.lines.pop_front() {
+                return Ok(line);
+            }
+
+            if !self.read_new().await? {
+                tokio::time::sleep(Duration::from_millis(LOG_POLL_INTERVAL)).await;
+            }
+        }
+    }
+
+    /// Reads everything that was appended since the last read, returns false if there was nothing.
+    async fn read_new(&mut self) -> Result<bool, Error> {
+        let (len, file) = file_state(&self.path).await;
+
+        // The path points to another file now (the log was relinked to a new run), of which
+        // the offset means nothing
+        if file.is_some() && file != self.file {
+            if self.file.is_some() {
+                info!("{} was replaced, following it from the start", self.path);
+                self.offset = 0;
+                self.partial.clear();
+            }
+            self.file = file;
+        }
+
+        // The file got truncated or recreated, so start reading from the beginning again
+        if len < self.offset {
+            info!("{} was truncated, following it from the start", self.path);
+            self.offset = 0;
+            self.partial.clear();
+        }
+
+        if len == self.offset {
+            return Ok(false);
+        }
+
+        let mut file = File::open(&self.path)
+            .await
+            .with_context(|| format!("failed to open {}", self.path))?;
+        file.seek(SeekFrom::Start(self.offset))
+            .await
+            .with_context(|| format!("failed to seek in {}", self.path))?;
+
+        let mut buffer = vec![];
+        file.take(len - self.offset)
+            .read_to_end(&mut buffer)
+            .await
+            .with_context(|| format!("failed to read from {}", self.path))?;
+
+        // Only hand out complete lines, the remainder is kept until its newline is written
+        let mut position = self.offset;
+        for byte in buffer {
+            position += 1;
+            if byte == b'\n' {
+                let line = String::from_utf8_lossy(&self.partial);
+                let line = line.trim_end_matches('\r').to_string();
+                self.lines.push_back((position, line));
+                self.partial.clear();
+            } else {
+                self.partial.push(byte);
+            }
+        }
+        self.offset = len;
+
+        Ok(true)
+    }
+}
+
+/// Length and (device, inode) of the file the path points to, a missing file is empty.
+async fn file_state(path: &str) -> (u64, Option<(u64, u64)>) {
+    match tokio::fs::metadata(path).await {
+        Ok(metadata) => (metadata.len(), Some((metadata.dev(), metadata.ino()))),
+        Err(_) => (0, None),
+    }
+}
+
+/// Path of the n-th rotated file of a log, e.g. `/tmp/roverlog/a/b/1.0.0.1.gz`.
+fn rotated_path(path: &Path, n: u32, compressed: bool) -> PathBuf {
+    let mut rotated = path.as_os_str().to_owned();
+    rotated.push(format!(".{}", n));
+    if compressed {
+        rotated.push(".gz");
+    }
+    PathBuf::from(rotated)
+}
+
+/// Rotates the log file if it grew beyond the configured size limit and returns whether it did.
+/// The contents are copied to `.1` (optionally compressed) after which the file is truncated,
+/// that way processes which still have it opened in append mode keep logging to it.
+pub fn rotate_log(settings: &Settings, path: &Path) -> Result<bool, Error> {
+    let len = match fs::metadata(path) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Ok(false),
+    };
+
+    if settings.log_max_size == 0 || len < settings.log_max_size {
+        return Ok(false);
+    }
+
+    info!("rotating {:?} ({} bytes)", path, len);
+
+    // Shift the existing rotated files up by one, dropping the oldest. Both the compressed
+    // and plain variants are handled since log_compress may have changed in between.
+    for n in (1..=settings.log_retention).rev() {
+        for compressed in [false, true] {
+            let from = rotated_path(path, n, compressed);
+            if !from.exists() {
+                continue;
+            }
+            if n == settings.log_retention {
+                fs::remove_file(&from).with_context(|| format!("failed to remove {:?}", from))?;
+            } else {
+                let to = rotated_path(path, n + 1, compressed);
+                fs::rename(&from, &to)
+                    .with_context(|| format!("failed to move {:?} to {:?}", from, to))?;
+            }
+        }
+    }
+
+    if settings.log_retention > 0 {
+        let to = rotated_path(path, 1, settings.log_compress);
+        let mut source =
+            fs::File::open(path).with_context(|| format!("failed to open {:?}", path))?;
+        let target = fs::File::create(&to).with_context(|| format!("failed to create {:?}", to))?;
+
+        if settings.log_compress {
+            let mut encoder = GzEncoder::new(target, Compression::default());
+            io::copy(&mut source, &mut encoder)
+                .with_context(|| format!("failed to compress {:?} into {:?}", path, to))?;
+            encoder
+                .finish()
+                .with_context(|| format!("failed to compress {:?} into {:?}", path, to))?;
+        } else {
+            let mut target = target;
+            io::copy(&mut source, &mut target)
+                .with_context(|| format!("failed to copy {:?} to {:?}", path, to))?;
+        }
+    }
+
+    fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|file| file.set_len(0))
+        .with_context(|| format!("failed to truncate {:?}", path))?;
+
+    Ok(true)
+}
+
+/// All existing files of a log ordered from newest to oldest: the log itself followed by
+/// its rotated files.
+pub fn log_files(settings: &Settings, path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    if path.exists() {
+        files.push(path.to_path_buf());
+    }
+
+    for n in 1..=settings.log_retention {
+        for compressed in [false, true] {
+            let rotated = rotated_path(path, n, compressed);
+            if rotated.exists() {
+                files.push(rotated);
+            }
+        }
+    }
+
+    files
+}
+
+/// Returns the last `num_lines` lines of a log, continuing into the rotated files if the
+/// current one does not contain enough lines.
+pub fn read_last_lines(
+    settings: &Settings,
+    path: &Path,
+    num_lines: usize,
+) -> Result<Vec<String>, Error> {
+    let files = log_files(settings, path);
+    if files.is_empty() {
+        return Err(Error::NoLogsFound);
+    }
+
+    let mut lines: Vec<String> = vec![];
+    for file in files {
+        if lines.len() >= num_lines {
+            break;
+        }
+        let remaining = num_lines - lines.len();
+
+        let mut older = if file.extension().is_some_and(|ext| ext == "gz") {
+            read_last_lines_compressed(&file, remaining)?
+        } else {
+            read_last_lines_plain(&file, remaining)?
+        };
+        older.append(&mut lines);
+        lines = older;
+    }
+
+    Ok(lines)
+}
+
+fn read_last_lines_compressed(path: &Path, num_lines: usize) -> Result<Vec<String>, Error> {
+    let file = fs::File::open(path).with_context(|| format!("failed to open {:?}", path))?;
+    let mut contents = String::new();
+    GzDecoder::new(file)
+        .read_to_string(&mut contents)
+        .with_context(|| format!("failed to decompress {:?}", path))?;
+
+    let lines: Vec<String> = contents.lines().map(String::from).collect();
+    let skip = lines.len().saturating_sub(num_lines);
+    Ok(lines.into_iter().skip(skip).collect())
+}
+
+fn read_last_lines_plain(path: &Path, num_lines: usize) -> Result<Vec<String>, Error> {
+    let file = fs::File::open(path).map_err(|_| Error::NoLogsFound)?;
+    let mut reader = BufReader::new(file);
+
+    // Seek to the end of the file
+    let mut position = reader
+        .seek(SeekFrom::End(0))
+        .with_context(|| format!("failed to seek in {:?}", path))?;
+
+    // Read the file in reverse until the lines are complete, one more newline than the number
+    // of lines is needed since the first line read can be partial. Lines can span chunks, so
+    // the chunks are only split into lines at the end.
+    let mut tail: Vec<u8> = Vec::new();
+    let mut newlines = 0;
+    while newlines <= num_lines && position > 0 {
+        // Adjust buffer size based on remaining file size
+        let chunk_size = cmp::min(position, LOG_READ_CHUNK_SIZE) as usize;
+        position -= chunk_size as u64;
+        reader
+            .seek(SeekFrom::Start(position))
+            .with_context(|| format!("failed to seek in {:?}", path))?;
+
+        // Read the chunk and put it in front of what was read before
+        let mut buffer = vec![0; chunk_size];
+        reader
+            .get_mut()
+            .read_exact(&mut buffer)
+            .with_context(|| format!("failed to read chunk for {:?}", path))?;
+        newlines += buffer.iter().filter(|b| **b == b'\n').count();
+        buffer.append(&mut tail);
+        tail = buffer;
+    }
+
+    let contents = String::from_utf8_lossy(&tail);
+    let lines: Vec<String> = contents.lines().map(String::from).collect();
+    let skip = lines.len().saturating_sub(num_lines);
+    Ok(lines.into_iter().skip(skip).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Settings that rotate logs of more than 10 bytes and keep two rotated files, along with
+    /// a fresh directory for the logs.
+    fn settings(test: &str, compress: bool) -> (Settings, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("roverd-logs-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let settings = Settings {
+            log_max_size: 10,
+            log_retention: 2,
+            log_compress: compress,
+            ..Settings::default()
+        };
+        (settings, dir)
+    }
+
+    fn write_lines(path: &Path, lines: std::ops::RangeInclusive<u32>) {
+        let contents: String = lines.map(|n| format!("line {}\n", n)).collect();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn lines(lines: std::ops::RangeInclusive<u32>) -> Vec<String> {
+        lines.map(|n| format!("line {}", n)).collect()
+    }
+
+    #[test]
+    fn test_rotate_log() {
+        let (settings, dir) = settings("rotate", false);
+        let log = dir.join("service.log");
+
+        // Nothing to rotate yet
+        assert!(!rotate_log(&settings, &log).unwrap());
+        fs::write(&log, "short\n").unwrap();
+        assert!(!rotate_log(&settings, &log).unwrap());
+
+        for (n, first) in (1..=3).zip([1, 4, 7]) {
+            write_lines(&log, first..=first + 2);
+            assert!(rotate_log(&settings, &log).unwrap(), "rotation {}", n);
+            assert_eq!(fs::metadata(&log).unwrap().len(), 0);
+        }
+
+        // The oldest rotated file is dropped
+        assert_eq!(
+            fs::read_to_string(dir.join("service.log.1")).unwrap(),
+            "line 7\nline 8\nline 9\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("service.log.2")).unwrap(),
+            "line 4\nline 5\nline 6\n"
+        );
+        assert!(!dir.join("service.log.3").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_read_last_lines_across_rotations() {
+        for compress in [false, true] {
+            let (settings, dir) = settings(&format!("read-{}", compress), compress);
+            let log = dir.join("service.log");
+            assert!(matches!(
+                read_last_lines(&settings, &log, 5),
+                Err(Error::NoLogsFound)
+            ));
+
+            write_lines(&log, 1..=3);
+            rotate_log(&settings, &log).unwrap();
+            write_lines(&log, 4..=6);
+            rotate_log(&settings, &log).unwrap();
+            write_lines(&log, 7..=8);
+
+            let read = |n| read_last_lines(&settings, &log, n).unwrap();
+            assert_eq!(read(0), Vec::<String>::new());
+            assert_eq!(read(2), lines(7..=8));
+            assert_eq!(read(3), lines(6..=8));
+            assert_eq!(read(6), lines(3..=8));
+            assert_eq!(read(8), lines(1..=8));
+            assert_eq!(read(100), lines(1..=8));
+
+            // Right after a rotation the current log is empty
+            fs::write(&log, "").unwrap();
+            assert_eq!(read(4), lines(3..=6));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_read_lines_across_chunks() {
+        let (settings, dir) = settings("chunks", false);
+        let log = dir.join("service.log");
+
+        // Lines that are longer than a chunk, and ones that end right at a chunk boundary
+        let long = "x".repeat(LOG_READ_CHUNK_SIZE as usize + 100);
+        let exact = "y".repeat(LOG_READ_CHUNK_SIZE as usize - 1);
+        fs::write(&log, format!("first\n{}\n{}\nlast", long, exact)).unwrap();
+
+        let read = read_last_lines(&settings, &log, 3).unwrap();
+        assert_eq!(read, vec![long.clone(), exact.clone(), "last".to_string()]);
+        let read = read_last_lines(&settings, &log, 10).unwrap();
+        assert_eq!(
+            read,
+            vec!["first".to_string(), long, exact, "last".to_string()]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tail_follows_relinked_log() {
+        let (_, dir) = settings("tail-relink", false);
+        let link = dir.join("log");
+        let (first, second) = (dir.join("run-1.log"), dir.join("run-2.log"));
+        write_lines(&first, 1..=2);
+        std::os::unix::fs::symlink(&first, &link).unwrap();
+
+        let mut tail = LogTail::new(link.to_string_lossy().to_string(), None)
+            .await
+            .unwrap();
+
+        // The new run already wrote more than the old one, so its length alone does not show
+        // that the file changed
+        write_lines(&second, 1..=3);
+        fs::remove_file(&link).unwrap();
+        std::os::unix::fs::symlink(&second, &link).unwrap();
+
+        let next = tokio::time::timeout(Duration::from_secs(5), tail.next_line())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(next, (7, "line 1".to_string()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
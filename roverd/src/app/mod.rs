@@ -2,49 +2,71 @@ use anyhow::{anyhow, Context};
 use axum_extra::extract::Multipart;
 use openapi::models::*;
 use process::{PipelineStats, Process, SpawnedProcess};
-use rovervalidate::config::{Configuration, ValidatedConfiguration};
+use rovervalidate::config::{
+    Configuration, ConfigurationOverride, Override, ValidatedConfiguration,
+};
 use rovervalidate::pipeline::interface::{Pipeline, RunnablePipeline};
-use rovervalidate::service::{Service, ValidatedService};
+use rovervalidate::service::{Policy, Service, ValidatedService};
 use rovervalidate::validate::Validate;
-use service::{Fq, FqBuf, FqBufVec, FqVec};
+use service::{Fq, FqBuf, FqVec};
+use sha2::{Digest, Sha256};
 use state::{Dormant, Operating, RoverState};
 use std::cmp;
 use std::collections::HashMap;
-use std::fs::File;
-use std::fs::{self, remove_dir_all, remove_file, Permissions};
+use std::fs::{self, Permissions};
 use std::io::{BufRead, BufReader, Write};
-use std::io::{Read, Seek, SeekFrom};
 use std::marker::PhantomData;
+use std::net::IpAddr;
 use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::Arc;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use sysinfo::{CpuRefreshKind, MemoryRefreshKind, Pid, ProcessRefreshKind, RefreshKind, System};
-use tokio::process::Command;
+use tokio::process::{Child, Command};
 use tokio::select;
-use tokio::sync::{broadcast, broadcast::Sender, Mutex, RwLock};
+use tokio::sync::{broadcast, broadcast::Sender, watch, Mutex, RwLock};
+use tokio::task::JoinHandle;
 use tracing::{error, info, warn};
 
 use crate::command::ParsedCommand;
 use crate::error::Error;
+use crate::settings::Settings;
+use crate::util::staging::{lock_service_dir, Staging};
 use crate::util::*;
 use crate::{constants::*, time_now};
-
+use accounts::{
+    hash_password, is_legacy_hash, random_hex, verify_password, Accounts, LoginThrottle, Token,
+    User,
+};
+use events::{emit, Event, EventKind};
+use info::{validate_rover_name, write_rover_info};
+use logs::{read_last_lines, rotate_log, LogTail};
+use profiles::{list_profiles, read_profile, remove_profile, validate_profile_name, write_profile};
+use runs::{link_log_file, record_run, Run};
+use tuning::{validate_tuning, Tuner};
+
+pub mod accounts;
 mod bootspec;
 pub mod daemons;
+pub mod events;
 pub mod info;
+pub mod logs;
 pub mod process;
+pub mod profiles;
+pub mod runs;
 pub mod service;
 pub mod state;
+pub mod tuning;
 
 /// The main struct that implements functions called from the api and holds all objects
 /// in memory necessary for operation. Info member holds static information derived mostly
 /// from the
 #[derive(Debug, Clone)]
 pub struct Roverd {
-    /// Information related to the roverd daemon, contains status.
-    pub info: info::Info,
+    /// Information related to the roverd daemon, contains status. The name of the rover can
+    /// change at runtime, so it is shared between all clones.
+    pub info: Arc<RwLock<info::Info>>,
 
     /// Run-time data structures of the Rover, interacts with the file system
     /// and spawns processes, so must be read/write locked.
@@ -52,12 +74,42 @@ pub struct Roverd {
 }
 
 impl Roverd {
-    pub async fn new() -> Result<Self, Error> {
-        let info = info::Info::new();
+    pub async fn new(settings: Arc<Settings>) -> Result<Self, Error> {
+        let (mut info, password_hash) = info::Info::new(&settings);
+
+        // Roverd can continue with the restored pipeline, but the user should know about it
+        if let Ok((_, Some(reason))) = load_config(&settings) {
+            if info.status == DaemonStatus::Operational {
+                info.status = DaemonStatus::Recoverable;
+            }
+            info.error_msg = Some(format!(
+                "rover.yaml was invalid and has been restored, see the logs: {:?}",
+                reason
+            ));
+        }
+
+        // Without its accounts roverd can still be used by the rover user, who is an admin
+        let mut accounts = Accounts::load(&settings).unwrap_or_else(|e| {
+            error!("failed to load the accounts: {:?}", e);
+            if info.status == DaemonStatus::Operational {
+                info.status = DaemonStatus::Recoverable;
+            }
+            info.error_msg = Some(format!(
+                "users.yaml or tokens.yaml is invalid, only the rover user can log in until it is fixed and roverd is restarted: {:?}",
+                e
+            ));
+            Accounts::default()
+        });
+        accounts.rover = password_hash.map(|password_hash| User {
+            name: info.username.clone(),
+            role: Role::Admin,
+            password_hash,
+        });
 
         let roverd = Self {
-            info,
+            info: Arc::new(RwLock::new(info)),
             app: App {
+                settings,
                 processes: Arc::new(RwLock::new(vec![])),
                 spawned: Arc::new(RwLock::new(vec![])),
                 stats: Arc::new(RwLock::new(PipelineStats {
@@ -65,8 +117,12 @@ impl Roverd {
                     last_start: None,
                     last_stop: None,
                     last_restart: None,
+                    run: None,
                 })),
+                watchers: Arc::new(Mutex::new(vec![])),
                 shutdown_tx: broadcast::channel::<()>(1).0,
+                events: broadcast::channel::<Event>(EVENT_CHANNEL_CAPACITY).0,
+                tuner: Arc::new(Mutex::new(None)),
                 built_services: Arc::new(RwLock::new(HashMap::new())),
                 sysinfo: Arc::new(RwLock::new(System::new_with_specifics(
                     RefreshKind::nothing()
@@ -74,23 +130,24 @@ impl Roverd {
                         .with_cpu(CpuRefreshKind::everything())
                         .with_memory(MemoryRefreshKind::everything()),
                 ))),
+                accounts: Arc::new(RwLock::new(accounts)),
+                logins: Arc::new(Mutex::new(LoginThrottle::default())),
+                password_guesses: Arc::new(Mutex::new(LoginThrottle::default())),
             },
         };
 
         // Validate the pipeline found in the yaml file, if it is not valid
-        // empty it.
-        let initial_pipieline_status = match roverd.app.get_valid_pipeline().await {
-            Ok(_) => PipelineStatus::Startable,
-            Err(_) => PipelineStatus::Empty,
-        };
-
-        {
-            let mut stats = roverd.app.stats.write().await;
-            stats.status = initial_pipieline_status;
+        // it is kept so the user can fix it.
+        if let Err(e) = roverd.app.revalidate_pipeline().await {
+            warn!("failed to validate the pipeline: {:?}", e);
+            roverd.app.stats.write().await.status = PipelineStatus::Empty;
+        }
 
-            if roverd.info.status != DaemonStatus::Operational {
-                warn!("did not initialize successfully {:#?}", roverd.info);
-            }
+        if roverd.info.read().await.status != DaemonStatus::Operational {
+            warn!(
+                "did not initialize successfully {:#?}",
+                roverd.info.read().await
+            );
         }
 
         Ok(roverd)
@@ -127,6 +184,9 @@ impl AsRef<Roverd> for Roverd {
 
 #[derive(Debug, Clone)]
 pub struct App {
+    /// Runtime settings, determines where everything lives on the filesystem.
+    pub settings: Arc<Settings>,
+
     /// Contains the "application view" of process after validation. In-between start / stop
     /// runs this vec remains unchanged.
     pub processes: Arc<RwLock<Vec<Process>>>,
@@ -137,28 +197,74 @@ pub struct App {
     /// Overall status of the pipeline.
     pub stats: Arc<RwLock<PipelineStats>>,
 
+    /// The tasks that watch the spawned processes, each ends once its process has stopped.
+    pub watchers: Arc<Mutex<Vec<JoinHandle<()>>>>,
+
     /// Broadcast channel to send shutdown command for termination.
     pub shutdown_tx: Sender<()>,
 
+    /// Event bus with all pipeline, process and service state transitions.
+    pub events: Sender<Event>,
+
+    /// Publishes tuned values to the services while the pipeline is started, if roverd
+    /// owns the tuning channel.
+    pub tuner: Arc<Mutex<Option<Tuner>>>,
+
     // Look up the last built time of a service on disk.
     pub built_services: Arc<RwLock<HashMap<FqBuf, i64>>>,
 
     // System information initialized once
     pub sysinfo: Arc<RwLock<System>>,
+
+    /// Accounts and API tokens that can be used besides the rover user.
+    pub accounts: Arc<RwLock<Accounts>>,
+
+    /// Failed logins per client, to lock out clients that are guessing passwords.
+    pub logins: Arc<Mutex<LoginThrottle>>,
+
+    /// Wrong current passwords per client when changing a password. These are kept apart from
+    /// the failed logins, since every request with valid credentials resets those.
+    pub password_guesses: Arc<Mutex<LoginThrottle>>,
 }
 
 impl App {
+    /// Validates the pipeline again if the given service is enabled in it, since it was just
+    /// replaced or deleted. Returns whether the pipeline is invalid because of it.
     pub async fn should_invalidate(&self, fq_buf: &FqBuf) -> Result<bool, Error> {
-        let mut config = get_config().await?;
+        let config = get_config(&self.settings).await?;
         let enabled_fq = FqVec::try_from(&config.enabled)?;
-        let pipeline_invalidated = enabled_fq.0.contains(&Fq::from(fq_buf));
+        if !enabled_fq.0.contains(&Fq::from(fq_buf)) {
+            return Ok(false);
+        }
+
+        Ok(self.revalidate_pipeline().await? == PipelineStatus::Invalid)
+    }
 
-        if pipeline_invalidated {
-            config.enabled.clear();
-            update_config(&config)?;
+    /// Validates the pipeline in rover.yaml and updates the pipeline status accordingly. An invalid
+    /// pipeline is kept as is, so the user can see what broke and fix it.
+    pub async fn revalidate_pipeline(&self) -> Result<PipelineStatus, Error> {
+        let config = get_config(&self.settings).await?;
+
+        let status = if config.enabled.is_empty() {
+            PipelineStatus::Empty
+        } else {
+            match validate_config(&config) {
+                Ok(_) => PipelineStatus::Startable,
+                Err(Error::PipelineValidation(invalid_services, errors)) => {
+                    warn!("pipeline is invalid: {:?} {:?}", invalid_services, errors);
+                    PipelineStatus::Invalid
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        let mut stats = self.stats.write().await;
+        if stats.status != status {
+            stats.status = status;
+            emit(&self.events, EventKind::PipelineStatus { status });
         }
 
-        Ok(pipeline_invalidated)
+        Ok(status)
     }
 
     /// Downloads the service from the specified url.
@@ -167,62 +273,113 @@ impl App {
         body: &FetchPostRequest,
         _: RoverState<Dormant>,
     ) -> Result<(FqBuf, bool), Error> {
-        let fq_buf = download_and_install_service(&body.url, false).await?;
+        let fq_buf =
+            download_and_install_service(&self.settings, &body.url, body.sha256.as_deref(), false)
+                .await?;
+        emit(
+            &self.events,
+            EventKind::ServiceInstalled {
+                service: fq_buf.clone(),
+            },
+        );
         let invalidate_pipline = self.should_invalidate(&fq_buf).await?;
         Ok((fq_buf, invalidate_pipline))
     }
 
+    /// Receives an uploaded service archive and installs it. The archive is streamed to disk
+    /// chunk by chunk, and checked against the SHA-256 digest and the signature if the client
+    /// sent them along.
     pub async fn receive_upload(
         &self,
         mut body: Multipart,
         _: RoverState<Dormant>,
     ) -> Result<(FqBuf, bool), Error> {
-        if let Some(field) = body
+        // Every upload gets its own staging directory, so concurrent installs can not
+        // overwrite each other's archive
+        let staging = Staging::new(&self.settings)?;
+        let archive = staging.archive();
+
+        let mut digest = None;
+        let mut expected_digest = None;
+
+        while let Some(mut field) = body
             .next_field()
             .await
             .map_err(|_| Error::ServiceUploadBadPayload)?
         {
-            // Extract bytes from payload
-            let data = field
-                .bytes()
-                .await
-                .map_err(|_| Error::ServiceUploadBadPayload)?;
+            if field.name() == Some(UPLOAD_DIGEST_FIELD) {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|_| Error::ServiceUploadBadPayload)?;
+                expected_digest = Some(text.trim().to_lowercase());
+                continue;
+            }
+
+            if field.name() == Some(UPLOAD_SIGNATURE_FIELD) {
+                let text = field
+                    .text()
+                    .await
+                    .map_err(|_| Error::ServiceUploadBadPayload)?;
+                let signature = staging.signature();
+                fs::write(&signature, text)
+                    .with_context(|| format!("failed to write to {}", signature))?;
+                continue;
+            }
 
-            // Ignore errors, since filesystem can be in any state and
-            // get a clean slate of the zip file
-            let _ = remove_file(ZIP_FILE);
-            let _ = remove_dir_all(UNZIPPED_DIR);
+            // Only a single archive can be uploaded at once
+            if digest.is_some() {
+                return Err(Error::ServiceUploadBadPayload);
+            }
+
+            // Create the archive file handle
+            let mut file = fs::File::create(&archive)
+                .with_context(|| format!("failed to create file {}", archive))?;
+            let mut hasher = Sha256::new();
+
+            while let Some(chunk) = field
+                .chunk()
+                .await
+                .map_err(|_| Error::ServiceUploadBadPayload)?
+            {
+                hasher.update(&chunk);
+                file.write_all(&chunk)
+                    .with_context(|| format!("failed to write data to {}", archive))?;
+            }
 
-            // Create the zip file handle
-            let mut file = fs::OpenOptions::new()
-                .create(true)
-                .truncate(true)
-                .write(true)
-                .open(ZIP_FILE)
-                .with_context(|| format!("failed to create file {}", ZIP_FILE))?;
+            digest = Some(format!("{:x}", hasher.finalize()));
+        }
 
-            file.write_all(&data)
-                .with_context(|| format!("failed to write data to {}", ZIP_FILE))?;
+        let digest = digest.ok_or(Error::ServiceUploadBadPayload)?;
+        if expected_digest.is_some_and(|expected| expected != digest) {
+            return Err(Error::ServiceUploadDigestMismatch);
+        }
 
-            let fq_buf = extract_fq_from_zip().await?;
+        let fq_buf =
+            extract_fq_from_archive(&self.settings, &staging, self.settings.signature_policy)
+                .await?;
 
-            // syncing can overwrite the current contents
-            // if service_exists(&Fq::from(&fq_buf))? {
-            //     return Err(Error::ServiceAlreadyExists);
-            // }
+        // syncing can overwrite the current contents
+        // if service_exists(&Fq::from(&fq_buf))? {
+        //     return Err(Error::ServiceAlreadyExists);
+        // }
 
-            install_service(&fq_buf).await?;
+        install_service(&self.settings, &staging, &fq_buf).await?;
+        emit(
+            &self.events,
+            EventKind::ServiceInstalled {
+                service: fq_buf.clone(),
+            },
+        );
 
-            let invalidate_pipline = self.should_invalidate(&fq_buf).await?;
+        let invalidate_pipline = self.should_invalidate(&fq_buf).await?;
 
-            return Ok((fq_buf, invalidate_pipline));
-        }
-        Err(Error::ServiceUploadBadPayload)
+        Ok((fq_buf, invalidate_pipline))
     }
 
     /// Returns all authors from the rover directory
     pub async fn get_authors(&self) -> Result<Vec<String>, Error> {
-        list_dir_contents("")
+        list_dir_contents(&self.settings, "")
     }
 
     /// Returns all services given an author
@@ -230,7 +387,7 @@ impl App {
         &self,
         path_params: ServicesAuthorGetPathParams,
     ) -> Result<Vec<String>, Error> {
-        list_dir_contents(&path_params.author.to_string())
+        list_dir_contents(&self.settings, &path_params.author.to_string())
     }
 
     /// Returns all versions given a service
@@ -238,13 +395,17 @@ impl App {
         &self,
         path_params: ServicesAuthorServiceGetPathParams,
     ) -> Result<Vec<String>, Error> {
-        list_dir_contents(format!("{}/{}", path_params.author, path_params.service).as_str())
+        list_dir_contents(
+            &self.settings,
+            format!("{}/{}", path_params.author, path_params.service).as_str(),
+        )
     }
 
     /// Returns a valid service given a fully qualified name
     pub async fn get_service(&self, fq: FqBuf) -> Result<ValidatedService, Error> {
-        let contents = fs::read_to_string(fq.path())
-            .map_err(|_| Error::ServiceNotFound(format!("Could not find {} on disk", fq.path())))?;
+        let path = fq.path(&self.settings);
+        let contents = fs::read_to_string(&path)
+            .map_err(|_| Error::ServiceNotFound(format!("Could not find {} on disk", path)))?;
         let service =
             serde_yaml::from_str::<rovervalidate::service::Service>(&contents)?.validate()?;
 
@@ -261,40 +422,34 @@ impl App {
     ) -> Result<bool, Error> {
         let delete_fq = FqBuf::from(path_params);
 
-        // Get the current configuration from disk
-        let mut config = get_config().await?;
-
-        let mut built_services = self.built_services.write().await;
-
-        let mut should_reset = false;
-        // Return whether or not the service was enabled and if it was,
-        // reset the pipeline
-        let enabled_fq_vec = FqBufVec::try_from(&config.enabled)?.0;
+        self.built_services.write().await.remove(&delete_fq);
 
-        if enabled_fq_vec.contains(&delete_fq) {
-            should_reset = true;
-            config.enabled.clear();
-            update_config(&config)?;
-
-            built_services.remove(&delete_fq);
-        }
-
-        // Remove the service to delete from the filesystem
-        if Path::new(&delete_fq.dir()).exists() {
-            std::fs::remove_dir_all(delete_fq.dir())
-                .with_context(|| format!("failed to remove {}", delete_fq.dir()))?;
+        // Remove the service to delete from the filesystem, unless it is still being installed
+        let delete_dir = delete_fq.dir(&self.settings);
+        let _lock = lock_service_dir(&delete_dir).await;
+        if Path::new(&delete_dir).exists() {
+            std::fs::remove_dir_all(&delete_dir)
+                .with_context(|| format!("failed to remove {}", delete_dir))?;
         } else {
             return Err(Error::ServiceNotFound(format!(
                 "wanted to delete {}, but it never existed",
-                delete_fq.dir()
+                delete_dir
             )));
         }
 
-        Ok(should_reset)
+        // The pipeline is kept, but it is invalid if the service was enabled
+        let invalidated_pipeline = self.should_invalidate(&delete_fq).await?;
+
+        emit(
+            &self.events,
+            EventKind::ServiceDeleted { service: delete_fq },
+        );
+
+        Ok(invalidated_pipeline)
     }
 
     /// Performs the build command for a given service and does so by instantiating a login shell
-    /// of the debix user. It also first changes into the service's directory.
+    /// of the rover user. It also first changes into the service's directory.
     pub async fn build_service(
         &self,
         params: ServicesAuthorServiceVersionPostPathParams,
@@ -307,7 +462,10 @@ impl App {
             .commands
             .build
             .ok_or_else(|| Error::BuildCommandMissing)?;
-        let log_file = create_log_file(&PathBuf::from(fq.build_log_file()))?;
+        let build_log_file = fq.build_log_file(&self.settings);
+        let service_dir = fq.dir(&self.settings);
+        rotate_log(&self.settings, Path::new(&build_log_file))?;
+        let log_file = create_log_file(&PathBuf::from(&build_log_file))?;
         let stdout = Stdio::from(
             log_file
                 .try_clone()
@@ -316,28 +474,40 @@ impl App {
         let stderr = Stdio::from(log_file);
 
         let mut built_services = self.built_services.write().await;
-        let corrected_build_command = format!("cd {} ; {}", fq.dir(), build_string.as_str());
+        let corrected_build_command = format!("cd {} ; {}", service_dir, build_string.as_str());
 
-        // Run the build command in the login shell of the debix user (necessary for build deps)
+        // Run the build command in the login shell of the rover user (necessary for build deps)
         match Command::new("su")
-            .args(["-", "debix", "-c", corrected_build_command.as_str()])
+            .args([
+                "-",
+                self.settings.user.as_str(),
+                "-c",
+                corrected_build_command.as_str(),
+            ])
             .stdout(stdout)
             .stderr(stderr)
-            .current_dir(fq.dir())
+            .current_dir(&service_dir)
             .spawn()
         {
             Ok(mut child) => match child.wait().await {
                 Ok(exit_status) => {
+                    emit(
+                        &self.events,
+                        EventKind::BuildFinished {
+                            service: fq.clone(),
+                            success: exit_status.success(),
+                        },
+                    );
                     if !exit_status.success() {
                         // Build was not successful, return logs
-                        let file = std::fs::File::open(fq.build_log_file())
-                            .with_context(|| format!("failed to open {}", fq.build_log_file()))?;
+                        let file = std::fs::File::open(&build_log_file)
+                            .with_context(|| format!("failed to open {}", build_log_file))?;
                         let reader = BufReader::new(file);
                         let lines: Vec<String> = reader
                             .lines()
                             .collect::<Result<Vec<String>, _>>()
                             .with_context(|| {
-                                format!("failed to collect lines from {}", fq.build_log_file())
+                                format!("failed to collect lines from {}", build_log_file)
                             })?;
                         return Err(Error::BuildLog(lines));
                     } else {
@@ -367,38 +537,363 @@ impl App {
         incoming_pipeline: Vec<PipelinePostRequestInner>,
         _: RoverState<Dormant>,
     ) -> Result<(), Error> {
-        let services = FqBufVec::from(incoming_pipeline).0;
+        let services = incoming_pipeline
+            .iter()
+            .map(|incoming| {
+                // Overrides are checked against the options declared in the service.yaml
+                let configuration = incoming
+                    .configuration
+                    .iter()
+                    .flatten()
+                    .map(|c| {
+                        Ok(ConfigurationOverride {
+                            name: c.name.clone(),
+                            value: serde_json::from_str(c.value.0.get())?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok((FqBuf::from(incoming), configuration))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let config = validate_services(&self.settings, &services)?;
+        self.replace_pipeline(&config).await
+    }
+
+    /// Overwrites rover.yaml with an already validated configuration and updates the
+    /// pipeline status accordingly.
+    async fn replace_pipeline(&self, config: &Configuration) -> Result<(), Error> {
+        update_config(&self.settings, config)?;
 
-        let mut valid_services = vec![];
+        let mut stats = self.stats.write().await;
 
-        for enabled in &services {
-            let service_file = std::fs::read_to_string(enabled.path()).map_err(|_| {
-                Error::ServiceNotFound(format!("could not find or read {}", enabled.path()))
-            })?;
-            let service: Service = serde_yaml::from_str(&service_file)?;
-            valid_services.push(service.validate()?);
+        if config.enabled.is_empty() {
+            stats.status = PipelineStatus::Empty;
+        } else {
+            stats.status = PipelineStatus::Startable;
         }
+        emit(
+            &self.events,
+            EventKind::PipelineStatus {
+                status: stats.status,
+            },
+        );
 
-        let _ = Pipeline::new(valid_services).validate()?;
+        Ok(())
+    }
 
-        // Here we have a valid pipeline, so rover.yaml can be overwritten
-        let mut config = get_config().await?;
-        config.enabled.clear();
+    /// Retrieves all stored pipeline profiles.
+    pub async fn get_profiles(&self) -> Result<Vec<Profile>, Error> {
+        let mut profiles = vec![];
 
-        // Services are valid since we didn't return earlier
-        for service in services {
-            config.enabled.push(service.path())
+        for name in list_profiles(&self.settings)? {
+            let config = read_profile(&self.settings, &name)?;
+
+            let mut services = vec![];
+            for enabled in &config.enabled {
+                let fq = FqBuf::try_from(enabled)?;
+                let configuration = config
+                    .overrides
+                    .iter()
+                    .flatten()
+                    .find(|o| o.service == *enabled)
+                    .map(|o| {
+                        o.configuration
+                            .iter()
+                            .map(|c| {
+                                Ok(openapi::models::ConfigurationOverride {
+                                    name: c.name.clone(),
+                                    value: ConfigurationOverrideValue(
+                                        serde_json::value::to_raw_value(&c.value)?,
+                                    ),
+                                })
+                            })
+                            .collect::<Result<Vec<_>, Error>>()
+                    })
+                    .transpose()?;
+
+                services.push(ProfileService {
+                    name: fq.name,
+                    version: fq.version,
+                    author: fq.author,
+                    configuration,
+                });
+            }
+
+            profiles.push(Profile { name, services });
         }
 
-        update_config(&config)?;
+        Ok(profiles)
+    }
+
+    /// Saves the services as a named profile, which is only done if they form a valid
+    /// pipeline. An existing profile with the same name is replaced.
+    pub async fn save_profile(
+        &self,
+        name: String,
+        incoming_services: Vec<ProfileService>,
+    ) -> Result<(), Error> {
+        validate_profile_name(&name)?;
+
+        let services = incoming_services
+            .iter()
+            .map(|incoming| {
+                let configuration = incoming
+                    .configuration
+                    .iter()
+                    .flatten()
+                    .map(|c| {
+                        Ok(ConfigurationOverride {
+                            name: c.name.clone(),
+                            value: serde_json::from_str(c.value.0.get())?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok((FqBuf::from(incoming), configuration))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let config = validate_services(&self.settings, &services)?;
+        write_profile(&self.settings, &name, &config)?;
+        info!("saved profile {}", name);
 
-        let mut stats = self.stats.write().await;
+        Ok(())
+    }
 
-        if config.enabled.is_empty() {
-            stats.status = PipelineStatus::Empty;
-        } else {
-            stats.status = PipelineStatus::Startable;
+    pub async fn delete_profile(&self, name: String) -> Result<(), Error> {
+        remove_profile(&self.settings, &name)?;
+        info!("deleted profile {}", name);
+        Ok(())
+    }
+
+    /// Sets the pipeline to the services of a profile. The services might have changed since
+    /// the profile was saved, so it is validated again.
+    pub async fn activate_profile(
+        &self,
+        name: String,
+        _: RoverState<Dormant>,
+    ) -> Result<(), Error> {
+        let config = read_profile(&self.settings, &name)?;
+
+        validate_config(&config)?;
+        self.replace_pipeline(&config).await?;
+        info!("activated profile {}", name);
+
+        Ok(())
+    }
+
+    /// Looks up the role of the user with the given password. A legacy hash of the password
+    /// is replaced by an argon2 hash on the first successful login.
+    pub async fn authenticate(
+        &self,
+        info: &RwLock<info::Info>,
+        name: &str,
+        password: &str,
+    ) -> Option<Role> {
+        let key = sha256::digest(format!("{}:{}", name, password));
+        if let Some(role) = self.accounts.read().await.recently_verified(&key) {
+            return Some(role);
+        }
+
+        let user = self.verify_user(name, password).await?;
+
+        // The password could have been changed while it was being verified
+        let mut accounts = self.accounts.write().await;
+        if accounts.find_user(name)?.password_hash != user.password_hash {
+            return None;
+        }
+
+        if is_legacy_hash(&user.password_hash) {
+            let info = info.read().await;
+            let result = hash_password(password)
+                .and_then(|hash| accounts.set_password_hash(&self.settings, &info, name, hash));
+            match result {
+                Ok(()) => info!("migrated the password hash of {} to argon2", name),
+                Err(e) => warn!("failed to migrate the password hash of {}: {:?}", name, e),
+            }
+        }
+        accounts.remember_verified(key, user.role);
+
+        Some(user.role)
+    }
+
+    /// The user with the given name, if the password is right.
+    async fn verify_user(&self, name: &str, password: &str) -> Option<User> {
+        let user = self.accounts.read().await.find_user(name)?.clone();
+
+        // Verifying takes a while on purpose, so it should not block the other requests
+        let (pw, hash) = (password.to_string(), user.password_hash.clone());
+        let valid = tokio::task::spawn_blocking(move || verify_password(&pw, &hash))
+            .await
+            .unwrap_or(false);
+
+        valid.then_some(user)
+    }
+
+    /// Changes the password of the rover user (in info.txt) or of an account from users.yaml,
+    /// after checking the current password. A client that gets it wrong too often is locked
+    /// out, just like for failed logins. Without a current password the password is reset,
+    /// which is up to the caller to allow.
+    pub async fn change_password(
+        &self,
+        info: &RwLock<info::Info>,
+        client: Option<IpAddr>,
+        name: &str,
+        password: Option<&str>,
+        new_password: &str,
+    ) -> Result<(), Error> {
+        if new_password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(Error::PasswordTooShort);
+        }
+
+        let current_hash = match password {
+            Some(password) => self.verify_current_password(client, name, password).await?,
+            None => match self.accounts.read().await.find_user(name) {
+                Some(user) => user.password_hash.clone(),
+                None => return Err(Error::AccountNotFound(name.to_string())),
+            },
+        };
+
+        let new_password = new_password.to_string();
+        let hash = tokio::task::spawn_blocking(move || hash_password(&new_password))
+            .await
+            .context("failed to hash password")??;
+
+        let mut accounts = self.accounts.write().await;
+        if accounts.find_user(name).map(|u| &u.password_hash) != Some(&current_hash) {
+            return Err(Error::PasswordIncorrect);
+        }
+        accounts.set_password_hash(&self.settings, &*info.read().await, name, hash)?;
+        accounts.forget_verified();
+        info!("changed the password of {}", name);
+
+        Ok(())
+    }
+
+    /// Checks the current password before it is changed and returns its hash.
+    async fn verify_current_password(
+        &self,
+        client: Option<IpAddr>,
+        name: &str,
+        password: &str,
+    ) -> Result<String, Error> {
+        if let Some(client) = client {
+            if self.password_guesses.lock().await.is_locked(client) {
+                return Err(Error::PasswordGuessesLockedOut);
+            }
+        }
+
+        let Some(user) = self.verify_user(name, password).await else {
+            if let Some(client) = client {
+                if self.record_failure(&self.password_guesses, client).await {
+                    return Err(Error::PasswordGuessesLockedOut);
+                }
+            }
+            return Err(Error::PasswordIncorrect);
+        };
+        if let Some(client) = client {
+            self.password_guesses.lock().await.succeeded(client);
+        }
+
+        Ok(user.password_hash)
+    }
+
+    /// Renames the rover, info.txt is rewritten so the new name is kept after a restart.
+    pub async fn rename_rover(&self, info: &RwLock<info::Info>, name: &str) -> Result<(), Error> {
+        validate_rover_name(name)?;
+
+        let accounts = self.accounts.read().await;
+        let mut info = info.write().await;
+
+        let (Some(id), Some(rover)) = (info.rover_id, &accounts.rover) else {
+            return Err(Error::RoverFileFormat);
+        };
+        write_rover_info(&self.settings.info_file, id, name, &rover.password_hash)?;
+
+        let old_name = info.rover_name.replace(name.to_string());
+        info!("renamed the rover from {:?} to {}", old_name, name);
+
+        Ok(())
+    }
+
+    /// Keeps track of the failed logins of a client, a client that fails too often is locked
+    /// out for a while, which is logged and sent to the event bus.
+    pub async fn record_login(&self, client: IpAddr, success: bool) {
+        if success {
+            self.logins.lock().await.succeeded(client);
+            return;
+        }
+
+        self.record_failure(&self.logins, client).await;
+    }
+
+    /// Registers a failed attempt of the client, returns whether it is locked out because of it.
+    async fn record_failure(&self, throttle: &Mutex<LoginThrottle>, client: IpAddr) -> bool {
+        let (failures, locked) = throttle.lock().await.failed(client);
+        warn!("failed login from {} ({} in a row)", client, failures);
+        if locked {
+            warn!(
+                "locked out {} for {} seconds after {} failed logins",
+                client,
+                LOGIN_LOCKOUT.as_secs(),
+                failures
+            );
+            emit(&self.events, EventKind::LoginLockout { client, failures });
+        }
+
+        locked
+    }
+
+    pub async fn get_tokens(&self) -> Vec<ApiToken> {
+        let accounts = self.accounts.read().await;
+        accounts.tokens.iter().map(ApiToken::from).collect()
+    }
+
+    /// Creates an API token with the given role. Only the digest of the secret is stored, so
+    /// the returned secret can not be retrieved again later.
+    pub async fn create_token(&self, name: &str, role: Role) -> Result<(String, String), Error> {
+        if name.trim().is_empty() {
+            return Err(Error::TokenNameInvalid);
+        }
+
+        let mut accounts = self.accounts.write().await;
+
+        let mut id = random_hex(4)?;
+        while accounts.tokens.iter().any(|t| t.id == id) {
+            id = random_hex(4)?;
+        }
+        let secret = format!("{}{}", TOKEN_PREFIX, random_hex(24)?);
+
+        accounts.tokens.push(Token {
+            id: id.clone(),
+            name: name.to_string(),
+            role,
+            created: time_now!() as i64,
+            hash: sha256::digest(&secret),
+        });
+        if let Err(e) = accounts.save_tokens(&self.settings) {
+            accounts.tokens.pop();
+            return Err(e);
+        }
+        info!("created {} token {} ({})", role, id, name);
+
+        Ok((id, secret))
+    }
+
+    pub async fn revoke_token(&self, id: &str) -> Result<(), Error> {
+        let mut accounts = self.accounts.write().await;
+
+        let index = accounts
+            .tokens
+            .iter()
+            .position(|t| t.id == id)
+            .ok_or_else(|| Error::TokenNotFound(id.to_string()))?;
+        let token = accounts.tokens.remove(index);
+        if let Err(e) = accounts.save_tokens(&self.settings) {
+            accounts.tokens.insert(index, token);
+            return Err(e);
         }
+        info!("revoked token {} ({})", token.id, token.name);
 
         Ok(())
     }
@@ -407,11 +902,14 @@ impl App {
     pub async fn get_pipeline(&self) -> Result<Vec<PipelineGet200ResponseEnabledInner>, Error> {
         let stats = self.stats.read().await;
         if stats.status == PipelineStatus::Empty {
-            let config = Configuration { enabled: vec![] };
-            update_config(&config)?;
+            let config = Configuration {
+                enabled: vec![],
+                overrides: None,
+            };
+            update_config(&self.settings, &config)?;
         }
 
-        let conf = get_config().await?;
+        let conf = get_config(&self.settings).await?;
 
         let processes = self.processes.read().await;
 
@@ -457,6 +955,7 @@ impl App {
                                 version: fq.version,
                                 faults: p.faults as i32,
                                 exit: p.last_exit_code,
+                                last_restart: p.last_restart,
                             },
                         }
                     } else {
@@ -468,6 +967,7 @@ impl App {
                                 version: fq.version,
                                 faults: p.faults as i32,
                                 exit: p.last_exit_code,
+                                last_restart: p.last_restart,
                             },
                         }
                     }
@@ -480,6 +980,7 @@ impl App {
                         version: fq.version,
                         faults: 0,
                         exit: 0,
+                        last_restart: None,
                     },
                 },
             });
@@ -499,12 +1000,33 @@ impl App {
         // clear the existing processes and then add them again
         let mut processes = self.processes.write().await;
 
-        let bootspecs = bootspec::BootSpecs::new(runnable.services().clone()).0;
+        // Bind the tuning channel before any service subscribes to it, unless the
+        // transceiver provides it. Without it the pipeline still runs, just without tuning.
+        let tuner_bound = {
+            let mut tuner = self.tuner.lock().await;
+            if let Some(previous) = tuner.take() {
+                previous.close().await;
+            }
+            if !runnable
+                .services()
+                .iter()
+                .any(|s| s.0.name == TRANSCEIVER_NAME)
+            {
+                match Tuner::bind().await {
+                    Ok(t) => *tuner = Some(t),
+                    Err(e) => warn!("failed to bind the tuning channel: {:?}", e),
+                }
+            }
+            tuner.is_some()
+        };
+
+        let bootspecs = bootspec::BootSpecs::new(runnable.services().clone(), tuner_bound).0;
 
         let mut fqs = vec![];
         let mut service_data = vec![];
 
-        for service in runnable.services() {
+        // Processes are kept in the order they are started in
+        for service in runnable.start_order() {
             // Create bootspecs with missing inputs, since the first step is to hand out
             // ports. After knowing the ports of all outputs, we can fill in the inputs.
             // Since we are valid, a given input will _always_ have an output.
@@ -529,6 +1051,9 @@ impl App {
                 proc.command = service.0.commands.run.clone();
                 proc.injected_env = injected_env.clone();
                 proc.start_time = time_now!() as i64;
+                proc.restart = service.0.restart.clone();
+                proc.inputs = inputs(&service.0);
+                proc.stop_timeout = stop_timeout(&service.0);
             } else {
                 // The runnable service has not previously been added, so add a new one
                 processes.push(Process {
@@ -538,26 +1063,37 @@ impl App {
                     last_exit_code: 0,
                     name: service.0.name.clone(),
                     status: ProcessStatus::Stopped,
-                    log_file: PathBuf::from(fq.log_file()),
+                    log_file: PathBuf::from(fq.log_file(&self.settings)),
                     injected_env: injected_env.clone(),
                     faults: 0,
                     start_time: time_now!() as i64,
+                    restart: service.0.restart.clone(),
+                    inputs: inputs(&service.0),
+                    stop_timeout: stop_timeout(&service.0),
+                    restarts: 0,
+                    last_restart: None,
                 })
             }
         }
 
+        // Retained processes keep their previous position, so restore the start order
+        processes.sort_by_key(|p| fqs.iter().position(|fq| *fq == p.fq));
+
         Ok(())
     }
 
     /// This function is called by the API handler. It gets the runnable pipeline from disk,
     /// constructs the processes and finally spawns the processes.
     pub async fn start(&self, _: RoverState<Dormant>) -> Result<(), Error> {
-        let enabled_services = get_config().await?.enabled;
+        let enabled_services = get_config(&self.settings).await?.enabled;
 
         if enabled_services.is_empty() {
             return Err(Error::PipelineIsEmpty);
         }
 
+        // The processes of a pipeline that went down on its own may still be stopping
+        self.wait_for_stopped().await;
+
         // Pipeline validation step
         let runnable = self.get_valid_pipeline().await?;
 
@@ -578,6 +1114,7 @@ impl App {
             PipelineStatus::Startable => (),
             PipelineStatus::Empty => return Err(Error::PipelineIsEmpty),
             PipelineStatus::Started => return Err(Error::PipelineAlreadyStarted),
+            PipelineStatus::Invalid => return Err(Error::PipelineIsInvalid),
         }
 
         let mut spawned_procs = self.spawned.write().await;
@@ -585,39 +1122,24 @@ impl App {
 
         spawned_procs.clear();
 
+        // Every start is recorded as a new run, which gets its own set of log files
+        let run = Run::create(&self.settings, &procs)?;
+        let run_id = run.id;
         for p in &mut *procs {
-            let mut log_file = create_log_file(&p.log_file)?;
-
-            let cur_time = chrono::Local::now().format("%H:%M:%S");
-            if writeln!(log_file, "[{}] roverd spawned {}", cur_time, p.name).is_err() {
-                warn!("could not write log_line to file: {:?}", p.log_file)
-            };
-
-            let stdout = Stdio::from(
-                log_file
-                    .try_clone()
-                    .with_context(|| format!("failed to clone log file {:?}", log_file))?,
-            );
-            let stderr = Stdio::from(log_file);
+            let log_file = Run::log_file(&self.settings, run.id, &p.fq);
+            link_log_file(&self.settings, &p.fq, &log_file)?;
+            p.log_file = PathBuf::from(log_file);
+        }
+        stats.run = Some(run);
 
-            let parsed_command = ParsedCommand::try_from(&p.command)?;
+        // Names of the services that other services take inputs from
+        let producers: Vec<String> = procs.iter().flat_map(|p| p.inputs.clone()).collect();
 
-            let full_program_path = format!("{}/{}", p.fq.dir(), &parsed_command.program);
-            info!("executing {:?}", full_program_path);
+        // Processes are in start order, so producers are started before their consumers
+        for p in &mut *procs {
+            p.restarts = 0;
 
-            fs::set_permissions(full_program_path.clone(), Permissions::from_mode(0o755))
-                .with_context(|| {
-                    format!("failed to set permissions for {:?}", full_program_path)
-                })?;
-
-            let mut command = Command::new(parsed_command.program);
-            command
-                .args(parsed_command.arguments)
-                .env(ENV_KEY, p.injected_env.clone())
-                .current_dir(p.fq.dir())
-                .stdout(stdout)
-                .stderr(stderr);
-            match command.spawn() {
+            match spawn_process(&self.settings, p) {
                 Ok(child) => {
                     p.status = ProcessStatus::Running;
                     if let Some(id) = child.id() {
@@ -637,111 +1159,255 @@ impl App {
                         name: p.name.clone(),
                         child: Arc::from(Mutex::from(child)),
                     });
+
+                    if self.settings.start_delay > 0 && producers.contains(&p.name) {
+                        tokio::time::sleep(Duration::from_millis(self.settings.start_delay)).await;
+                    }
                 }
                 Err(e) => {
-                    let err_msg = format!("{}", e);
-                    warn!("failed to spawn process '{}': {}", p.name, &err_msg);
+                    warn!("failed to spawn process '{}': {:?}", p.name, &e);
                     p.faults += 1;
                     p.last_exit_code = 1;
                     self.cancel_start(&mut stats, &mut procs, &mut spawned_procs)
                         .await;
-                    return Err(Error::FailedToSpawnProcess(err_msg));
+                    return Err(e);
                 }
             }
         }
 
         stats.status = PipelineStatus::Started;
         stats.last_start = Some(time_now!() as i64);
+        emit(
+            &self.events,
+            EventKind::PipelineStatus {
+                status: stats.status,
+            },
+        );
+        for p in &*procs {
+            emit(
+                &self.events,
+                EventKind::ProcessStatus {
+                    service: p.fq.clone(),
+                    status: p.status,
+                    pid: p.last_pid,
+                },
+            );
+        }
+        record_run(&self.settings, &mut stats, &procs);
+
+        // Processes are stopped in reverse start order: a process only stops once the processes
+        // taking inputs from it have exited. Every task holds a sender which is dropped when the
+        // task ends, that is what the receivers given to its producers wait for.
+        let (stopped_txs, stopped_rxs): (Vec<_>, Vec<_>) =
+            spawned_procs.iter().map(|_| watch::channel(())).unzip();
+        let mut watchers = self.watchers.lock().await;
+
+        for ((index, spawned), stopped_tx) in spawned_procs
+            .clone()
+            .into_iter()
+            .enumerate()
+            .zip(stopped_txs)
+        {
+            // Only consumers that were started later are waited for, so cycles cannot deadlock
+            let mut consumers: Vec<watch::Receiver<()>> = procs
+                .iter()
+                .enumerate()
+                .filter(|(i, p)| *i > index && p.inputs.contains(&spawned.name))
+                .map(|(i, _)| stopped_rxs[i].clone())
+                .collect();
 
-        for spawned in spawned_procs.clone() {
             let mut shutdown_rx = self.shutdown_tx.subscribe();
             let process_shutdown_tx = self.shutdown_tx.clone();
 
             let procs_clone = Arc::clone(&self.processes);
             let stats_clone = Arc::clone(&self.stats);
+            let settings = Arc::clone(&self.settings);
+            let events = self.events.clone();
 
-            tokio::spawn(async move {
+            watchers.push(tokio::spawn(async move {
+                let _stopped_tx = stopped_tx;
                 let mut child = spawned.child.lock().await;
-                select! {
-                    // Wait for process completion
-                    result_status = child.wait() => {
-                        match result_status {
-                            Ok(exit_status) => {
-                                // Update the pipeline's status.
-                                let mut stats = stats_clone.write().await;
-                                stats.status = PipelineStatus::Startable;
-                                stats.last_restart = Some(time_now!() as i64);
+                loop {
+                    select! {
+                        // Wait for process completion
+                        result_status = child.wait() => {
+                            let exit_status = match result_status {
+                                Ok(exit_status) => exit_status,
+                                Err(e) => {
+                                    error!("error waiting for process {}: {}", spawned.name, e);
+                                    process_shutdown_tx.send(()).ok();
+                                    break;
+                                }
+                            };
+                            info!("child {} exited with status {}", spawned.name, exit_status);
 
-                                info!("child {} exited with status {}", spawned.name, exit_status);
-                                let exit_code = exit_status.code();
+                            let delay = {
+                                let mut stats = stats_clone.write().await;
                                 let mut procs_guard = procs_clone.write().await;
-
-                                if let Some(proc) = procs_guard.iter_mut().find(|p| p.fq == spawned.fq) {
-                                    proc.status = ProcessStatus::Stopped;
-                                    if let Some(e) = exit_code {
-                                        proc.last_exit_code = e;
-                                    }
-                                    if !exit_status.success() {
-                                        proc.faults += 1
+                                let proc = run_proc(&stats, &mut procs_guard, run_id, &spawned.fq);
+                                let Some(proc) = proc else {
+                                    break;
+                                };
+
+                                proc.status = ProcessStatus::Stopped;
+                                if let Some(e) = exit_status.code() {
+                                    proc.last_exit_code = e;
+                                }
+                                if !exit_status.success() {
+                                    proc.faults += 1
+                                }
+                                emit(&events, EventKind::ProcessExited {
+                                    service: proc.fq.clone(),
+                                    exit_code: proc.last_exit_code,
+                                    crashed: !exit_status.success(),
+                                    faults: proc.faults,
+                                });
+
+                                let delay = restart_delay(proc, exit_status.success());
+                                match delay {
+                                    Some(_) => proc.restarts += 1,
+                                    None => {
+                                        // Not restarted according to its policy, so the whole
+                                        // pipeline goes down.
+                                        stats.status = PipelineStatus::Startable;
+                                        stats.last_restart = Some(time_now!() as i64);
+                                        emit(&events, EventKind::PipelineStatus {
+                                            status: stats.status,
+                                        });
+                                        process_shutdown_tx.send(()).ok();
                                     }
                                 }
-                                process_shutdown_tx.send(()).ok();
-                            }
-                            Err(e) => {
-                                error!("error waiting for process {}: {}", spawned.name, e);
-                                process_shutdown_tx.send(()).ok();
+                                record_run(&settings, &mut stats, &procs_guard);
+                                delay
+                            };
+
+                            let Some(delay) = delay else {
+                                break;
+                            };
+
+                            info!("restarting {} in {:?}", spawned.name, delay);
+                            select! {
+                                _ = tokio::time::sleep(delay) => (),
+                                _ = shutdown_rx.recv() => break,
                             }
-                        }
-                    }
-                    _ = shutdown_rx.recv() => {
-                        // We have been sent a terminate signal, so end the process
-
-                        // Update the pipeline's status.
-                        let mut stats = stats_clone.write().await;
-                        stats.status = PipelineStatus::Startable;
 
-                        let mut procs_guard = procs_clone.write().await;
-                        if let Some(proc) = procs_guard.iter_mut().find(|p| p.fq == spawned.fq) {
-                            proc.status = ProcessStatus::Terminated;
-                            proc.last_exit_code = 0;
-                        }
-
-                        if let Some(id) = child.id() {
-                            info!("terminating {} pid ({})", spawned.name, id);
-                            unsafe {
-                                libc::kill(id as i32, libc::SIGTERM);
+                            let mut stats = stats_clone.write().await;
+                            if stats.status != PipelineStatus::Started {
+                                break;
+                            }
+                            let mut procs_guard = procs_clone.write().await;
+                            let proc = run_proc(&stats, &mut procs_guard, run_id, &spawned.fq);
+                            let Some(proc) = proc else {
+                                break;
+                            };
+
+                            match spawn_process(&settings, proc) {
+                                Ok(restarted) => {
+                                    let now = time_now!() as i64;
+                                    info!("restarted {:?} at {:?}", proc.name, restarted.id());
+                                    proc.status = ProcessStatus::Running;
+                                    proc.last_pid = restarted.id();
+                                    proc.start_time = now;
+                                    proc.last_restart = Some(now);
+                                    stats.last_restart = Some(now);
+                                    emit(&events, EventKind::ProcessRestarted {
+                                        service: proc.fq.clone(),
+                                        pid: proc.last_pid,
+                                        restarts: proc.restarts,
+                                    });
+                                    *child = restarted;
+                                    record_run(&settings, &mut stats, &procs_guard);
+                                }
+                                Err(e) => {
+                                    warn!("failed to restart process '{}': {:?}", proc.name, e);
+                                    proc.faults += 1;
+                                    proc.last_exit_code = 1;
+                                    stats.status = PipelineStatus::Startable;
+                                    emit(&events, EventKind::PipelineStatus {
+                                        status: stats.status,
+                                    });
+                                    record_run(&settings, &mut stats, &procs_guard);
+                                    process_shutdown_tx.send(()).ok();
+                                    break;
+                                }
                             }
                         }
+                        _ = shutdown_rx.recv() => {
+                            // We have been sent a terminate signal, so end the process
 
-                        // Wait a short while before checking if child still exists
-                        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
-
-                        // If the child has not terminated, kill it.
-                        match child.try_wait() {
-                            Ok(None) => {
-                                info!("process {} did not terminate, killing", spawned.name);
-                                if let Err(e) = child.kill().await {
-                                    error!("error killing process {:?}: {:?}", spawned.name, e);
+                            // Update the pipeline's status, unless a newer run took over.
+                            let stop_timeout = {
+                                let mut stats = stats_clone.write().await;
+                                let mut procs_guard = procs_clone.write().await;
+                                let proc = run_proc(&stats, &mut procs_guard, run_id, &spawned.fq);
+                                match proc {
+                                    Some(proc) => {
+                                        proc.status = ProcessStatus::Terminated;
+                                        proc.last_exit_code = 0;
+                                        let stop_timeout = proc.stop_timeout;
+                                        stats.status = PipelineStatus::Startable;
+                                        record_run(&settings, &mut stats, &procs_guard);
+                                        stop_timeout
+                                    }
+                                    None => Duration::from_millis(DEFAULT_STOP_TIMEOUT),
                                 }
-                            },
-                            Ok(Some(_)) => {},
-                            Err(e) => {
-                                error!("Error: {:?}", e);
-                                if let Err(e) = child.kill().await {
-                                    error!("error killing process {:?}: {:?}", spawned.name, e);
+                            };
+
+                            // The locks are released while waiting, so the consumers of this
+                            // process can stop first.
+                            for consumer in consumers.iter_mut() {
+                                consumer.changed().await.ok();
+                            }
+                            let killed = stop_child(&spawned.name, &mut child, stop_timeout).await;
+
+                            let mut stats = stats_clone.write().await;
+                            let mut procs_guard = procs_clone.write().await;
+                            let proc = run_proc(&stats, &mut procs_guard, run_id, &spawned.fq);
+                            if let Some(proc) = proc {
+                                if killed {
+                                    proc.status = ProcessStatus::Killed;
                                 }
+                                emit(&events, EventKind::ProcessStatus {
+                                    service: proc.fq.clone(),
+                                    status: proc.status,
+                                    pid: proc.last_pid,
+                                });
+                                record_run(&settings, &mut stats, &procs_guard);
                             }
+                            break;
                         }
                     }
                 }
-            });
+            }));
         }
 
+        // Keep the logs of the running processes within their size limit
+        let mut shutdown_rx = self.shutdown_tx.subscribe();
+        let settings = Arc::clone(&self.settings);
+        let log_files: Vec<PathBuf> = procs.iter().map(|p| p.log_file.clone()).collect();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(LOG_ROTATE_INTERVAL));
+            loop {
+                select! {
+                    _ = interval.tick() => {
+                        for log_file in &log_files {
+                            if let Err(e) = rotate_log(&settings, log_file) {
+                                warn!("failed to rotate {:?}: {:?}", log_file, e);
+                            }
+                        }
+                    }
+                    _ = shutdown_rx.recv() => break,
+                }
+            }
+        });
+
         Ok(())
     }
 
     /// If one process fails during the beginning of the starting procedure, we need to
-    /// kill all started children manually, set their states and clear the spawned vec.
+    /// kill all started children manually, set their states and clear the spawned vec. Only
+    /// the children spawned in this attempt are signalled, the pids of earlier runs may have
+    /// been reused by now.
     pub async fn cancel_start(
         &self,
         stats: &mut PipelineStats,
@@ -750,62 +1416,129 @@ impl App {
     ) {
         warn!("cancelled spawning process");
         stats.status = PipelineStatus::Startable;
-        for p in &mut *processes {
-            if let Some(pid) = p.last_pid {
+        emit(
+            &self.events,
+            EventKind::PipelineStatus {
+                status: stats.status,
+            },
+        );
+        for spawned in spawned_procs.iter() {
+            if let Some(pid) = spawned.child.lock().await.id() {
                 unsafe {
-                    libc::kill(pid as i32, libc::SIGKILL);
+                    libc::kill(-(pid as i32), libc::SIGKILL);
                 }
             }
-            p.status = ProcessStatus::Killed
+        }
+        for p in &mut *processes {
+            p.status = ProcessStatus::Killed;
+            emit(
+                &self.events,
+                EventKind::ProcessStatus {
+                    service: p.fq.clone(),
+                    status: p.status,
+                    pid: p.last_pid,
+                },
+            );
         }
 
         spawned_procs.clear();
+        record_run(&self.settings, stats, processes);
     }
 
-    /// If the pipeline is started, it will be stopped.
+    /// If the pipeline is started, it will be stopped. Returns once all of its processes have
+    /// exited, so it can be started again right away.
     pub async fn stop(&self, _: RoverState<Operating>) -> Result<(), Error> {
-        let mut stats = self.stats.write().await;
+        {
+            let mut stats = self.stats.write().await;
 
-        match stats.status {
-            PipelineStatus::Empty => return Err(Error::PipelineIsEmpty),
-            PipelineStatus::Startable => return Err(Error::NoRunningServices),
-            _ => (),
+            match stats.status {
+                PipelineStatus::Empty => return Err(Error::PipelineIsEmpty),
+                PipelineStatus::Startable => return Err(Error::NoRunningServices),
+                _ => (),
+            }
+            stats.status = PipelineStatus::Startable;
+            emit(
+                &self.events,
+                EventKind::PipelineStatus {
+                    status: stats.status,
+                },
+            );
+
+            stats.last_stop = Some(time_now!() as i64);
+            self.shutdown_tx.send(()).ok();
         }
-        stats.status = PipelineStatus::Startable;
 
-        stats.last_stop = Some(time_now!() as i64);
-        self.shutdown_tx.send(()).ok();
+        // The watchers need the stats to record how their process stopped
+        self.wait_for_stopped().await;
+
         let mut spawned = self.spawned.write().await;
         spawned.clear();
+
+        if let Some(tuner) = self.tuner.lock().await.take() {
+            tuner.close().await;
+        }
         Ok(())
     }
 
-    /// Reads the config file from disk and returns a RunnablePipeline if it is valid.
-    /// If it isn't valid it returns an error and resets the config.
-    pub async fn get_valid_pipeline(&self) -> Result<RunnablePipeline, Error> {
-        let mut config = get_config().await?;
-        let mut enabled_services: Vec<ValidatedService> = vec![];
-
-        let res = {
-            for enabled in &config.enabled {
-                let service_file = std::fs::read_to_string(enabled).map_err(|_| {
-                    Error::ServiceNotFound(format!("could not find or read {}", enabled))
-                })?;
-                let service: Service = serde_yaml::from_str(&service_file)?;
-                let validated = service.validate()?;
-                enabled_services.push(validated);
+    /// Waits for the watchers of the last run, which end once their process has exited.
+    async fn wait_for_stopped(&self) {
+        let watchers: Vec<JoinHandle<()>> = self.watchers.lock().await.drain(..).collect();
+        for watcher in watchers {
+            if let Err(e) = watcher.await {
+                error!("process watcher failed: {:?}", e);
             }
+        }
+    }
+
+    /// Publishes new values for tunable configuration values to the running services. All
+    /// values are checked against the configuration of the enabled services first, and are
+    /// kept in their bootspec so services that restart continue with the tuned values.
+    pub async fn tune(
+        &self,
+        values: Vec<(String, rovervalidate::service::Value)>,
+        _: RoverState<Operating>,
+    ) -> Result<(), Error> {
+        let mut procs = self.processes.write().await;
 
-            Pipeline::new(enabled_services).validate()
+        let mut bootspecs = vec![];
+        for p in procs.iter() {
+            bootspecs.push(serde_json::from_str::<bootspec::BootSpec>(&p.injected_env)?);
+        }
+
+        for (name, value) in &values {
+            let configurations: Vec<&rovervalidate::service::Configuration> = bootspecs
+                .iter()
+                .flat_map(|b| b.configuration.iter())
+                .filter(|c| c.name == *name)
+                .collect();
+            validate_tuning(name, value, &configurations)?;
+        }
+
+        let mut tuner = self.tuner.lock().await;
+        let Some(tuner) = tuner.as_mut() else {
+            return Err(Error::TuningNotAvailable);
         };
-        match res {
-            Ok(val) => Ok(val),
-            Err(e) => {
-                config.enabled.clear();
-                update_config(&config)?;
-                Err(Error::Validation(e))
+        tuner.publish(&values).await?;
+
+        for (p, mut bootspec) in procs.iter_mut().zip(bootspecs) {
+            for c in bootspec.configuration.iter_mut() {
+                if let Some((_, value)) = values.iter().find(|(name, _)| *name == c.name) {
+                    if c.tunable.unwrap_or(false) {
+                        c.value = value.clone();
+                    }
+                }
             }
+            p.injected_env = serde_json::to_string(&bootspec)?;
         }
+
+        info!("tuned {} configuration value(s)", values.len());
+        Ok(())
+    }
+
+    /// Reads the config file from disk and returns a RunnablePipeline if it is valid.
+    pub async fn get_valid_pipeline(&self) -> Result<RunnablePipeline, Error> {
+        let config = get_config(&self.settings).await?;
+        validate_config(&config)
     }
 
     pub async fn get_service_logs(
@@ -813,42 +1546,55 @@ impl App {
         fq: FqBuf,
         num_lines: usize,
     ) -> Result<Vec<String>, Error> {
-        let file = File::open(fq.log_file()).map_err(|_| Error::NoLogsFound)?;
-        let mut reader = BufReader::new(file);
-
-        let mut buffer = Vec::new();
-        let mut lines = Vec::new();
+        // The log file links to the log of the latest run, rotated files are found next to it
+        let log_file = PathBuf::from(fq.log_file(&self.settings));
+        let log_file = fs::read_link(&log_file).unwrap_or(log_file);
+        read_last_lines(&self.settings, &log_file, num_lines)
+    }
 
-        // Seek to the end of the file
-        let mut position = reader
-            .seek(SeekFrom::End(0))
-            .with_context(|| format!("failed to seek in {}", fq.log_file()))?;
+    /// Returns the history of pipeline runs, most recent first.
+    pub async fn get_runs(&self) -> Result<Vec<Run>, Error> {
+        runs::list_runs(&self.settings)
+    }
 
-        // Read the file in reverse to gather lines
-        while lines.len() < num_lines && position > 0 {
-            // Adjust buffer size based on remaining file size
-            let chunk_size = cmp::min(position as usize, 4096);
-            position -= chunk_size as u64;
-            reader
-                .seek(SeekFrom::Start(position))
-                .with_context(|| format!("failed to seek in {}", fq.log_file()))?;
-            buffer.resize(chunk_size, 0);
+    /// Retrieves the last lines a service logged during the given run.
+    pub async fn get_run_logs(
+        &self,
+        id: u64,
+        fq: FqBuf,
+        num_lines: usize,
+    ) -> Result<Vec<String>, Error> {
+        let run = runs::get_run(&self.settings, id)?;
+        if !run
+            .services
+            .iter()
+            .any(|s| s.author == fq.author && s.name == fq.name && s.version == fq.version)
+        {
+            return Err(Error::ServiceNotFound(format!(
+                "{} was not part of run {}",
+                fq, id
+            )));
+        }
 
-            // Read the chunk
-            reader
-                .get_mut()
-                .read_exact(&mut buffer)
-                .with_context(|| format!("failed to read chunk for {}", fq.log_file()))?;
+        let log_file = Run::log_file(&self.settings, id, &fq);
+        read_last_lines(&self.settings, Path::new(&log_file), num_lines)
+    }
 
-            // Split into lines and push to the result
-            let chunk = String::from_utf8_lossy(&buffer);
-            let mut chunk_lines: Vec<_> = chunk.lines().rev().map(String::from).collect();
-            lines.append(&mut chunk_lines);
-        }
+    /// Follows the log file (or the build log file) of a service and yields new lines as they
+    /// are written. Starts at byte offset `since`, or at the end of the file if not given.
+    pub async fn tail_service_logs(
+        &self,
+        fq: FqBuf,
+        build: bool,
+        since: Option<u64>,
+    ) -> Result<LogTail, Error> {
+        let log_file = if build {
+            fq.build_log_file(&self.settings)
+        } else {
+            fq.log_file(&self.settings)
+        };
 
-        // Reverse the lines to restore their original order
-        lines.reverse();
-        Ok(lines.into_iter().take(num_lines).collect())
+        LogTail::new(log_file, since).await
     }
 
     /// Spawns a separate shell to run the update script
@@ -879,14 +1625,15 @@ impl App {
 
     pub async fn get_fqns(&self) -> Result<Vec<FqnsGet200ResponseInner>, Error> {
         let mut fqns = Vec::new();
-        let rover_dir = Path::new(ROVER_DIR);
+        let rover_dir = Path::new(&self.settings.rover_dir);
 
         // Ensure base directory exists
         if !rover_dir.exists() {
             return Ok(fqns);
         }
 
-        // Iterate through author directories
+        // Iterate through author directories, hidden entries are installs that are still in
+        // progress (or left behind by one that failed) at every level
         for author_entry in
             fs::read_dir(rover_dir).with_context(|| format!("failed to read {:?}", rover_dir))?
         {
@@ -898,6 +1645,7 @@ impl App {
                     format!("failed to get file metadata of {:?}", author_entry.path())
                 })?
                 .is_dir()
+                || is_hidden(&author_entry)
             {
                 continue;
             }
@@ -923,6 +1671,7 @@ impl App {
                         format!("failed to get file metadata of {:?}", service_entry.path())
                     })?
                     .is_dir()
+                    || is_hidden(&service_entry)
                 {
                     continue;
                 }
@@ -949,6 +1698,7 @@ impl App {
                             format!("failed to get file metadata of {:?}", version_entry.path())
                         })?
                         .is_dir()
+                        || is_hidden(&version_entry)
                     {
                         continue;
                     }
@@ -967,17 +1717,62 @@ impl App {
     }
 }
 
+fn is_hidden(entry: &fs::DirEntry) -> bool {
+    entry.file_name().to_string_lossy().starts_with('.')
+}
+
 /// Retrieves rover.yaml file from disk, performs validation and returns object.
-pub async fn get_config() -> Result<Configuration, Error> {
-    if !Path::new(ROVER_CONFIG_FILE).exists() {
+pub async fn get_config(settings: &Settings) -> Result<Configuration, Error> {
+    Ok(load_config(settings)?.0)
+}
+
+/// Same as get_config, but also returns why rover.yaml was rejected if it had to be restored.
+/// A corrupt rover.yaml (e.g. after it was edited by hand) is replaced by its last known-good
+/// copy, or by an empty configuration if that is not usable either.
+pub fn load_config(settings: &Settings) -> Result<(Configuration, Option<Error>), Error> {
+    let config_file = settings.config_file();
+    if !Path::new(&config_file).exists() {
         // If there is no existing config, create a new file and write
         // an empty config to it.
-        let empty_config = Configuration { enabled: vec![] };
-        update_config(&empty_config)?;
+        let empty_config = Configuration {
+            enabled: vec![],
+            overrides: None,
+        };
+        update_config(settings, &empty_config)?;
     }
 
-    let file_content =
-        std::fs::read_to_string(ROVER_CONFIG_FILE).map_err(|_| Error::ConfigFileIO)?;
+    let reason = match read_config(&config_file) {
+        Ok(config) => return Ok((config, None)),
+        Err(e) => e,
+    };
+
+    let backup_file = settings.config_backup_file();
+    let config = match read_config(&backup_file) {
+        Ok(config) => {
+            error!(
+                "{} is invalid, restoring {}: {:?}",
+                config_file, backup_file, reason
+            );
+            config
+        }
+        Err(e) => {
+            error!(
+                "{} is invalid and so is {} ({:?}), resetting the pipeline: {:?}",
+                config_file, backup_file, e, reason
+            );
+            Configuration {
+                enabled: vec![],
+                overrides: None,
+            }
+        }
+    };
+    update_config(settings, &config)?;
+
+    Ok((config, Some(reason)))
+}
+
+fn read_config(config_file: &str) -> Result<Configuration, Error> {
+    let file_content = std::fs::read_to_string(config_file).map_err(|_| Error::ConfigFileIO)?;
 
     let config: ValidatedConfiguration =
         serde_yaml::from_str::<Configuration>(&file_content)?.validate()?;
@@ -985,6 +1780,230 @@ pub async fn get_config() -> Result<Configuration, Error> {
     Ok(config.0)
 }
 
+/// Validates the given services (with their configuration overrides) as a pipeline and
+/// returns the configuration that enables them.
+fn validate_services(
+    settings: &Settings,
+    services: &[(FqBuf, Vec<ConfigurationOverride>)],
+) -> Result<Configuration, Error> {
+    let overrides: Vec<Override> = services
+        .iter()
+        .filter(|(_, configuration)| !configuration.is_empty())
+        .map(|(fq, configuration)| Override {
+            service: fq.path(settings),
+            configuration: configuration.clone(),
+        })
+        .collect();
+
+    let config = Configuration {
+        enabled: services.iter().map(|(fq, _)| fq.path(settings)).collect(),
+        overrides: if overrides.is_empty() {
+            None
+        } else {
+            Some(overrides)
+        },
+    };
+    validate_config(&config)?;
+
+    Ok(config)
+}
+
+/// Validates the services enabled in a configuration (rover.yaml or a profile) as a pipeline.
+/// Services that can not be read are reported along with the errors of the pipeline formed by
+/// the remaining services, so all broken entries are known at once.
+fn validate_config(config: &Configuration) -> Result<RunnablePipeline, Error> {
+    let mut valid_services = vec![];
+    let mut invalid_services = vec![];
+
+    for enabled in &config.enabled {
+        match read_enabled_service(config, enabled) {
+            Ok(validated) => valid_services.push(validated),
+            Err(e) => invalid_services.push((FqBuf::try_from(enabled)?, format!("{:?}", e))),
+        }
+    }
+
+    match Pipeline::new(valid_services).validate() {
+        Ok(runnable) if invalid_services.is_empty() => {
+            for warning in runnable.warnings() {
+                warn!("pipeline warning: {}", warning);
+            }
+            Ok(runnable)
+        }
+        Ok(runnable) => Err(Error::PipelineValidation(
+            invalid_services,
+            runnable.warnings(),
+        )),
+        Err(errors) => Err(Error::PipelineValidation(invalid_services, errors)),
+    }
+}
+
+/// Reads and validates the service.yaml of an enabled service and applies its overrides.
+fn read_enabled_service(
+    config: &Configuration,
+    enabled: &String,
+) -> Result<ValidatedService, Error> {
+    let service_file = std::fs::read_to_string(enabled)
+        .map_err(|_| Error::ServiceNotFound(format!("could not find or read {}", enabled)))?;
+    let service: Service = serde_yaml::from_str(&service_file)?;
+    let mut validated = service.validate()?;
+
+    // Values set for this pipeline take precedence over the service.yaml
+    if let Some(o) = config
+        .overrides
+        .iter()
+        .flatten()
+        .find(|o| o.service == *enabled)
+    {
+        validated = validated.with_overrides(&o.configuration)?;
+    }
+
+    Ok(validated)
+}
+
+/// Opens the log file of the process and spawns its run command from within the service
+/// directory.
+fn spawn_process(settings: &Settings, p: &Process) -> Result<Child, Error> {
+    rotate_log(settings, &p.log_file)?;
+    let mut log_file = create_log_file(&p.log_file)?;
+
+    let cur_time = chrono::Local::now().format("%H:%M:%S");
+    if writeln!(log_file, "[{}] roverd spawned {}", cur_time, p.name).is_err() {
+        warn!("could not write log_line to file: {:?}", p.log_file)
+    };
+
+    let stdout = Stdio::from(
+        log_file
+            .try_clone()
+            .with_context(|| format!("failed to clone log file {:?}", log_file))?,
+    );
+    let stderr = Stdio::from(log_file);
+
+    let parsed_command = ParsedCommand::try_from(&p.command)?;
+
+    let full_program_path = format!("{}/{}", p.fq.dir(settings), &parsed_command.program);
+    info!("executing {:?}", full_program_path);
+
+    fs::set_permissions(full_program_path.clone(), Permissions::from_mode(0o755))
+        .with_context(|| format!("failed to set permissions for {:?}", full_program_path))?;
+
+    let mut command = Command::new(parsed_command.program);
+    command
+        .args(parsed_command.arguments)
+        .env(ENV_KEY, p.injected_env.clone())
+        .current_dir(p.fq.dir(settings))
+        .stdout(stdout)
+        .stderr(stderr)
+        // Its own process group, so that stopping the service also reaches its children
+        .process_group(0);
+
+    command
+        .spawn()
+        .map_err(|e| Error::FailedToSpawnProcess(format!("{}", e)))
+}
+
+/// Asks the process group of the child to stop with SIGTERM and gives it until the timeout to
+/// exit, this includes any processes the service started itself (e.g. from a shell script).
+/// Whatever is left after that gets killed. Returns whether a hard kill was necessary.
+async fn stop_child(name: &str, child: &mut Child, timeout: Duration) -> bool {
+    let Some(id) = child.id() else {
+        // Already exited and reaped
+        return false;
+    };
+    let group = -(id as i32);
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    info!("terminating {} pid ({}) and its process group", name, id);
+    unsafe {
+        libc::kill(group, libc::SIGTERM);
+    }
+
+    if tokio::time::timeout_at(deadline, child.wait())
+        .await
+        .is_ok()
+    {
+        // Give the rest of the group until the deadline as well
+        while group_alive(group) && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(Duration::from_millis(STOP_POLL_INTERVAL)).await;
+        }
+        if !group_alive(group) {
+            return false;
+        }
+    }
+
+    warn!(
+        "{} did not terminate within {:?}, killing it",
+        name, timeout
+    );
+    unsafe {
+        libc::kill(group, libc::SIGKILL);
+    }
+    if let Err(e) = child.kill().await {
+        error!("error killing process {:?}: {:?}", name, e);
+    }
+    true
+}
+
+/// The process that a watcher is responsible for, as long as the run it was spawned in is still
+/// the current one.
+fn run_proc<'a>(
+    stats: &PipelineStats,
+    procs: &'a mut [Process],
+    run: u64,
+    fq: &FqBuf,
+) -> Option<&'a mut Process> {
+    if stats.run.as_ref().map(|r| r.id) != Some(run) {
+        return None;
+    }
+    procs.iter_mut().find(|p| &p.fq == fq)
+}
+
+/// Checks if any process in the given (negated) process group still exists.
+fn group_alive(group: i32) -> bool {
+    unsafe { libc::kill(group, 0) == 0 }
+}
+
+/// Names of the services a service takes inputs from.
+fn inputs(service: &Service) -> Vec<String> {
+    service.inputs.iter().map(|i| i.service.clone()).collect()
+}
+
+/// Grace period of a service between asking it to stop and killing it.
+fn stop_timeout(service: &Service) -> Duration {
+    let timeout = service
+        .stop_timeout
+        .map_or(DEFAULT_STOP_TIMEOUT, |t| t as u64);
+    Duration::from_millis(timeout)
+}
+
+/// Decides based on the restart policy of the process whether it should be restarted after it
+/// exited. Returns the time to wait before restarting, which doubles with every restart.
+fn restart_delay(p: &Process, success: bool) -> Option<Duration> {
+    let restart = p.restart.as_ref()?;
+
+    match restart.policy {
+        Policy::Never => return None,
+        Policy::OnFailure => {
+            if success {
+                return None;
+            }
+            if let Some(max_retries) = restart.max_retries {
+                if p.restarts as i64 >= max_retries {
+                    warn!("process {} ran out of restarts ({})", p.name, max_retries);
+                    return None;
+                }
+            }
+        }
+        Policy::Always => (),
+    }
+
+    let backoff = restart
+        .backoff
+        .map(|b| b as u64)
+        .unwrap_or(DEFAULT_RESTART_BACKOFF);
+    let delay = backoff.saturating_mul(2_u64.saturating_pow(p.restarts));
+    Some(Duration::from_millis(cmp::min(delay, MAX_RESTART_BACKOFF)))
+}
+
 /// Returns the process which contains the fq.
 pub fn get_proc(fq: FqBuf, processes: &Vec<Process>) -> Result<&Process, Error> {
     for p in processes {
@@ -994,3 +2013,110 @@ pub fn get_proc(fq: FqBuf, processes: &Vec<Process>) -> Result<&Process, Error>
     }
     Err(Error::ProcessNotFound)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rovervalidate::service::Restart;
+
+    fn process(restart: Option<Restart>, restarts: u32) -> Process {
+        Process {
+            fq: FqBuf::new_daemon("vu-ase", "test", "1.0.0"),
+            last_pid: None,
+            last_exit_code: 0,
+            name: "test".to_string(),
+            command: "./test".to_string(),
+            log_file: PathBuf::new(),
+            status: ProcessStatus::Stopped,
+            injected_env: String::new(),
+            faults: 0,
+            start_time: 0,
+            restart,
+            inputs: vec![],
+            stop_timeout: Duration::from_millis(DEFAULT_STOP_TIMEOUT),
+            restarts,
+            last_restart: None,
+        }
+    }
+
+    #[test]
+    fn test_restart_delay() {
+        let restart = |policy, backoff, max_retries| {
+            Some(Restart {
+                policy,
+                backoff,
+                max_retries,
+            })
+        };
+        let max = MAX_RESTART_BACKOFF;
+
+        // (restart policy, restarts so far, exited successfully, expected delay in ms)
+        let cases = [
+            (None, 0, false, None),
+            (restart(Policy::Never, None, None), 0, false, None),
+            (restart(Policy::OnFailure, None, None), 0, true, None),
+            (
+                restart(Policy::OnFailure, None, None),
+                0,
+                false,
+                Some(DEFAULT_RESTART_BACKOFF),
+            ),
+            (
+                restart(Policy::OnFailure, Some(100), None),
+                3,
+                false,
+                Some(800),
+            ),
+            (
+                restart(Policy::OnFailure, Some(100), Some(3)),
+                2,
+                false,
+                Some(400),
+            ),
+            (
+                restart(Policy::OnFailure, Some(100), Some(3)),
+                3,
+                false,
+                None,
+            ),
+            (
+                restart(Policy::Always, Some(100), Some(3)),
+                3,
+                true,
+                Some(800),
+            ),
+            (restart(Policy::Always, Some(100), None), 0, true, Some(100)),
+            (restart(Policy::Always, Some(0), None), 5, false, Some(0)),
+            // The delay is capped instead of overflowing
+            (
+                restart(Policy::Always, Some(100), None),
+                20,
+                false,
+                Some(max),
+            ),
+            (
+                restart(Policy::Always, Some(100), None),
+                64,
+                false,
+                Some(max),
+            ),
+            (
+                restart(Policy::Always, Some(100), None),
+                u32::MAX,
+                false,
+                Some(max),
+            ),
+            (
+                restart(Policy::Always, Some(i64::MAX), None),
+                1,
+                false,
+                Some(max),
+            ),
+        ];
+
+        for (i, (restart, restarts, success, expected)) in cases.into_iter().enumerate() {
+            let delay = restart_delay(&process(restart, restarts), success);
+            assert_eq!(delay, expected.map(Duration::from_millis), "case {}", i);
+        }
+    }
+}
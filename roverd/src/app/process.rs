@@ -1,13 +1,17 @@
 use std::path::PathBuf;
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::{process::Child, sync::Mutex};
 
 use openapi::models::*;
+use rovervalidate::service::Restart;
 
 use crate::service::FqBuf;
 
+use super::runs::Run;
+
 #[derive(Debug, Clone)]
 pub struct SpawnedProcess {
     pub fq: FqBuf,
@@ -29,6 +33,15 @@ pub struct Process {
     pub injected_env: String,
     pub faults: u32,
     pub start_time: i64,
+    /// Restart policy as declared in the service.yaml, None means never restart.
+    pub restart: Option<Restart>,
+    /// Names of the services this process takes inputs from.
+    pub inputs: Vec<String>,
+    /// Grace period between SIGTERM and SIGKILL when the pipeline is stopped.
+    pub stop_timeout: Duration,
+    /// Number of times this process was restarted since the pipeline was started.
+    pub restarts: u32,
+    pub last_restart: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -37,4 +50,6 @@ pub struct PipelineStats {
     pub last_start: Option<i64>,
     pub last_stop: Option<i64>,
     pub last_restart: Option<i64>,
+    /// The run that is in progress, or the last one if the pipeline is not started.
+    pub run: Option<Run>,
 }
@@ -0,0 +1,128 @@
+use std::fs;
+use std::path::Path;
+
+use rovervalidate::config::{Configuration, ValidatedConfiguration};
+use rovervalidate::validate::Validate;
+
+use crate::error::Error;
+use crate::settings::Settings;
+use crate::util::write_atomically;
+
+/// Profile names end up in a file name, so only lowercase letters, digits and single dashes
+/// in between are allowed.
+pub fn validate_profile_name(name: &str) -> Result<(), Error> {
+    let valid = !name.is_empty()
+        && name.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::ProfileNameInvalid(name.to_string()))
+    }
+}
+
+/// Names of all stored profiles, sorted alphabetically.
+pub fn list_profiles(settings: &Settings) -> Result<Vec<String>, Error> {
+    let dir = settings.profile_dir();
+    if !Path::new(&dir).exists() {
+        return Ok(vec![]);
+    }
+
+    let mut names = vec![];
+    for entry in fs::read_dir(&dir).map_err(|_| Error::ProfileFileIO)? {
+        let path = entry.map_err(|_| Error::ProfileFileIO)?.path();
+        if path.extension().is_some_and(|e| e == "yaml") {
+            if let Some(name) = path.file_stem().and_then(|s| s.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+
+    Ok(names)
+}
+
+/// A profile is stored in the same format as the rover.yaml, so it can be activated by copying it.
+pub fn read_profile(settings: &Settings, name: &str) -> Result<Configuration, Error> {
+    validate_profile_name(name)?;
+
+    let file = settings.profile_file(name);
+    if !Path::new(&file).exists() {
+        return Err(Error::ProfileNotFound(name.to_string()));
+    }
+
+    let contents = fs::read_to_string(&file).map_err(|_| Error::ProfileFileIO)?;
+    let config: ValidatedConfiguration =
+        serde_yaml::from_str::<Configuration>(&contents)?.validate()?;
+
+    Ok(config.0)
+}
+
+pub fn write_profile(settings: &Settings, name: &str, config: &Configuration) -> Result<(), Error> {
+    validate_profile_name(name)?;
+
+    let contents = serde_yaml::to_string(config)?;
+    fs::create_dir_all(settings.profile_dir()).map_err(|_| Error::ProfileFileIO)?;
+
+    write_atomically(settings.profile_file(name), contents.as_bytes())
+        .map_err(|_| Error::ProfileFileIO)?;
+
+    Ok(())
+}
+
+pub fn remove_profile(settings: &Settings, name: &str) -> Result<(), Error> {
+    validate_profile_name(name)?;
+
+    let file = settings.profile_file(name);
+    if !Path::new(&file).exists() {
+        return Err(Error::ProfileNotFound(name.to_string()));
+    }
+
+    fs::remove_file(&file).map_err(|_| Error::ProfileFileIO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_profile_name() {
+        for name in ["driving", "track-2", "a", "2024-demo"] {
+            assert!(
+                validate_profile_name(name).is_ok(),
+                "{:?} should be valid",
+                name
+            );
+        }
+
+        for name in [
+            "",
+            "..",
+            ".",
+            "a/b",
+            "../rover",
+            "/etc/passwd",
+            "-a",
+            "a-",
+            "a--b",
+            "Driving",
+            "a b",
+            "a.yaml",
+            "a_b",
+        ] {
+            assert!(
+                matches!(
+                    validate_profile_name(name),
+                    Err(Error::ProfileNameInvalid(_))
+                ),
+                "{:?} should be invalid",
+                name
+            );
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::fs;
+use std::os::unix::fs::symlink;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use openapi::models::{PipelineStatus, ProcessStatus};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::constants::*;
+use crate::error::Error;
+use crate::service::FqBuf;
+use crate::settings::Settings;
+use crate::time_now;
+
+use super::process::{PipelineStats, Process};
+
+/// A single start of the pipeline. Every run has its own directory in the run dir which
+/// contains the run.yaml with this struct and the logs of all services during that run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Run {
+    pub id: u64,
+    pub start: i64,
+    pub stop: Option<i64>,
+    pub services: Vec<RunService>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunService {
+    pub author: String,
+    pub name: String,
+    pub version: String,
+    pub exit_code: Option<i32>,
+    pub faults: u32,
+    pub restarts: u32,
+
+    /// Faults of the process before this run, since those are counted over the lifetime of roverd.
+    #[serde(skip)]
+    faults_before: u32,
+}
+
+impl Run {
+    /// Records a new run for the given processes. It gets the next free id, its directory is
+    /// created and the oldest runs beyond the retention are removed.
+    pub fn create(settings: &Settings, processes: &[Process]) -> Result<Run, Error> {
+        let id = list_run_ids(settings)?
+            .into_iter()
+            .max()
+            .map_or(1, |id| id + 1);
+
+        let run = Run {
+            id,
+            start: time_now!() as i64,
+            stop: None,
+            services: processes
+                .iter()
+                .map(|p| RunService {
+                    author: p.fq.author.clone(),
+                    name: p.fq.name.clone(),
+                    version: p.fq.version.clone(),
+                    exit_code: None,
+                    faults: 0,
+                    restarts: 0,
+                    faults_before: p.faults,
+                })
+                .collect(),
+        };
+
+        let dir = Run::dir(settings, id);
+        fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir))?;
+        run.save(settings)?;
+        prune_runs(settings, id);
+
+        Ok(run)
+    }
+
+    pub fn dir(settings: &Settings, id: u64) -> String {
+        format!("{}/{}", settings.run_dir, id)
+    }
+
+    /// The log file of a service during the given run.
+    pub fn log_file(settings: &Settings, id: u64, fq: &FqBuf) -> String {
+        format!(
+            "{}/{}-{}-{}.log",
+            Run::dir(settings, id),
+            fq.author,
+            fq.name,
+            fq.version
+        )
+    }
+
+    /// Takes over exit codes, faults and restarts from the current state of the processes.
+    pub fn update(&mut self, processes: &[Process]) {
+        for service in &mut self.services {
+            let Some(p) = processes.iter().find(|p| {
+                p.fq.author == service.author
+                    && p.fq.name == service.name
+                    && p.fq.version == service.version
+            }) else {
+                continue;
+            };
+
+            service.exit_code = match p.status {
+                ProcessStatus::Running => None,
+                _ => Some(p.last_exit_code),
+            };
+            service.faults = p.faults.saturating_sub(service.faults_before);
+            service.restarts = p.restarts;
+        }
+    }
+
+    pub fn save(&self, settings: &Settings) -> Result<(), Error> {
+        let file = format!("{}/{}", Run::dir(settings, self.id), RUN_FILE_NAME);
+        let contents = serde_yaml::to_string(self)?;
+        fs::write(&file, contents).with_context(|| format!("failed to write {}", file))?;
+        Ok(())
+    }
+}
+
+/// Writes the current state of the processes to the run that is in progress (if any). Once
+/// the pipeline is no longer started the run is marked as stopped.
+pub fn record_run(settings: &Settings, stats: &mut PipelineStats, processes: &[Process]) {
+    let status = stats.status;
+    let Some(run) = stats.run.as_mut() else {
+        return;
+    };
+
+    run.update(processes);
+    if status != PipelineStatus::Started && run.stop.is_none() {
+        run.stop = Some(time_now!() as i64);
+    }
+
+    if let Err(e) = run.save(settings) {
+        warn!("failed to save run {}: {:?}", run.id, e);
+    }
+}
+
+/// Points the regular log file of a service (in the log dir) to its log of the given run,
+/// so fetching and following the logs of a service always shows the latest run.
+pub fn link_log_file(settings: &Settings, fq: &FqBuf, run_log_file: &str) -> Result<(), Error> {
+    let log_file = fq.log_file(settings);
+    let path = Path::new(&log_file);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("failed to create {:?}", parent))?;
+    }
+    if path.symlink_metadata().is_ok() {
+        fs::remove_file(path).with_context(|| format!("failed to remove {}", log_file))?;
+    }
+
+    symlink(run_log_file, path)
+        .with_context(|| format!("failed to link {} to {}", log_file, run_log_file))?;
+    Ok(())
+}
+
+/// Returns all recorded runs, most recent first.
+pub fn list_runs(settings: &Settings) -> Result<Vec<Run>, Error> {
+    let mut ids = list_run_ids(settings)?;
+    ids.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut runs = vec![];
+    for id in ids {
+        match get_run(settings, id) {
+            Ok(run) => runs.push(run),
+            Err(e) => warn!("skipping run {}: {:?}", id, e),
+        }
+    }
+
+    Ok(runs)
+}
+
+pub fn get_run(settings: &Settings, id: u64) -> Result<Run, Error> {
+    let file = format!("{}/{}", Run::dir(settings, id), RUN_FILE_NAME);
+    let contents = fs::read_to_string(&file).map_err(|_| Error::RunNotFound(id))?;
+    Ok(serde_yaml::from_str(&contents)?)
+}
+
+fn list_run_ids(settings: &Settings) -> Result<Vec<u64>, Error> {
+    if !Path::new(&settings.run_dir).exists() {
+        return Ok(vec![]);
+    }
+
+    let entries = fs::read_dir(&settings.run_dir)
+        .with_context(|| format!("failed to read {}", settings.run_dir))?;
+
+    Ok(entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+        .collect())
+}
+
+/// Removes all runs that are older than the last `run_retention` runs.
+fn prune_runs(settings: &Settings, latest: u64) {
+    let Ok(ids) = list_run_ids(settings) else {
+        return;
+    };
+
+    for id in ids {
+        if id + settings.run_retention.max(1) as u64 <= latest {
+            let dir = Run::dir(settings, id);
+            info!("removing old run {}", dir);
+            if let Err(e) = fs::remove_dir_all(&dir) {
+                warn!("failed to remove {}: {}", dir, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Settings that keep the runs and logs in a fresh directory, with only 2 runs kept.
+    fn settings(test: &str) -> Settings {
+        let dir = std::env::temp_dir().join(format!("roverd-runs-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let dir = dir.to_string_lossy().to_string();
+
+        Settings {
+            run_dir: format!("{}/runs", dir),
+            log_dir: format!("{}/logs", dir),
+            run_retention: 2,
+            ..Settings::default()
+        }
+    }
+
+    #[test]
+    fn test_prune_runs() {
+        let settings = settings("prune");
+
+        let ids: Vec<u64> = (0..4)
+            .map(|_| Run::create(&settings, &[]).unwrap().id)
+            .collect();
+        assert_eq!(ids, [1, 2, 3, 4]);
+
+        // Only the most recent runs are kept, most recent first
+        let kept: Vec<u64> = list_runs(&settings).unwrap().iter().map(|r| r.id).collect();
+        assert_eq!(kept, [4, 3]);
+        assert!(matches!(get_run(&settings, 2), Err(Error::RunNotFound(2))));
+
+        // Ids keep counting up from the most recent run
+        assert_eq!(Run::create(&settings, &[]).unwrap().id, 5);
+
+        fs::remove_dir_all(Path::new(&settings.run_dir).parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_link_log_file() {
+        let settings = settings("link");
+        let fq = FqBuf {
+            author: "vu-ase".to_string(),
+            name: "imaging".to_string(),
+            version: "1.0.0".to_string(),
+            is_daemon: false,
+        };
+
+        for id in [1, 2] {
+            let run = Run::create(&settings, &[]).unwrap();
+            assert_eq!(run.id, id);
+            let run_log_file = Run::log_file(&settings, id, &fq);
+            fs::write(&run_log_file, format!("run {}\n", id)).unwrap();
+
+            // The log of the service always shows the latest run
+            link_log_file(&settings, &fq, &run_log_file).unwrap();
+            let log_file = fq.log_file(&settings);
+            assert_eq!(fs::read_link(&log_file).unwrap(), Path::new(&run_log_file));
+            assert_eq!(
+                fs::read_to_string(&log_file).unwrap(),
+                format!("run {}\n", id)
+            );
+        }
+
+        fs::remove_dir_all(Path::new(&settings.run_dir).parent().unwrap()).unwrap();
+    }
+}
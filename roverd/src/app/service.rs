@@ -5,14 +5,13 @@ use crate::error::*;
 
 use rovervalidate::service::ValidatedService;
 
-use crate::constants::*;
+use crate::settings::Settings;
 
 use openapi::models::*;
+use serde::Serialize;
 
 pub struct FqVec<'a>(pub Vec<Fq<'a>>);
 
-pub struct FqBufVec(pub Vec<FqBuf>);
-
 /// Internal representation of a service, whether as a source or user service.
 #[derive(Debug)]
 pub struct Fq<'a> {
@@ -22,11 +21,12 @@ pub struct Fq<'a> {
 }
 
 /// Same as FqService but with Strings instead of &str.
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct FqBuf {
     pub author: String,
     pub name: String,
     pub version: String,
+    #[serde(skip)]
     pub is_daemon: bool,
 }
 
@@ -118,6 +118,17 @@ impl From<&PipelinePostRequestInner> for FqBuf {
     }
 }
 
+impl From<&ProfileService> for FqBuf {
+    fn from(service: &ProfileService) -> Self {
+        FqBuf {
+            name: service.name.clone(),
+            author: service.author.clone(),
+            version: service.version.clone(),
+            is_daemon: false,
+        }
+    }
+}
+
 impl From<&LogsAuthorNameVersionGetPathParams> for FqBuf {
     fn from(value: &LogsAuthorNameVersionGetPathParams) -> Self {
         FqBuf {
@@ -129,45 +140,56 @@ impl From<&LogsAuthorNameVersionGetPathParams> for FqBuf {
     }
 }
 
+impl From<&RunsIdLogsAuthorNameVersionGetPathParams> for FqBuf {
+    fn from(value: &RunsIdLogsAuthorNameVersionGetPathParams) -> Self {
+        FqBuf {
+            name: value.name.clone(),
+            author: value.author.clone(),
+            version: value.version.clone(),
+            is_daemon: false,
+        }
+    }
+}
+
 impl FqBuf {
-    pub fn path(&self) -> String {
+    pub fn path(&self, settings: &Settings) -> String {
         if self.is_daemon {
             format!(
                 "{}/{}/{}/{}/service.yaml",
-                DAEMON_DIR, self.author, self.name, self.version
+                settings.daemon_dir, self.author, self.name, self.version
             )
         } else {
             format!(
                 "{}/{}/{}/{}/service.yaml",
-                ROVER_DIR, self.author, self.name, self.version
+                settings.rover_dir, self.author, self.name, self.version
             )
         }
     }
 
-    pub fn log_file(&self) -> String {
+    pub fn log_file(&self, settings: &Settings) -> String {
         format!(
             "{}/{}-{}-{}.log",
-            LOG_DIR, self.author, self.name, self.version
+            settings.log_dir, self.author, self.name, self.version
         )
     }
 
-    pub fn build_log_file(&self) -> String {
+    pub fn build_log_file(&self, settings: &Settings) -> String {
         format!(
             "{}/build-{}-{}-{}.log",
-            BUILD_LOG_DIR, self.author, self.name, self.version
+            settings.build_log_dir, self.author, self.name, self.version
         )
     }
 
-    pub fn dir(&self) -> String {
+    pub fn dir(&self, settings: &Settings) -> String {
         if self.is_daemon {
             format!(
                 "{}/{}/{}/{}",
-                DAEMON_DIR, self.author, self.name, self.version
+                settings.daemon_dir, self.author, self.name, self.version
             )
         } else {
             format!(
                 "{}/{}/{}/{}",
-                ROVER_DIR, self.author, self.name, self.version
+                settings.rover_dir, self.author, self.name, self.version
             )
         }
     }
@@ -270,28 +292,6 @@ impl<'a> TryFrom<&'a Vec<String>> for FqVec<'a> {
     }
 }
 
-impl TryFrom<Vec<String>> for FqBufVec {
-    type Error = error::Error;
-    fn try_from(string_vec: Vec<String>) -> Result<Self, Self::Error> {
-        let fq_services: Vec<FqBuf> = string_vec
-            .iter()
-            .map(FqBuf::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(FqBufVec(fq_services))
-    }
-}
-
-impl TryFrom<&Vec<String>> for FqBufVec {
-    type Error = error::Error;
-    fn try_from(string_vec: &Vec<String>) -> Result<Self, Self::Error> {
-        let fq_services: Vec<FqBuf> = string_vec
-            .iter()
-            .map(FqBuf::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(FqBufVec(fq_services))
-    }
-}
-
 impl<'a> From<&'a Vec<PipelinePostRequestInner>> for FqVec<'a> {
     fn from(vec: &'a Vec<PipelinePostRequestInner>) -> Self {
         let fq_services = vec.iter().map(Fq::from).collect::<Vec<_>>();
@@ -299,15 +299,8 @@ impl<'a> From<&'a Vec<PipelinePostRequestInner>> for FqVec<'a> {
     }
 }
 
-impl From<Vec<PipelinePostRequestInner>> for FqBufVec {
-    fn from(vec: Vec<PipelinePostRequestInner>) -> Self {
-        let fq_services = vec.iter().map(FqBuf::from).collect::<Vec<_>>();
-        FqBufVec(fq_services)
-    }
-}
-
 // impl<'a> Fq<'a> {
-//     pub fn path(&self) -> String {
+//     pub fn path(&self, settings: &Settings) -> String {
 //         format!(
 //             "{}/{}/{}/{}/service.yaml",
 //             ROVER_DIR, self.author, self.name, self.version
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use prost::Message;
+use rovervalidate::service::{Configuration, Type, Value};
+use tracing::{info, warn};
+use zeromq::{PubSocket, Socket, SocketSend, ZmqMessage};
+
+use crate::constants::*;
+use crate::error::Error;
+use crate::time_now;
+
+/// Message sent over the tuning channel, services built with roverlib decode this and
+/// update their tunable configuration values. Mirrors tuning/tuning.proto of rovercom.
+#[derive(Clone, PartialEq, Message)]
+pub struct TuningState {
+    #[prost(uint64, tag = "1")]
+    pub timestamp: u64,
+
+    #[prost(message, repeated, tag = "2")]
+    pub dynamic_parameters: Vec<TuningParameter>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct TuningParameter {
+    #[prost(oneof = "TuningParameterValue", tags = "1, 2")]
+    pub parameter: Option<TuningParameterValue>,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum TuningParameterValue {
+    #[prost(message, tag = "1")]
+    Number(NumberParameter),
+
+    #[prost(message, tag = "2")]
+    String(StringParameter),
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct NumberParameter {
+    #[prost(string, tag = "1")]
+    pub key: String,
+
+    #[prost(float, tag = "2")]
+    pub value: f32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct StringParameter {
+    #[prost(string, tag = "1")]
+    pub key: String,
+
+    #[prost(string, tag = "2")]
+    pub value: String,
+}
+
+/// The publishing end of the tuning channel that is advertised to services in their bootspec.
+/// Roverd only owns it if there is no transceiver in the pipeline, which otherwise binds
+/// the same address.
+pub struct Tuner {
+    address: String,
+    socket: PubSocket,
+}
+
+impl fmt::Debug for Tuner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tuner")
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+impl Tuner {
+    pub async fn bind() -> Result<Tuner, Error> {
+        // The bootspec advertises DATA_ADDRESS (localhost), which can not be bound directly
+        let address = format!("{}:{}", DATA_ADDRESS, START_PORT).replace("localhost", "127.0.0.1");
+
+        let mut socket = PubSocket::new();
+        socket.bind(&address).await?;
+        info!("publishing tuning values on {}", address);
+
+        Ok(Tuner { address, socket })
+    }
+
+    pub async fn publish(&mut self, values: &[(String, Value)]) -> Result<(), Error> {
+        let state = TuningState {
+            timestamp: time_now!() as u64,
+            dynamic_parameters: values
+                .iter()
+                .map(|(key, value)| TuningParameter {
+                    parameter: Some(match value {
+                        Value::Number(n) => TuningParameterValue::Number(NumberParameter {
+                            key: key.clone(),
+                            value: *n as f32,
+                        }),
+                        Value::String(s) => TuningParameterValue::String(StringParameter {
+                            key: key.clone(),
+                            value: s.clone(),
+                        }),
+                    }),
+                })
+                .collect(),
+        };
+
+        self.socket
+            .send(ZmqMessage::from(state.encode_to_vec()))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn close(self) {
+        for e in self.socket.close().await {
+            warn!("failed to close tuning channel {}: {:?}", self.address, e);
+        }
+    }
+}
+
+/// Checks that a new value can be applied to the given configuration values (of all services
+/// that declare it): at least one of them must be tunable and all tunable ones must have the
+/// same type as the new value.
+pub fn validate_tuning(
+    name: &str,
+    value: &Value,
+    configurations: &[&Configuration],
+) -> Result<(), Error> {
+    if configurations.is_empty() {
+        return Err(Error::ConfigurationNotFound(name.to_string()));
+    }
+
+    let tunable: Vec<&&Configuration> = configurations
+        .iter()
+        .filter(|c| c.tunable.unwrap_or(false))
+        .collect();
+    if tunable.is_empty() {
+        return Err(Error::ConfigurationNotTunable(name.to_string()));
+    }
+
+    for configuration in tunable {
+        // Without an explicit type, the type is derived from the declared value
+        let declared = match (&configuration.configuration_type, &configuration.value) {
+            (Some(t), _) => t.clone(),
+            (None, Value::Number(_)) => Type::Number,
+            (None, Value::String(_)) => Type::String,
+        };
+
+        match (declared, value) {
+            (Type::Number, Value::Number(_)) | (Type::String, Value::String(_)) => (),
+            _ => return Err(Error::ConfigurationTypeMismatch(name.to_string())),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn configuration(
+        tunable: Option<bool>,
+        configuration_type: Option<Type>,
+        value: Value,
+    ) -> Configuration {
+        Configuration {
+            name: "speed".to_string(),
+            tunable,
+            configuration_type,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_validate_tuning() {
+        let number = configuration(Some(true), None, Value::Number(1.0));
+        let string = configuration(
+            Some(true),
+            Some(Type::String),
+            Value::String("fast".to_string()),
+        );
+        let fixed = configuration(Some(false), None, Value::Number(1.0));
+        let default = configuration(None, None, Value::Number(1.0));
+
+        assert!(validate_tuning("speed", &Value::Number(2.0), &[&number]).is_ok());
+        assert!(validate_tuning("speed", &Value::String("slow".to_string()), &[&string]).is_ok());
+
+        // Options that are not tunable are left alone, as long as one service can tune it
+        assert!(validate_tuning("speed", &Value::Number(2.0), &[&fixed, &number]).is_ok());
+
+        // No service declares the option
+        assert!(matches!(
+            validate_tuning("speed", &Value::Number(2.0), &[]),
+            Err(Error::ConfigurationNotFound(_))
+        ));
+
+        // Options are not tunable unless they say so
+        for configurations in [&[&fixed][..], &[&default], &[&fixed, &default]] {
+            assert!(matches!(
+                validate_tuning("speed", &Value::Number(2.0), configurations),
+                Err(Error::ConfigurationNotTunable(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_tuning_type() {
+        let number = configuration(Some(true), None, Value::Number(1.0));
+        let string = configuration(Some(true), None, Value::String("fast".to_string()));
+        // An explicit type takes precedence over the type of the declared value
+        let typed = configuration(Some(true), Some(Type::String), Value::Number(1.0));
+
+        for (value, configurations) in [
+            (Value::String("slow".to_string()), &[&number][..]),
+            (Value::Number(2.0), &[&string]),
+            (Value::Number(2.0), &[&typed]),
+            // Every service that can tune it must agree on the type
+            (Value::Number(2.0), &[&number, &string]),
+        ] {
+            assert!(matches!(
+                validate_tuning("speed", &value, configurations),
+                Err(Error::ConfigurationTypeMismatch(_))
+            ));
+        }
+        assert!(validate_tuning("speed", &Value::String("slow".to_string()), &[&typed]).is_ok());
+    }
+}
@@ -1,18 +1,67 @@
 // This file defines constants used across all over the crate
 
+use std::time::Duration;
+
+// These are the defaults of the runtime settings, see settings.rs
+
+pub const SETTINGS_FILE: &str = "/etc/roverd/settings.yaml";
+
 pub const LISTEN_ADDRESS: &str = "0.0.0.0:80";
+pub const TLS_CERT_FILE: &str = "/etc/roverd/tls/cert.pem";
+pub const TLS_KEY_FILE: &str = "/etc/roverd/tls/key.pem";
+
+/// The web-monitor, as it is served on the machine of the user.
+pub const CORS_ALLOWED_ORIGINS: [&str; 2] = ["http://localhost:5173", "http://127.0.0.1:5173"];
+pub const CORS_MAX_AGE: u64 = 60 * 60;
 
 pub const ROVER_INFO_FILE: &str = "/etc/roverd/info.txt";
 pub const ROVER_CONFIG_DIR: &str = "/etc/roverd";
-pub const ROVER_CONFIG_FILE: &str = "/etc/roverd/rover.yaml";
+pub const ROVER_CONFIG_FILE_NAME: &str = "rover.yaml";
+pub const ROVER_CONFIG_BACKUP_FILE_NAME: &str = "rover.yaml.bak";
+pub const PROFILE_DIR_NAME: &str = "profiles";
+pub const TRUSTED_KEYS_DIR_NAME: &str = "trusted-keys";
+pub const USERS_FILE_NAME: &str = "users.yaml";
+pub const TOKENS_FILE_NAME: &str = "tokens.yaml";
 pub const ROVER_USER: &str = "debix";
 pub const ROVER_DIR: &str = "/home/debix/.rover";
 pub const DAEMON_DIR: &str = "/etc/roverd/daemons";
 pub const LOG_DIR: &str = "/tmp/roverlog";
 pub const BUILD_LOG_DIR: &str = "/tmp/roverbuildlog";
+pub const LOG_MAX_SIZE: u64 = 10 * 1024 * 1024;
+pub const LOG_RETENTION: u32 = 3;
+pub const LOG_COMPRESS: bool = false;
+pub const RUN_DIR: &str = "/var/lib/roverd/runs";
+pub const RUN_RETENTION: u32 = 20;
+pub const RUN_FILE_NAME: &str = "run.yaml";
+pub const START_DELAY: u64 = 0;
+
+pub const STAGING_DIR: &str = "/tmp/roverd-staging";
+
+/// API tokens start with this prefix, so they are easy to recognize (and to scan for in
+/// repositories they should not have ended up in).
+pub const TOKEN_PREFIX: &str = "rvd_";
+
+/// New passwords that are shorter than this are refused.
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// A client that fails to log in this many times in a row is locked out for a while, failures
+/// older than the window are forgotten.
+pub const MAX_FAILED_LOGINS: u32 = 5;
+pub const FAILED_LOGIN_WINDOW: Duration = Duration::from_secs(10 * 60);
+pub const LOGIN_LOCKOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Verifying a password hash is slow on purpose, so credentials that were verified recently
+/// are remembered for a while (every request carries them again).
+pub const VERIFIED_LOGIN_DURATION: Duration = Duration::from_secs(5 * 60);
+
+/// Multipart field of an upload with the optional (hex) SHA-256 digest of the archive.
+pub const UPLOAD_DIGEST_FIELD: &str = "sha256";
 
-pub const ZIP_FILE: &str = "/tmp/incoming-service.zip";
-pub const UNZIPPED_DIR: &str = "/tmp/incoming-service";
+/// Multipart field of an upload with the optional minisign signature of the archive.
+pub const UPLOAD_SIGNATURE_FIELD: &str = "signature";
+
+/// The detached signature of a fetched archive is expected at its url with this extension.
+pub const SIGNATURE_EXTENSION: &str = "minisig";
 
 pub const ENV_KEY: &str = "ASE_SERVICE";
 
@@ -21,9 +70,34 @@ pub const BATTERY_STREAM_NAME: &str = "voltage";
 
 pub const START_PORT: u32 = 5700;
 
+// If a service with this name is in the pipeline, it owns the tuning channel at START_PORT
+pub const TRANSCEIVER_NAME: &str = "transceiver";
+
 pub const DATA_ADDRESS: &str = "tcp://localhost";
 pub const DEFAULT_LOG_LINES: i32 = 50;
 
+// How often (in milliseconds) a followed log file is checked for new lines
+pub const LOG_POLL_INTERVAL: u64 = 250;
+
+// Number of bytes read at a time when reading the last lines of a log backwards
+pub const LOG_READ_CHUNK_SIZE: u64 = 4096;
+
+// How often (in seconds) the logs of running services are checked against the size limit
+pub const LOG_ROTATE_INTERVAL: u64 = 10;
+
+// Number of events buffered for subscribers of the event bus, slower clients miss events
+pub const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+// Restart backoff (in milliseconds) if the service.yaml does not specify one, and the
+// upper bound of the delay after doubling it for subsequent restarts
+pub const DEFAULT_RESTART_BACKOFF: u64 = 1000;
+pub const MAX_RESTART_BACKOFF: u64 = 30000;
+
+// Grace period (in milliseconds) a service gets to exit after SIGTERM if its service.yaml does
+// not specify a stop_timeout, and how often its process group is checked in the meantime
+pub const DEFAULT_STOP_TIMEOUT: u64 = 1000;
+pub const STOP_POLL_INTERVAL: u64 = 50;
+
 pub const DEBIX_UID: Option<u32> = Some(1000);
 pub const DEBIX_GID: Option<u32> = Some(1000);
 
@@ -34,3 +108,7 @@ pub const BATTERY_FETCH_URL: &str =
 
 // Any downloads made by roverd will timeout after 10 seconds
 pub const DOWNLOAD_TIMEOUT: u64 = 10;
+
+// Limits of a service archive, anything bigger is rejected before it fills up the disk
+pub const MAX_ARCHIVE_ENTRIES: usize = 10_000;
+pub const MAX_ARCHIVE_SIZE: u64 = 1024 * 1024 * 1024;
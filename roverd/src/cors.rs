@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderValue, Method, StatusCode, Uri};
+use axum::middleware::Next;
+use axum::response::Response;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tracing::warn;
+
+use crate::error::Error;
+use crate::settings::Settings;
+
+/// The web pages (origins) that may use the API from a browser.
+#[derive(Debug, Clone)]
+pub enum AllowedOrigins {
+    /// Configured as `*`, any page may use the API but the browser will not send credentials
+    /// along with it.
+    Any,
+    List(Vec<HeaderValue>),
+}
+
+impl AllowedOrigins {
+    pub fn from_settings(settings: &Settings) -> Result<Self, Error> {
+        if settings.cors_allowed_origins.iter().any(|o| o == "*") {
+            return Ok(AllowedOrigins::Any);
+        }
+
+        let origins = settings
+            .cors_allowed_origins
+            .iter()
+            .map(|origin| parse_origin(origin))
+            .collect::<Result<_, _>>()?;
+
+        Ok(AllowedOrigins::List(origins))
+    }
+
+    fn allows(&self, origin: &HeaderValue) -> bool {
+        match self {
+            AllowedOrigins::Any => true,
+            AllowedOrigins::List(origins) => origins.contains(origin),
+        }
+    }
+}
+
+/// An origin is a scheme and host with an optional port, e.g. `http://localhost:5173`. It is
+/// normalised the way browsers send it: in lowercase, without a trailing slash and without
+/// the default port of the scheme.
+fn parse_origin(origin: &str) -> Result<HeaderValue, Error> {
+    let invalid = || Error::CorsOriginInvalid(origin.to_string());
+
+    let uri: Uri = origin.parse().map_err(|_| invalid())?;
+    let (Some(scheme), Some(authority)) = (uri.scheme_str(), uri.authority()) else {
+        return Err(invalid());
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    let default_port = match scheme.as_str() {
+        "http" => 80,
+        "https" => 443,
+        _ => return Err(invalid()),
+    };
+    if uri.path() != "/" || uri.query().is_some() || authority.as_str().contains('@') {
+        return Err(invalid());
+    }
+
+    let host = authority.host().to_ascii_lowercase();
+    let normalised = match authority.port_u16() {
+        Some(port) if port != default_port => format!("{}://{}:{}", scheme, host, port),
+        _ => format!("{}://{}", scheme, host),
+    };
+
+    HeaderValue::from_str(&normalised).map_err(|_| invalid())
+}
+
+/// Answers preflight requests and tells the browser which origins may read the responses. The
+/// allowed origins may send credentials, which is needed for the event streams since an
+/// EventSource can not set the Authorization header itself.
+pub fn layer(origins: &AllowedOrigins, max_age: u64) -> CorsLayer {
+    let layer = CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST, Method::DELETE])
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+        .max_age(Duration::from_secs(max_age));
+
+    match origins {
+        AllowedOrigins::Any => layer.allow_origin(AllowOrigin::any()),
+        AllowedOrigins::List(list) => layer
+            .allow_origin(AllowOrigin::list(list.clone()))
+            .allow_credentials(true),
+    }
+}
+
+/// CORS only keeps a page from reading the response, a simple request from another origin is
+/// still carried out (with credentials the browser has cached). So requests from an origin
+/// that is not allowed are refused before they reach the handlers.
+pub async fn check_origin(
+    State(origins): State<AllowedOrigins>,
+    req: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if let Some(origin) = req.headers().get(header::ORIGIN) {
+        if !origins.allows(origin) {
+            warn!(
+                "refused {} {} from origin {:?}",
+                req.method(),
+                req.uri().path(),
+                origin
+            );
+            return Err(StatusCode::FORBIDDEN);
+        }
+    }
+
+    Ok(next.run(req).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use tower::ServiceExt;
+
+    fn allowed(origins: &[&str]) -> Result<AllowedOrigins, Error> {
+        AllowedOrigins::from_settings(&Settings {
+            cors_allowed_origins: origins.iter().map(|o| o.to_string()).collect(),
+            ..Settings::default()
+        })
+    }
+
+    fn list(origins: &[&str]) -> Vec<String> {
+        match allowed(origins).unwrap() {
+            AllowedOrigins::List(list) => list
+                .iter()
+                .map(|o| o.to_str().unwrap().to_string())
+                .collect(),
+            AllowedOrigins::Any => panic!("expected a list of origins"),
+        }
+    }
+
+    #[test]
+    fn test_parse_origins() {
+        assert_eq!(
+            list(&["http://localhost:5173", "https://rover.local/"]),
+            ["http://localhost:5173", "https://rover.local"]
+        );
+
+        // Browsers leave out the default port and send the origin in lowercase
+        assert_eq!(
+            list(&["HTTP://LocalHost:80", "https://Rover.local:443/"]),
+            ["http://localhost", "https://rover.local"]
+        );
+        assert_eq!(
+            list(&["https://rover.local:80"]),
+            ["https://rover.local:80"]
+        );
+        assert_eq!(list(&["http://[::1]:5173"]), ["http://[::1]:5173"]);
+        assert!(list(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_any_origin() {
+        assert!(matches!(allowed(&["*"]), Ok(AllowedOrigins::Any)));
+        assert!(matches!(
+            allowed(&["http://localhost:5173", "*"]),
+            Ok(AllowedOrigins::Any)
+        ));
+    }
+
+    #[test]
+    fn test_invalid_origins() {
+        for origin in [
+            "localhost:5173",
+            "ftp://localhost",
+            "http://",
+            "http://localhost/path",
+            "http://localhost/?query",
+            "http://user@localhost",
+            "",
+        ] {
+            assert!(
+                matches!(allowed(&[origin]), Err(Error::CorsOriginInvalid(_))),
+                "{:?} should be invalid",
+                origin
+            );
+        }
+    }
+
+    async fn status(origins: &AllowedOrigins, origin: Option<&str>) -> StatusCode {
+        let check = middleware::from_fn_with_state(origins.clone(), check_origin);
+        let router = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(check);
+
+        let mut req = Request::builder().uri("/");
+        if let Some(origin) = origin {
+            req = req.header(header::ORIGIN, origin);
+        }
+
+        router
+            .oneshot(req.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_check_origin() {
+        let origins = allowed(&["http://localhost:5173"]).unwrap();
+
+        assert_eq!(
+            status(&origins, Some("http://localhost:5173")).await,
+            StatusCode::OK
+        );
+        assert_eq!(status(&origins, None).await, StatusCode::OK);
+        assert_eq!(
+            status(&origins, Some("http://localhost:5174")).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status(&origins, Some("http://evil.example")).await,
+            StatusCode::FORBIDDEN
+        );
+
+        let any = allowed(&["*"]).unwrap();
+        assert_eq!(
+            status(&any, Some("http://evil.example")).await,
+            StatusCode::OK
+        );
+    }
+}
@@ -2,6 +2,8 @@
 
 use derive_more::From;
 
+use crate::service::FqBuf;
+
 /// A central definition of all possible errors in roverd. The point of this organization is
 /// to be able to explain at a high level all possible error situations from just this file.
 /// For this reason, we avoid generic errors where the caller specifies further context
@@ -11,9 +13,18 @@ pub enum Error {
     // --- Roverd status ---
     RoverdNotOperational,
 
+    // --- Settings file ---
+    SettingsFileIO,
+    CorsOriginInvalid(String),
+
+    // --- TLS ---
+    TlsKeyPairIncomplete,
+    TlsCertificateInvalid(String),
+
     // --- Rover info file ---
     RoverFileNotFound,
     RoverFileFormat,
+    RoverNameInvalid(String),
 
     // --- Configuration file ---
     ConfigFileIO,
@@ -24,9 +35,13 @@ pub enum Error {
     ServiceAlreadyExists,
     ServiceDownloadFailed,
     ServiceUploadBadPayload,
+    ServiceUploadDigestMismatch,
+    ServiceDownloadDigestMismatch,
 
     // --- Installation ---
     ServiceYamlNotFoundInDownload,
+    ArchiveFormatUnknown,
+    ArchiveEntryRejected(String, ArchiveRejection),
 
     // --- Build ---
     BuildLog(Vec<String>),
@@ -35,17 +50,49 @@ pub enum Error {
 
     // --- Runtime ---
     NoLogsFound,
+    RunNotFound(u64),
     NoRunningServices,
     ProcessNotFound,
     ParsingRunCommand,
     StringToFqConversion,
     FailedToSpawnProcess(String),
 
+    // --- Tuning ---
+    TuningNotAvailable,
+    ConfigurationNotFound(String),
+    ConfigurationNotTunable(String),
+    ConfigurationTypeMismatch(String),
+
+    // --- Profiles ---
+    ProfileFileIO,
+    ProfileNotFound(String),
+    ProfileNameInvalid(String),
+
+    // --- Accounts ---
+    AccountsFileIO,
+    AccountNotFound(String),
+    TokenNameInvalid,
+    TokenNotFound(String),
+    PasswordIncorrect,
+    PasswordTooShort,
+    PasswordGuessesLockedOut,
+
+    // --- Signatures ---
+    SignatureMissing,
+    SignatureInvalid(String),
+    SignatureUntrusted,
+    TrustedKeyInvalid(String),
+
     // Since pipeline is *always* in a valid state, the only
     // error case is a warning in which it is empty, but valid.
     PipelineIsEmpty,
     PipelineAlreadyStarted,
 
+    // The pipeline in rover.yaml is kept when one of its services changes, so it can
+    // be invalid until the user fixes it.
+    PipelineIsInvalid,
+    PipelineValidation(Vec<(FqBuf, String)>, Vec<rovervalidate::error::Error>),
+
     // TODO: remove me for prod!
     Unimplemented,
 
@@ -83,4 +130,21 @@ pub enum Error {
 
     #[from]
     Validation(Vec<rovervalidate::error::Error>),
+
+    #[from]
+    Zmq(zeromq::ZmqError),
+}
+
+/// Why an entry of a service archive was not extracted, the archive is rejected as a whole.
+#[derive(Debug)]
+pub enum ArchiveRejection {
+    /// The path is absolute or contains `..`, so it could end up outside of the service.
+    UnsafePath,
+    /// A symlink that points outside of the service, or an entry that would be written
+    /// through a symlink.
+    UnsafeSymlink,
+    /// Device files, fifos and sockets have no place in a service.
+    SpecialFile,
+    TooManyEntries,
+    TooLarge,
 }
@@ -1,30 +1,36 @@
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
-use axum::extract::{DefaultBodyLimit, Request, State};
-use axum::http::{self, StatusCode};
+use axum::extract::{ConnectInfo, DefaultBodyLimit, Request, State};
+use axum::http::{self, Method, StatusCode};
 use axum::middleware::{self, Next};
 use axum::response::Response;
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
 use base64::Engine;
 use daemons::DaemonManager;
-use openapi::models::DaemonStatus;
-use tower_http::cors::CorsLayer;
+use openapi::models::{DaemonStatus, Role};
 use tracing::{error, info, warn};
 
 mod apis;
 mod app;
 mod command;
 mod constants;
+mod cors;
 mod error;
 mod log;
+mod settings;
+mod tls;
 mod util;
 
 #[cfg(test)]
 mod test;
 
+use app::accounts::{Caller, CALLER};
 use app::*;
-use constants::*;
 use error::Error::*;
+use settings::Settings;
 
 /// Not ideal, but an error wrapper work around since middleware::from_fn_with_state expects
 /// Result<Response, StatusCode>. But ideally, we want to use custom Error to propogate our custom
@@ -46,18 +52,19 @@ async fn auth_wrapper(
     }
 }
 
-/// Performs password check to hashed password stored on disk.
-fn check_auth(state: &Roverd, auth_str: &str) -> Result<(), error::Error> {
+/// Performs password check to hashed password stored on disk. The rover user from info.txt
+/// is an admin, other accounts have the role that is configured in users.yaml.
+async fn check_auth(state: &Roverd, auth_str: &str) -> Result<Caller, error::Error> {
     let (user, password) = auth_str
         .split_once(':')
         .ok_or(Http(StatusCode::BAD_REQUEST))?;
 
-    let stored_hash = sha256::digest(password);
-
-    if let Some(hash) = &state.info.password {
-        if user == state.info.username && hash == &stored_hash {
-            return Ok(());
-        }
+    if let Some(role) = state.app.authenticate(&state.info, user, password).await {
+        return Ok(Caller {
+            name: Some(user.to_string()),
+            role,
+            client: None,
+        });
     }
 
     warn!("Unauthorized access denied");
@@ -65,8 +72,64 @@ fn check_auth(state: &Roverd, auth_str: &str) -> Result<(), error::Error> {
     Err(Http(StatusCode::UNAUTHORIZED))
 }
 
+/// Looks up the role of an API token.
+async fn check_token(state: &Roverd, token: &str) -> Result<Caller, error::Error> {
+    if let Some(role) = state.app.accounts.read().await.token_role(token) {
+        return Ok(Caller {
+            name: None,
+            role,
+            client: None,
+        });
+    }
+
+    warn!("Unauthorized access denied, unknown API token");
+
+    Err(Http(StatusCode::UNAUTHORIZED))
+}
+
+/// Checks the credentials of a Basic or Bearer authorization header.
+async fn check_auth_header(state: &Roverd, auth_header: &str) -> Result<Caller, error::Error> {
+    let auth: Vec<&str> = auth_header.split(' ').collect();
+
+    match auth.as_slice() {
+        ["Basic", base64_data] => {
+            let raw_bytes = base64::prelude::BASE64_STANDARD
+                .decode(base64_data)
+                .map_err(|_| Http(StatusCode::BAD_REQUEST))?;
+
+            let auth_str =
+                core::str::from_utf8(&raw_bytes).map_err(|_| Http(StatusCode::BAD_REQUEST))?;
+
+            check_auth(state, auth_str).await
+        }
+        ["Bearer", token] => check_token(state, token).await,
+        _ => Err(Context(anyhow!(
+            "auth header is not Basic or Bearer or did not contain exactly two items"
+        ))),
+    }
+}
+
+/// The least privileged role that is allowed to make the request. Everything that only reads is
+/// open to viewers, as is changing their own password (which requires the current one). Controlling the
+/// pipeline requires an operator. The rest (installing, building and deleting services, renaming,
+/// shutting down, updating and managing API tokens) is only for admins.
+fn required_role(method: &Method, path: &str) -> Role {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (method, segments.as_slice()) {
+        (&Method::GET, ["auth", ..]) => Role::Admin,
+        (&Method::GET, _) => Role::Viewer,
+        (&Method::POST, ["auth", "password"]) => Role::Viewer,
+        (&Method::POST, ["pipeline"])
+        | (&Method::POST, ["pipeline", "start" | "stop" | "tuning"])
+        | (_, ["pipeline", "profiles", ..]) => Role::Operator,
+        _ => Role::Admin,
+    }
+}
+
 /// Main authentication logic requires authenticated requests for all endpoints
-/// except for "/status".
+/// except for "/status", and checks that the account or token is allowed to make the request.
+/// The handlers of authenticated requests can find out who made it through `CALLER`.
 async fn auth(
     State(state): State<Roverd>,
     req: Request,
@@ -74,35 +137,57 @@ async fn auth(
 ) -> Result<Response, error::Error> {
     info!("{} {}", req.method(), *req.uri());
 
-    // the /status and / endpoints do not require authentication, all others do.
-    if *req.uri() != *"/status" && *req.uri() != *"/" {
-        if state.info.status == DaemonStatus::Operational {
+    let mut caller = None;
+
+    // the /status and / endpoints do not require authentication, all others do. A recoverable
+    // error leaves the API usable, otherwise it could not be used to look into it.
+    if req.uri().path() != "/status" && req.uri().path() != "/" {
+        if state.info.read().await.status != DaemonStatus::Unrecoverable {
             let auth_header = req
                 .headers()
                 .get(http::header::AUTHORIZATION)
                 .and_then(|header| header.to_str().ok())
                 .ok_or(Http(StatusCode::UNAUTHORIZED))?;
 
-            let basic_auth: Vec<&str> = auth_header.split(' ').collect();
-
-            if basic_auth.len() != 2 || basic_auth[0] != "Basic" {
-                // warn!("request is missing basic auth header");
-                return Err(Context(anyhow!(
-                    "basic_auth header != Basic or auth header did not contain exactly two items"
-                )));
+            // Clients that keep failing to log in are locked out for a while
+            let client = req
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip());
+            if let Some(client) = client {
+                if state.app.logins.lock().await.is_locked(client) {
+                    warn!("rejected request from {}, locked out", client);
+                    return Err(Http(StatusCode::TOO_MANY_REQUESTS));
+                }
             }
 
-            let base64_data = basic_auth[1];
-
-            let raw_bytes = base64::prelude::BASE64_STANDARD
-                .decode(base64_data)
-                .map_err(|_| Http(StatusCode::BAD_REQUEST))?;
+            // Returns early if authentication fails
+            let result = check_auth_header(&state, auth_header).await;
+            if let Some(client) = client {
+                match &result {
+                    Ok(_) => state.app.record_login(client, true).await,
+                    Err(Http(StatusCode::UNAUTHORIZED)) => {
+                        state.app.record_login(client, false).await
+                    }
+                    Err(_) => (),
+                }
+            }
+            let authenticated = Caller { client, ..result? };
+            let role = authenticated.role;
 
-            let auth_str =
-                core::str::from_utf8(&raw_bytes).map_err(|_| Http(StatusCode::BAD_REQUEST))?;
+            let required = required_role(req.method(), req.uri().path());
+            if role < required {
+                warn!(
+                    "{} {} requires the {} role, but the request has the {} role",
+                    req.method(),
+                    req.uri().path(),
+                    required,
+                    role
+                );
+                return Err(Http(StatusCode::FORBIDDEN));
+            }
 
-            // Returns early if authentication fails
-            check_auth(&state, auth_str)?;
+            caller = Some(authenticated);
         } else {
             warn!("could not handle request since roverd not operational");
             return Err(error::Error::RoverdNotOperational);
@@ -110,55 +195,125 @@ async fn auth(
     }
 
     // Pass the request on to the request handlers.
-    let response = next.run(req).await;
+    let response = match caller {
+        Some(caller) => CALLER.scope(caller, next.run(req)).await,
+        None => next.run(req).await,
+    };
     Ok(response)
 }
 
+/// Hand-off to axum with a max upload limit of 100MB. The same router (and CORS policy) is used
+/// whether roverd is operational or not.
+fn router(roverd: Roverd, origins: &cors::AllowedOrigins, cors_max_age: u64) -> Router {
+    openapi::server::new(roverd.clone())
+        .merge(apis::streams::router(roverd.clone()))
+        .layer(middleware::from_fn_with_state(roverd, auth_wrapper))
+        .layer(middleware::from_fn_with_state(
+            origins.clone(),
+            cors::check_origin,
+        ))
+        .layer(cors::layer(origins, cors_max_age))
+        .layer(DefaultBodyLimit::max(100000000))
+}
+
+/// Binds the HTTPS listener with its (possibly just generated) certificate, whose fingerprint
+/// is shown in /status. A failure is shown there as well, but does not change the status since
+/// the rover can still be reached over plain HTTP.
+async fn bind_tls(
+    settings: &Settings,
+    roverd: &Roverd,
+    address: &str,
+) -> Option<(std::net::TcpListener, RustlsConfig)> {
+    let result = tls::bind(settings, address).await;
+
+    let mut rover_info = roverd.info.write().await;
+    match result {
+        Ok((listener, tls)) => {
+            info!(
+                "listening on {} (https), certificate fingerprint {}",
+                address, tls.fingerprint
+            );
+            rover_info.tls_fingerprint = Some(tls.fingerprint);
+            Some((listener, tls.config))
+        }
+        Err(e) => {
+            error!("unable to start the https listener: {:?}", e);
+            rover_info.error_msg =
+                Some(format!("the https listener could not be started: {:?}", e));
+            None
+        }
+    }
+}
+
 /// Entry of program, initializes logging and constructs app state used by axum router.
 #[tokio::main]
 async fn main() -> Result<(), error::Error> {
     log::init();
     info!("logging initialized");
 
+    // Settings file, command line flags and environment decide where everything lives
+    let settings = Arc::new(Settings::from_env()?);
+
     // All app initialization happens in new()
-    let mut roverd = Roverd::new().await?;
+    let roverd = Roverd::new(Arc::clone(&settings)).await?;
+
+    // Browsers may only use the API from the allowed origins
+    let origins = cors::AllowedOrigins::from_settings(&settings)?;
+
+    // The HTTPS listener is optional, roverd stays reachable over plain HTTP without it
+    let tls_listener = match &settings.tls_listen_address {
+        Some(address) => bind_tls(&settings, &roverd, address).await,
+        None => None,
+    };
 
     // If our daemons are downloaded
-    match DaemonManager::new().await {
+    match DaemonManager::new(&settings).await {
         Ok(d) => {
             let daemon_manager = Arc::new(d);
             let dm_arc = Arc::clone(&daemon_manager);
 
-            // Hand-off to axum with a max upload limit of 100MB
-            let router = openapi::server::new(roverd.clone())
-                .layer(middleware::from_fn_with_state(roverd, auth_wrapper))
-                .layer(CorsLayer::permissive())
-                .layer(DefaultBodyLimit::max(100000000));
-
-            let listener = tokio::net::TcpListener::bind(LISTEN_ADDRESS).await.unwrap();
+            let router = router(roverd, &origins, settings.cors_max_age);
 
-            info!("listening on {}", LISTEN_ADDRESS);
+            if let Some((listener, config)) = tls_listener {
+                tls::serve(listener, config, router.clone());
+            }
 
-            axum::serve(listener, router)
-                .with_graceful_shutdown(async move {
-                    dm_arc.shutdown_signal().await;
-                })
+            let listener = tokio::net::TcpListener::bind(&settings.listen_address)
                 .await
-                .context("axum error")?;
+                .with_context(|| format!("failed to bind to {}", settings.listen_address))?;
+
+            info!("listening on {}", settings.listen_address);
+
+            axum::serve(
+                listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .with_graceful_shutdown(async move {
+                dm_arc.shutdown_signal().await;
+            })
+            .await
+            .context("axum error")?;
         }
         Err(e) => {
             error!("unable to start daemons: {:?}", e);
-            roverd.info.status = DaemonStatus::Unrecoverable;
+            roverd.info.write().await.status = DaemonStatus::Unrecoverable;
 
-            // Hand-off to axum with a max upload limit of 100MB
-            let router = openapi::server::new(roverd.clone())
-                .layer(middleware::from_fn_with_state(roverd, auth_wrapper))
-                .layer(CorsLayer::permissive())
-                .layer(DefaultBodyLimit::max(100000000));
+            let router = router(roverd, &origins, settings.cors_max_age);
 
-            let listener = tokio::net::TcpListener::bind(LISTEN_ADDRESS).await.unwrap();
+            if let Some((listener, config)) = tls_listener {
+                tls::serve(listener, config, router.clone());
+            }
+
+            let listener = tokio::net::TcpListener::bind(&settings.listen_address)
+                .await
+                .with_context(|| format!("failed to bind to {}", settings.listen_address))?;
 
-            axum::serve(listener, router).await.context("axum error")?;
+            axum::serve(
+                listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .context("axum error")?;
         }
     }
 
@@ -0,0 +1,343 @@
+use std::path::Path;
+
+use clap::{Parser, ValueEnum};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::constants::*;
+use crate::error::Error;
+
+/// Command line flags of roverd. Every flag can also be set through its environment
+/// variable and takes precedence over the value in the settings file.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Args {
+    /// Location of the roverd settings file, it is fine if it does not exist.
+    #[arg(long, env = "ROVERD_SETTINGS", default_value = SETTINGS_FILE)]
+    pub settings: String,
+
+    /// Address (host:port) the API listens on.
+    #[arg(long, env = "ROVERD_LISTEN_ADDRESS")]
+    pub listen_address: Option<String>,
+
+    /// Address (host:port) the API listens on over HTTPS, which is disabled if not set.
+    #[arg(long, env = "ROVERD_TLS_LISTEN_ADDRESS")]
+    pub tls_listen_address: Option<String>,
+
+    /// PEM file with the certificate (chain) of the HTTPS listener.
+    #[arg(long, env = "ROVERD_TLS_CERT_FILE")]
+    pub tls_cert_file: Option<String>,
+
+    /// PEM file with the private key of the HTTPS listener.
+    #[arg(long, env = "ROVERD_TLS_KEY_FILE")]
+    pub tls_key_file: Option<String>,
+
+    /// Origins (e.g. http://localhost:5173) of the web pages that may use the API, separated
+    /// by commas. A `*` allows every page, but without credentials.
+    #[arg(long, env = "ROVERD_CORS_ALLOWED_ORIGINS", value_delimiter = ',')]
+    pub cors_allowed_origins: Option<Vec<String>>,
+
+    /// Seconds a browser may cache the answer to a preflight request.
+    #[arg(long, env = "ROVERD_CORS_MAX_AGE")]
+    pub cors_max_age: Option<u64>,
+
+    /// File containing the rover id, rover name and password hash.
+    #[arg(long, env = "ROVERD_INFO_FILE")]
+    pub info_file: Option<String>,
+
+    /// Directory containing the rover.yaml pipeline configuration.
+    #[arg(long, env = "ROVERD_CONFIG_DIR")]
+    pub config_dir: Option<String>,
+
+    /// User that owns the installed services and runs the build commands.
+    #[arg(long, env = "ROVERD_USER")]
+    pub user: Option<String>,
+
+    /// Directory containing all installed services.
+    #[arg(long, env = "ROVERD_ROVER_DIR")]
+    pub rover_dir: Option<String>,
+
+    /// Directory containing the installed daemons.
+    #[arg(long, env = "ROVERD_DAEMON_DIR")]
+    pub daemon_dir: Option<String>,
+
+    /// Directory the service logs are written to.
+    #[arg(long, env = "ROVERD_LOG_DIR")]
+    pub log_dir: Option<String>,
+
+    /// Directory the build logs are written to.
+    #[arg(long, env = "ROVERD_BUILD_LOG_DIR")]
+    pub build_log_dir: Option<String>,
+
+    /// Size in bytes at which a log file gets rotated, 0 disables rotation.
+    #[arg(long, env = "ROVERD_LOG_MAX_SIZE")]
+    pub log_max_size: Option<u64>,
+
+    /// Number of rotated log files (.1, .2, ...) that are kept around.
+    #[arg(long, env = "ROVERD_LOG_RETENTION")]
+    pub log_retention: Option<u32>,
+
+    /// Whether rotated log files are gzip compressed.
+    #[arg(long, env = "ROVERD_LOG_COMPRESS")]
+    pub log_compress: Option<bool>,
+
+    /// Directory the history of pipeline runs (and their logs) is kept in.
+    #[arg(long, env = "ROVERD_RUN_DIR")]
+    pub run_dir: Option<String>,
+
+    /// Number of past runs that are kept around.
+    #[arg(long, env = "ROVERD_RUN_RETENTION")]
+    pub run_retention: Option<u32>,
+
+    /// Milliseconds to wait after starting a service before starting the services that
+    /// take inputs from it.
+    #[arg(long, env = "ROVERD_START_DELAY")]
+    pub start_delay: Option<u64>,
+
+    /// Directory incoming service archives are downloaded and extracted in, every install
+    /// gets its own subdirectory.
+    #[arg(long, env = "ROVERD_STAGING_DIR")]
+    pub staging_dir: Option<String>,
+
+    /// Whether installed services must be signed by one of the trusted keys.
+    #[arg(long, env = "ROVERD_SIGNATURE_POLICY", value_enum)]
+    pub signature_policy: Option<SignaturePolicy>,
+
+    /// Owner uid of installed service files.
+    #[arg(long, env = "ROVERD_UID")]
+    pub uid: Option<u32>,
+
+    /// Owner gid of installed service files.
+    #[arg(long, env = "ROVERD_GID")]
+    pub gid: Option<u32>,
+}
+
+/// How the signatures of uploaded and fetched services are treated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum SignaturePolicy {
+    /// Signatures are not looked at.
+    Ignore,
+    /// Unsigned services are installed, but a signature that is present must be valid and
+    /// made with a trusted key.
+    #[default]
+    Verify,
+    /// Only services with a valid signature of a trusted key are installed.
+    Require,
+}
+
+/// Runtime settings of roverd. All paths default to the conventions described in
+/// docs/02-conventions.md, so an empty or missing settings file behaves exactly like
+/// a regular rover.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub listen_address: String,
+
+    /// The HTTPS listener is only started if this is set. A self-signed certificate and key
+    /// are generated when neither of the files exists.
+    pub tls_listen_address: Option<String>,
+    pub tls_cert_file: String,
+    pub tls_key_file: String,
+
+    /// Web pages that may use the API from a browser, `*` allows all of them.
+    pub cors_allowed_origins: Vec<String>,
+    pub cors_max_age: u64,
+
+    pub info_file: String,
+    pub config_dir: String,
+    pub user: String,
+    pub rover_dir: String,
+    pub daemon_dir: String,
+    pub log_dir: String,
+    pub build_log_dir: String,
+    pub log_max_size: u64,
+    pub log_retention: u32,
+    pub log_compress: bool,
+    pub run_dir: String,
+    pub run_retention: u32,
+    pub start_delay: u64,
+    pub staging_dir: String,
+    pub signature_policy: SignaturePolicy,
+
+    /// Ownership of installed service files, `None` leaves the owner untouched.
+    pub uid: Option<u32>,
+    pub gid: Option<u32>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            listen_address: LISTEN_ADDRESS.to_string(),
+            tls_listen_address: None,
+            tls_cert_file: TLS_CERT_FILE.to_string(),
+            tls_key_file: TLS_KEY_FILE.to_string(),
+            cors_allowed_origins: CORS_ALLOWED_ORIGINS.map(String::from).to_vec(),
+            cors_max_age: CORS_MAX_AGE,
+            info_file: ROVER_INFO_FILE.to_string(),
+            config_dir: ROVER_CONFIG_DIR.to_string(),
+            user: ROVER_USER.to_string(),
+            rover_dir: ROVER_DIR.to_string(),
+            daemon_dir: DAEMON_DIR.to_string(),
+            log_dir: LOG_DIR.to_string(),
+            build_log_dir: BUILD_LOG_DIR.to_string(),
+            log_max_size: LOG_MAX_SIZE,
+            log_retention: LOG_RETENTION,
+            log_compress: LOG_COMPRESS,
+            run_dir: RUN_DIR.to_string(),
+            run_retention: RUN_RETENTION,
+            start_delay: START_DELAY,
+            staging_dir: STAGING_DIR.to_string(),
+            signature_policy: SignaturePolicy::default(),
+            uid: DEBIX_UID,
+            gid: DEBIX_GID,
+        }
+    }
+}
+
+impl Settings {
+    /// Parses the command line and environment and builds the settings from them.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::load(Args::parse())
+    }
+
+    /// Reads the settings file (if it exists) and applies the overrides from the command
+    /// line / environment on top of it. Anything left unspecified keeps its default.
+    pub fn load(args: Args) -> Result<Self, Error> {
+        let mut settings = if Path::new(&args.settings).exists() {
+            info!("reading settings from {}", args.settings);
+            let contents =
+                std::fs::read_to_string(&args.settings).map_err(|_| Error::SettingsFileIO)?;
+            serde_yaml::from_str::<Settings>(&contents)?
+        } else {
+            Settings::default()
+        };
+
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                $(if let Some(value) = args.$field {
+                    settings.$field = value;
+                })*
+            };
+        }
+
+        apply!(
+            listen_address,
+            tls_cert_file,
+            tls_key_file,
+            cors_allowed_origins,
+            cors_max_age,
+            info_file,
+            config_dir,
+            user,
+            rover_dir,
+            daemon_dir,
+            log_dir,
+            build_log_dir,
+            log_max_size,
+            log_retention,
+            log_compress,
+            run_dir,
+            run_retention,
+            start_delay,
+            staging_dir,
+            signature_policy
+        );
+
+        if args.tls_listen_address.is_some() {
+            settings.tls_listen_address = args.tls_listen_address;
+        }
+        if args.uid.is_some() {
+            settings.uid = args.uid;
+        }
+        if args.gid.is_some() {
+            settings.gid = args.gid;
+        }
+
+        Ok(settings)
+    }
+
+    /// The pipeline configuration (rover.yaml) inside the config directory.
+    pub fn config_file(&self) -> String {
+        format!("{}/{}", self.config_dir, ROVER_CONFIG_FILE_NAME)
+    }
+
+    /// Copy of the last rover.yaml that was written by roverd, used when rover.yaml is corrupt.
+    pub fn config_backup_file(&self) -> String {
+        format!("{}/{}", self.config_dir, ROVER_CONFIG_BACKUP_FILE_NAME)
+    }
+
+    /// The directory with the named pipeline profiles inside the config directory.
+    pub fn profile_dir(&self) -> String {
+        format!("{}/{}", self.config_dir, PROFILE_DIR_NAME)
+    }
+
+    pub fn profile_file(&self, name: &str) -> String {
+        format!("{}/{}.yaml", self.profile_dir(), name)
+    }
+
+    /// Accounts besides the rover user, maintained by hand.
+    pub fn users_file(&self) -> String {
+        format!("{}/{}", self.config_dir, USERS_FILE_NAME)
+    }
+
+    /// API tokens, maintained by roverd.
+    pub fn tokens_file(&self) -> String {
+        format!("{}/{}", self.config_dir, TOKENS_FILE_NAME)
+    }
+
+    /// The directory with the minisign public keys that services may be signed with.
+    pub fn trusted_keys_dir(&self) -> String {
+        format!("{}/{}", self.config_dir, TRUSTED_KEYS_DIR_NAME)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_precedence() {
+        let file =
+            std::env::temp_dir().join(format!("roverd-settings-{}.yaml", std::process::id()));
+        std::fs::write(
+            &file,
+            "listen_address: 0.0.0.0:1\nlog_dir: /file/logs\nrun_dir: /file/runs\n",
+        )
+        .unwrap();
+        let file = file.to_string_lossy().to_string();
+
+        // Only Args::parse reads these, which no other test calls
+        std::env::set_var("ROVERD_LISTEN_ADDRESS", "0.0.0.0:2");
+        std::env::set_var("ROVERD_LOG_DIR", "/env/logs");
+        let args = Args::try_parse_from([
+            "roverd",
+            "--settings",
+            &file,
+            "--listen-address",
+            "0.0.0.0:3",
+        ]);
+        std::env::remove_var("ROVERD_LISTEN_ADDRESS");
+        std::env::remove_var("ROVERD_LOG_DIR");
+
+        // The command line beats the environment, which beats the settings file, which beats
+        // the defaults
+        let settings = Settings::load(args.unwrap()).unwrap();
+        assert_eq!(settings.listen_address, "0.0.0.0:3");
+        assert_eq!(settings.log_dir, "/env/logs");
+        assert_eq!(settings.run_dir, "/file/runs");
+        assert_eq!(settings.staging_dir, STAGING_DIR);
+
+        // Without a settings file only the flags are applied to the defaults
+        std::fs::remove_file(&file).unwrap();
+        let args = Args::try_parse_from(["roverd", "--settings", &file, "--run-retention", "3"]);
+        let settings = Settings::load(args.unwrap()).unwrap();
+        assert_eq!(
+            settings,
+            Settings {
+                run_retention: 3,
+                ..Settings::default()
+            }
+        );
+    }
+}
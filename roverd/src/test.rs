@@ -6,13 +6,18 @@ use once_cell::sync::Lazy;
 use serde_json::json;
 use tokio::sync::OnceCell;
 
+use axum::extract::Host;
+use axum_extra::extract::CookieJar;
+use openapi::apis::pipeline::{Pipeline as _, PipelinePostResponse};
 use openapi::models::*;
 use reqwest::multipart;
 use reqwest::{header, Client, Response};
+use tower::ServiceExt;
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 
 use crate::*;
+use settings::SignaturePolicy;
 
 static SERVER: Lazy<OnceCell<Result<(), Error>>> = Lazy::new(OnceCell::new);
 
@@ -20,16 +25,15 @@ async fn start_server() -> Result<(), Error> {
     log::init();
     info!("logging initialized");
 
-    let rover_state = Roverd::new().await?;
+    let settings = Arc::new(Settings::default());
+    let rover_state = Roverd::new(Arc::clone(&settings)).await?;
 
-    let router = openapi::server::new(rover_state.clone())
-        .layer(middleware::from_fn_with_state(
-            rover_state.clone(),
-            auth_wrapper,
-        ))
-        .layer(CorsLayer::permissive());
+    let origins = cors::AllowedOrigins::from_settings(&settings)?;
+    let router = router(rover_state, &origins, settings.cors_max_age);
 
-    let listener = tokio::net::TcpListener::bind(LISTEN_ADDRESS).await.unwrap();
+    let listener = tokio::net::TcpListener::bind(&settings.listen_address)
+        .await
+        .unwrap();
 
     tokio::spawn(async move {
         axum::serve(listener, router).await.unwrap();
@@ -77,6 +81,103 @@ async fn check_status(client: &Client) -> Result<(), Error> {
     Ok(())
 }
 
+/// Settings that keep all state of roverd in a fresh directory, with "debix:debix" as login.
+fn temp_settings(name: &str) -> Settings {
+    let dir = std::env::temp_dir().join(format!("roverd-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let dir = dir.to_string_lossy().to_string();
+
+    let info_file = format!("{}/info.txt", dir);
+    std::fs::write(
+        &info_file,
+        format!("13\nbunny\n{}\n", sha256::digest("debix")),
+    )
+    .unwrap();
+
+    Settings {
+        info_file,
+        config_dir: dir.clone(),
+        rover_dir: format!("{}/rover", dir),
+        daemon_dir: format!("{}/daemons", dir),
+        log_dir: format!("{}/logs", dir),
+        build_log_dir: format!("{}/buildlogs", dir),
+        run_dir: format!("{}/runs", dir),
+        staging_dir: format!("{}/staging", dir),
+        uid: None,
+        gid: None,
+        ..Settings::default()
+    }
+}
+
+/// Sends a request straight to the router, without a listener, as if it came from localhost.
+async fn request(
+    roverd: &Roverd,
+    settings: &Settings,
+    login: &str,
+    method: Method,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> StatusCode {
+    let origins = cors::AllowedOrigins::from_settings(settings).unwrap();
+    let mut req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(http::header::HOST, "localhost")
+        .header(http::header::CONTENT_TYPE, "application/json")
+        .header(
+            http::header::AUTHORIZATION,
+            format!("Basic {}", STANDARD.encode(login)),
+        )
+        .body(match body {
+            Some(body) => axum::body::Body::from(body.to_string()),
+            None => axum::body::Body::empty(),
+        })
+        .unwrap();
+    req.extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 1234))));
+
+    router(roverd.clone(), &origins, settings.cors_max_age)
+        .oneshot(req)
+        .await
+        .unwrap()
+        .status()
+}
+
+/// Installs a service of the "vu-ase" author at version 1.0.0, which takes the output of each
+/// of the given services as input and has a "speed" option.
+fn install_service(settings: &Settings, name: &str, inputs: &[&str]) {
+    let dir = format!("{}/vu-ase/{}/1.0.0", settings.rover_dir, name);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let inputs: String = inputs
+        .iter()
+        .map(|input| format!("  - service: {}\n    streams:\n      - {}\n", input, input))
+        .collect();
+    std::fs::write(
+        format!("{}/service.yaml", dir),
+        format!(
+            "name: {name}\nauthor: vu-ase\nsource: github.com/vu-ase/{name}\nversion: 1.0.0\n\n\
+             commands:\n  run: ./{name}\n\ninputs:\n{inputs}\noutputs:\n  - {name}\n\n\
+             configuration:\n  - name: speed\n    value: 1\n"
+        ),
+    )
+    .unwrap();
+}
+
+fn enabled(names: &[&str]) -> Vec<PipelinePostRequestInner> {
+    names
+        .iter()
+        .map(|name| {
+            PipelinePostRequestInner::new(
+                name.to_string(),
+                "1.0.0".to_string(),
+                "vu-ase".to_string(),
+            )
+        })
+        .collect()
+}
+
 async fn delete_service(client: &Client, s: &str) -> Response {
     client.delete(s).send().await.unwrap()
 }
@@ -205,3 +306,446 @@ async fn all() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn restored_config_keeps_api_available() {
+    let settings = temp_settings("restore");
+    std::fs::write(settings.config_backup_file(), "enabled: []\n").unwrap();
+    std::fs::write(settings.config_file(), "enabled: [\n").unwrap();
+
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+    assert_eq!(roverd.info.read().await.status, DaemonStatus::Recoverable);
+
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::GET,
+        "/pipeline",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn invalid_accounts_leave_rover_user() {
+    let settings = temp_settings("accounts");
+    std::fs::write(settings.users_file(), "users: [\n").unwrap();
+
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+    assert_eq!(roverd.info.read().await.status, DaemonStatus::Recoverable);
+
+    // Listing the tokens is only allowed for admins
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::GET,
+        "/auth/tokens",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn password_changes_are_limited_and_throttled() {
+    let settings = temp_settings("password");
+    std::fs::write(
+        settings.users_file(),
+        format!(
+            "users:\n  - name: alice\n    role: viewer\n    password_hash: {}\n",
+            sha256::digest("alicepassword")
+        ),
+    )
+    .unwrap();
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+
+    let change_password = |username: &str, password: &str| {
+        request(
+            &roverd,
+            &settings,
+            "alice:alicepassword",
+            Method::POST,
+            "/auth/password",
+            Some(json!({
+                "username": username,
+                "password": password,
+                "new_password": "alicepassword2"
+            })),
+        )
+    };
+
+    // A viewer can not try passwords of other accounts
+    let status = change_password("debix", "debix").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    // Guessing the current password is throttled, even though every request is authenticated
+    for _ in 1..constants::MAX_FAILED_LOGINS {
+        let status = change_password("alice", "wrong").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+    let status = change_password("alice", "wrong").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    let status = change_password("alice", "alicepassword").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn fqns_skip_installs_in_progress() {
+    let settings = temp_settings("fqns");
+    for dir in [
+        "vu-ase/imaging/1.0.0",
+        "vu-ase/imaging/.1.0.1.incoming-1234",
+        "vu-ase/.controller/1.0.0",
+        ".staging/imaging/1.0.0",
+    ] {
+        std::fs::create_dir_all(format!("{}/{}", settings.rover_dir, dir)).unwrap();
+    }
+
+    let roverd = Roverd::new(Arc::new(settings)).await.unwrap();
+    let fqns = roverd.app.get_fqns().await.unwrap();
+
+    assert_eq!(fqns.len(), 1);
+    assert_eq!(
+        (fqns[0].author.as_str(), fqns[0].name.as_str()),
+        ("vu-ase", "imaging")
+    );
+    assert_eq!(fqns[0].version, "1.0.0");
+}
+
+#[tokio::test]
+async fn pipeline_warnings_do_not_reject_pipeline() {
+    let settings = temp_settings("warnings");
+    install_service(&settings, "a", &["b"]);
+    install_service(&settings, "b", &["a"]);
+    install_service(&settings, "c", &["d"]);
+    let roverd = Roverd::new(Arc::new(settings)).await.unwrap();
+
+    let post = |names: &[&str]| {
+        roverd.pipeline_post(
+            Method::POST,
+            Host("localhost".to_string()),
+            CookieJar::new(),
+            enabled(names),
+        )
+    };
+
+    // Services that depend on each other are only a warning
+    let response = post(&["a", "b"]).await.unwrap();
+    assert_eq!(
+        response,
+        PipelinePostResponse::Status200_ThePipelineWasUpdatedSuccessfully
+    );
+    assert_eq!(
+        roverd.app.stats.read().await.status,
+        PipelineStatus::Startable
+    );
+
+    // Once the pipeline is rejected for an error, the warnings are returned along with it
+    let response = post(&["a", "b", "c"]).await.unwrap();
+    let PipelinePostResponse::Status400_ThePipelineWasNotValidAndCouldNotBeSet(rejected) = response
+    else {
+        panic!("expected the pipeline to be rejected, got {:?}", response);
+    };
+    let errors = rejected.validation_errors;
+    assert_eq!(errors.unmet_services.map(|u| u.len()), Some(1));
+    let cycles = errors.cycles.unwrap();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(
+        cycles[0].services,
+        Some(vec!["a".to_string(), "b".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn admins_can_reset_passwords() {
+    let settings = temp_settings("reset");
+    std::fs::write(
+        settings.users_file(),
+        format!(
+            "users:\n  - name: alice\n    role: viewer\n    password_hash: {}\n",
+            sha256::digest("alicepassword")
+        ),
+    )
+    .unwrap();
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+
+    // The current password of alice is not known to the admin
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::POST,
+        "/auth/password",
+        Some(json!({
+            "username": "alice",
+            "password": "forgotten",
+            "new_password": "alicepassword2"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let login =
+        |login: &'static str| request(&roverd, &settings, login, Method::GET, "/pipeline", None);
+    assert_eq!(login("alice:alicepassword2").await, StatusCode::OK);
+    assert_eq!(login("alice:alicepassword").await, StatusCode::UNAUTHORIZED);
+
+    // Changing their own password still needs the current one, also for admins
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::POST,
+        "/auth/password",
+        Some(json!({
+            "username": "debix",
+            "password": "wrong",
+            "new_password": "debixpassword"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn profiles_can_be_saved_and_activated() {
+    let settings = temp_settings("profiles");
+    install_service(&settings, "a", &[]);
+    install_service(&settings, "b", &["a"]);
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::POST,
+        "/pipeline/profiles/driving",
+        Some(json!([
+            { "author": "vu-ase", "name": "a", "version": "1.0.0" },
+            {
+                "author": "vu-ase",
+                "name": "b",
+                "version": "1.0.0",
+                "configuration": [{ "name": "speed", "value": 2 }]
+            }
+        ])),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let profiles = roverd.app.get_profiles().await.unwrap();
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].name, "driving");
+    let services: Vec<&str> = profiles[0]
+        .services
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(services, ["a", "b"]);
+    assert_eq!(profiles[0].services[0].configuration, None);
+    let configuration = profiles[0].services[1].configuration.as_ref().unwrap();
+    assert_eq!(configuration[0].name, "speed");
+    assert_eq!(configuration[0].value.0.get(), "2.0");
+
+    // Activating the profile sets the pipeline to the saved services and values
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::POST,
+        "/pipeline/profiles/driving/activate",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let config = app::get_config(&settings).await.unwrap();
+    let b = format!("{}/vu-ase/b/1.0.0/service.yaml", settings.rover_dir);
+    assert_eq!(
+        config.enabled,
+        [
+            format!("{}/vu-ase/a/1.0.0/service.yaml", settings.rover_dir),
+            b.clone()
+        ]
+    );
+    let overrides = config.overrides.unwrap();
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].service, b);
+    assert_eq!(overrides[0].configuration[0].name, "speed");
+    assert_eq!(
+        roverd.app.stats.read().await.status,
+        PipelineStatus::Startable
+    );
+
+    // Names that could point elsewhere are rejected before anything is written
+    let result = roverd
+        .app
+        .save_profile("../rover".to_string(), vec![])
+        .await;
+    assert!(matches!(result, Err(Error::ProfileNameInvalid(_))));
+    let result = roverd.app.delete_profile("".to_string()).await;
+    assert!(matches!(result, Err(Error::ProfileNameInvalid(_))));
+}
+
+/// A zip archive of a "vu-ase" service at version 1.0.0, as it would be downloaded or uploaded.
+fn service_zip(name: &str) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+    zip.start_file("service.yaml", zip::write::FileOptions::default())
+        .unwrap();
+    write!(
+        zip,
+        "name: {name}\nauthor: vu-ase\nsource: github.com/vu-ase/{name}\nversion: 1.0.0\n\n\
+         commands:\n  run: ./{name}\n\ninputs: []\noutputs: []\nconfiguration: []\n"
+    )
+    .unwrap();
+    zip.finish().unwrap().into_inner()
+}
+
+#[tokio::test]
+async fn fetched_services_are_checked_against_their_digest() {
+    let settings = temp_settings("fetch-digest");
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+
+    let archive = service_zip("fetched");
+    let digest = sha256::digest(&archive[..]);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/fetched.zip", listener.local_addr().unwrap());
+    let downloads = Router::new().route("/fetched.zip", axum::routing::get(|| async { archive }));
+    tokio::spawn(async move { axum::serve(listener, downloads).await.unwrap() });
+
+    let installed = Path::new(&settings.rover_dir).join("vu-ase/fetched/1.0.0/service.yaml");
+
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::POST,
+        "/fetch",
+        Some(json!({ "url": url, "sha256": sha256::digest("something else") })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(!installed.exists());
+
+    // The digest is compared regardless of its case
+    let status = request(
+        &roverd,
+        &settings,
+        "debix:debix",
+        Method::POST,
+        "/fetch",
+        Some(json!({ "url": url, "sha256": digest.to_uppercase() })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(installed.exists());
+}
+
+#[tokio::test]
+async fn uploads_are_checked_against_their_digest() {
+    let settings = temp_settings("upload-digest");
+    let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+    let origins = cors::AllowedOrigins::from_settings(&settings).unwrap();
+
+    let archive = service_zip("uploaded");
+    let boundary = "roverd-test-boundary";
+    let mut body = format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"sha256\"\r\n\r\n{}\r\n\
+         --{boundary}\r\nContent-Disposition: form-data; name=\"content\"; \
+         filename=\"uploaded.zip\"\r\nContent-Type: application/zip\r\n\r\n",
+        sha256::digest("something else")
+    )
+    .into_bytes();
+    body.extend_from_slice(&archive);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let mut req = Request::builder()
+        .method(Method::POST)
+        .uri("/upload")
+        .header(http::header::HOST, "localhost")
+        .header(
+            http::header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={}", boundary),
+        )
+        .header(
+            http::header::AUTHORIZATION,
+            format!("Basic {}", STANDARD.encode("debix:debix")),
+        )
+        .body(axum::body::Body::from(body))
+        .unwrap();
+    req.extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 1234))));
+
+    let status = router(roverd.clone(), &origins, settings.cors_max_age)
+        .oneshot(req)
+        .await
+        .unwrap()
+        .status();
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(!Path::new(&settings.rover_dir)
+        .join("vu-ase/uploaded")
+        .exists());
+}
+
+#[tokio::test]
+async fn signatures_that_can_not_be_downloaded_depend_on_the_policy() {
+    let archive = service_zip("unsigned");
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/unsigned.zip", listener.local_addr().unwrap());
+    let downloads = Router::new()
+        .route("/unsigned.zip", axum::routing::get(|| async { archive }))
+        .route(
+            "/unsigned.zip.minisig",
+            axum::routing::get(|| async { StatusCode::FORBIDDEN }),
+        );
+    tokio::spawn(async move { axum::serve(listener, downloads).await.unwrap() });
+
+    // Under verify the service is installed as if it was unsigned, under require it is not
+    for (policy, expected) in [
+        (SignaturePolicy::Verify, StatusCode::OK),
+        (SignaturePolicy::Require, StatusCode::BAD_REQUEST),
+    ] {
+        let settings = Settings {
+            signature_policy: policy,
+            ..temp_settings(&format!("signature-fallback-{:?}", policy))
+        };
+        let roverd = Roverd::new(Arc::new(settings.clone())).await.unwrap();
+
+        let status = request(
+            &roverd,
+            &settings,
+            "debix:debix",
+            Method::POST,
+            "/fetch",
+            Some(json!({ "url": url })),
+        )
+        .await;
+        assert_eq!(status, expected, "{:?}", policy);
+        assert_eq!(
+            Path::new(&settings.rover_dir)
+                .join("vu-ase/unsigned/1.0.0/service.yaml")
+                .exists(),
+            expected == StatusCode::OK,
+            "{:?}",
+            policy
+        );
+    }
+
+    // The same goes for a signature of which the host can not be reached at all
+    let settings = temp_settings("signature-unreachable");
+    let unreachable = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}/gone.zip", listener.local_addr().unwrap())
+    };
+    let staging = util::staging::Staging::new(&settings).unwrap();
+    let result = util::download_signature(&staging, &unreachable, SignaturePolicy::Verify).await;
+    assert!(result.is_ok(), "{:?}", result);
+    assert!(!Path::new(&staging.signature()).exists());
+    let result = util::download_signature(&staging, &unreachable, SignaturePolicy::Require).await;
+    assert!(result.is_err());
+}
@@ -0,0 +1,188 @@
+use std::fs;
+use std::io::Write;
+use std::net::{SocketAddr, TcpListener};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+
+use anyhow::Context;
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use sha2::{Digest, Sha256};
+use sysinfo::System;
+use tracing::{error, info};
+
+use crate::error::Error;
+use crate::settings::Settings;
+
+/// Configuration of the HTTPS listener, with the fingerprint of its certificate so clients
+/// can pin it.
+pub struct Tls {
+    pub config: RustlsConfig,
+    pub fingerprint: String,
+}
+
+/// Binds the HTTPS listener and loads its certificate.
+pub async fn bind(settings: &Settings, address: &str) -> Result<(TcpListener, Tls), Error> {
+    let tls = load_or_generate(settings).await?;
+
+    let listener =
+        TcpListener::bind(address).with_context(|| format!("failed to bind to {}", address))?;
+    listener
+        .set_nonblocking(true)
+        .with_context(|| format!("failed to bind to {}", address))?;
+
+    Ok((listener, tls))
+}
+
+/// Serves the router over HTTPS in the background, next to the plain HTTP listener.
+pub fn serve(listener: TcpListener, config: RustlsConfig, router: Router) {
+    tokio::spawn(async move {
+        let server = axum_server::from_tcp_rustls(listener, config)
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>());
+
+        if let Err(e) = server.await {
+            error!("https listener failed: {:?}", e);
+        }
+    });
+}
+
+/// Loads the certificate and key of the HTTPS listener. If neither of them exists yet, a
+/// self-signed certificate is generated and kept for the next boots.
+pub async fn load_or_generate(settings: &Settings) -> Result<Tls, Error> {
+    let (cert_file, key_file) = (&settings.tls_cert_file, &settings.tls_key_file);
+
+    match (Path::new(cert_file).exists(), Path::new(key_file).exists()) {
+        (true, true) => (),
+        (false, false) => generate_self_signed(cert_file, key_file)?,
+        _ => return Err(Error::TlsKeyPairIncomplete),
+    }
+
+    let cert = fs::read(cert_file).with_context(|| format!("failed to read {}", cert_file))?;
+    let key = fs::read(key_file).with_context(|| format!("failed to read {}", key_file))?;
+
+    let fingerprint = fingerprint(&cert)?;
+    let config = RustlsConfig::from_pem(cert, key)
+        .await
+        .map_err(|e| Error::TlsCertificateInvalid(e.to_string()))?;
+
+    Ok(Tls {
+        config,
+        fingerprint,
+    })
+}
+
+/// The SHA-256 digest of the first (DER encoded) certificate in the file, which is the same
+/// fingerprint that browsers and `openssl x509 -fingerprint -sha256` show.
+fn fingerprint(cert_pem: &[u8]) -> Result<String, Error> {
+    let der = rustls_pemfile::certs(&mut &cert_pem[..])
+        .next()
+        .ok_or_else(|| Error::TlsCertificateInvalid("no certificate found".to_string()))?
+        .map_err(|e| Error::TlsCertificateInvalid(e.to_string()))?;
+
+    Ok(format!("{:x}", Sha256::digest(&der)))
+}
+
+/// The certificate is valid for the hostname of the rover and localhost, but since it is not
+/// signed by anyone clients have to pin its fingerprint instead.
+fn generate_self_signed(cert_file: &str, key_file: &str) -> Result<(), Error> {
+    let mut names = vec!["localhost".to_string()];
+    if let Some(hostname) = System::host_name() {
+        names.insert(0, hostname);
+    }
+
+    let certified = rcgen::generate_simple_self_signed(names)
+        .map_err(|e| Error::TlsCertificateInvalid(e.to_string()))?;
+
+    for file in [cert_file, key_file] {
+        if let Some(dir) = Path::new(file).parent() {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+    }
+
+    // Both files are written next to their destination first and only then renamed into
+    // place, so a failure can not leave a key without a certificate (which every later boot
+    // would refuse)
+    let key = certified.key_pair.serialize_pem();
+    let (cert_tmp, key_tmp) = (format!("{}.tmp", cert_file), format!("{}.tmp", key_file));
+    let result = write_new(&key_tmp, key.as_bytes(), 0o600)
+        .and_then(|_| write_new(&cert_tmp, certified.cert.pem().as_bytes(), 0o644))
+        .and_then(|_| rename(&key_tmp, key_file))
+        .and_then(|_| {
+            rename(&cert_tmp, cert_file).inspect_err(|_| {
+                let _ = fs::remove_file(key_file);
+            })
+        });
+    if result.is_err() {
+        let _ = fs::remove_file(&key_tmp);
+        let _ = fs::remove_file(&cert_tmp);
+    }
+    result?;
+
+    info!("generated a self-signed certificate in {}", cert_file);
+    Ok(())
+}
+
+/// Writes a file that did not exist before with the given permissions, so the private key is
+/// never readable by others (not even briefly).
+fn write_new(file: &str, contents: &[u8], mode: u32) -> anyhow::Result<()> {
+    let _ = fs::remove_file(file);
+
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(mode)
+        .open(file)
+        .and_then(|mut f| {
+            f.write_all(contents)?;
+            f.sync_all()
+        })
+        .with_context(|| format!("failed to write {}", file))
+}
+
+fn rename(from: &str, to: &str) -> anyhow::Result<()> {
+    fs::rename(from, to).with_context(|| format!("failed to write {}", to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[tokio::test]
+    async fn test_load_or_generate() {
+        let dir = std::env::temp_dir().join(format!("roverd-tls-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let settings = Settings {
+            tls_cert_file: dir.join("tls/cert.pem").to_string_lossy().to_string(),
+            tls_key_file: dir.join("tls/key.pem").to_string_lossy().to_string(),
+            ..Settings::default()
+        };
+
+        // The first boot generates the key pair, the key is only readable by roverd
+        let generated = load_or_generate(&settings).await.unwrap();
+        let mode = |file: &str| fs::metadata(file).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&settings.tls_key_file), 0o600);
+        assert_eq!(mode(&settings.tls_cert_file), 0o644);
+        assert!(!Path::new(&format!("{}.tmp", settings.tls_key_file)).exists());
+
+        // Later boots load the same certificate, so its pinned fingerprint stays valid
+        let cert = fs::read(&settings.tls_cert_file).unwrap();
+        let key = fs::read(&settings.tls_key_file).unwrap();
+        let loaded = load_or_generate(&settings).await.unwrap();
+        assert_eq!(loaded.fingerprint, generated.fingerprint);
+        assert_eq!(loaded.fingerprint.len(), 64);
+        assert_eq!(fs::read(&settings.tls_cert_file).unwrap(), cert);
+        assert_eq!(fs::read(&settings.tls_key_file).unwrap(), key);
+
+        // A key without its certificate is not silently replaced
+        fs::remove_file(&settings.tls_cert_file).unwrap();
+        assert!(matches!(
+            load_or_generate(&settings).await,
+            Err(Error::TlsKeyPairIncomplete)
+        ));
+        assert_eq!(fs::read(&settings.tls_key_file).unwrap(), key);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
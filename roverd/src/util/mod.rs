@@ -1,12 +1,13 @@
 use anyhow::{anyhow, Context, Result};
 use reqwest::Client;
+use sha2::{Digest, Sha256};
 use std::fs::{File, OpenOptions};
-use std::os::unix::fs::chown;
+use std::os::unix::fs::{chown, lchown, symlink, PermissionsExt};
 use std::time::Duration;
 use std::{
     fs,
-    io::{self, Read, Write},
-    path::{Path, PathBuf},
+    io::{self, Read, Seek, Write},
+    path::{Component, Path, PathBuf},
 };
 
 use axum::http::StatusCode;
@@ -16,16 +17,27 @@ use rovervalidate::config::{Configuration, Validate};
 use rovervalidate::service::Service;
 use tracing::{info, warn};
 
-use crate::error::Error;
+use crate::error::{ArchiveRejection, Error};
 use crate::service::FqBuf;
+use crate::settings::{Settings, SignaturePolicy};
 
 use crate::constants::*;
 
+pub mod signature;
+pub mod staging;
+
+use signature::verify_signature;
+use staging::{lock_service_dir, Staging};
+
 /// Copies all files from source to destination recursively and sets ownership of all
-/// desitnation files to "debix:debix".
-pub fn copy_recursively(source: impl AsRef<Path>, destination_dir: impl AsRef<Path>) -> Result<()> {
+/// desitnation files to the configured uid and gid ("debix:debix" by default).
+pub fn copy_recursively(
+    settings: &Settings,
+    source: impl AsRef<Path>,
+    destination_dir: impl AsRef<Path>,
+) -> Result<()> {
     fs::create_dir_all(&destination_dir)?;
-    chown(&destination_dir, DEBIX_UID, DEBIX_GID).with_context(|| {
+    chown(&destination_dir, settings.uid, settings.gid).with_context(|| {
         format!(
             "failed to set the ownership of directory: {:?}",
             destination_dir.as_ref()
@@ -40,10 +52,19 @@ pub fn copy_recursively(source: impl AsRef<Path>, destination_dir: impl AsRef<Pa
         // Make sure all files copied over have debix:debix permissions so
         // that the build command succeeds
         if filetype.is_dir() {
-            copy_recursively(entry.path(), &destination_file)?;
+            copy_recursively(settings, entry.path(), &destination_file)?;
+        } else if filetype.is_symlink() {
+            // Symlinks were checked to stay within the service when it was extracted
+            symlink(fs::read_link(entry.path())?, &destination_file)?;
+            lchown(&destination_file, settings.uid, settings.gid).with_context(|| {
+                format!(
+                    "failed to set the ownership of symlink: {:?}",
+                    &destination_file
+                )
+            })?;
         } else {
             fs::copy(entry.path(), &destination_file)?;
-            chown(&destination_file, DEBIX_UID, DEBIX_GID).with_context(|| {
+            chown(&destination_file, settings.uid, settings.gid).with_context(|| {
                 format!(
                     "failed to set the ownership of file: {:?}",
                     &destination_file
@@ -54,50 +75,288 @@ pub fn copy_recursively(source: impl AsRef<Path>, destination_dir: impl AsRef<Pa
     Ok(())
 }
 
-/// Extracts the contents of the zip file into the directory at destination_dir.
-pub fn extract_zip(zip_file: &str, destination_dir: &str) -> Result<(), Error> {
+/// Service archives are recognized by their first bytes rather than by their file name, since
+/// neither uploads nor download URLs need to have one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArchiveFormat {
+    Zip,
+    TarGz,
+    TarZst,
+}
+
+impl ArchiveFormat {
+    fn detect(file: &mut fs::File) -> Result<ArchiveFormat, Error> {
+        let mut magic = [0u8; 4];
+        let read = file
+            .read(&mut magic)
+            .and_then(|n| file.rewind().map(|_| n))
+            .with_context(|| "failed to read archive header".to_string())?;
+
+        match &magic[..read] {
+            [b'P', b'K', 0x03, 0x04] | [b'P', b'K', 0x05, 0x06] => Ok(ArchiveFormat::Zip),
+            [0x1f, 0x8b, ..] => Ok(ArchiveFormat::TarGz),
+            [0x28, 0xb5, 0x2f, 0xfd] => Ok(ArchiveFormat::TarZst),
+            _ => Err(Error::ArchiveFormatUnknown),
+        }
+    }
+}
+
+/// An archive entry as it is written to disk, independent of the archive format.
+enum ArchiveEntry {
+    File(u32),
+    Dir,
+    Symlink(String),
+    Special,
+}
+
+/// Writes the entries of an archive into a directory, keeping track of the limits that apply
+/// to the archive as a whole.
+struct ArchiveWriter {
+    target: PathBuf,
+    entries: usize,
+    remaining_size: u64,
+}
+
+impl ArchiveWriter {
+    fn write(
+        &mut self,
+        name: &str,
+        entry: ArchiveEntry,
+        contents: &mut impl Read,
+    ) -> Result<(), Error> {
+        let reject = |reason| Error::ArchiveEntryRejected(name.to_string(), reason);
+
+        if self.entries >= MAX_ARCHIVE_ENTRIES {
+            return Err(reject(ArchiveRejection::TooManyEntries));
+        }
+        self.entries += 1;
+
+        let relative =
+            archive_entry_path(name).ok_or_else(|| reject(ArchiveRejection::UnsafePath))?;
+        if through_symlink(&self.target, &relative) {
+            return Err(reject(ArchiveRejection::UnsafeSymlink));
+        }
+        let path = self.target.join(&relative);
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("failed to create {:?}", parent))?;
+        }
+
+        match entry {
+            ArchiveEntry::Symlink(link) => {
+                // Relative to the directory of the symlink, it must still point into the archive
+                let parent = relative.parent().unwrap_or(Path::new(""));
+                if !symlink_within_root(parent, &link) {
+                    return Err(reject(ArchiveRejection::UnsafeSymlink));
+                }
+
+                symlink(&link, &path).with_context(|| format!("failed to create {:?}", path))?;
+            }
+            ArchiveEntry::File(mode) => {
+                let mut out = fs::File::create(&path)
+                    .with_context(|| format!("failed to create {:?}", path))?;
+
+                // The sizes in the archive can not be trusted, so count what is actually written
+                let written = io::copy(&mut contents.take(self.remaining_size + 1), &mut out)
+                    .with_context(|| format!("failed to extract {}", name))?;
+                if written > self.remaining_size {
+                    return Err(reject(ArchiveRejection::TooLarge));
+                }
+                self.remaining_size -= written;
+
+                // Only keep the permission bits, setuid and friends are dropped
+                fs::set_permissions(&path, fs::Permissions::from_mode(mode & 0o777))
+                    .with_context(|| format!("failed to set permissions of {:?}", path))?;
+            }
+            ArchiveEntry::Dir => {
+                fs::create_dir_all(&path)
+                    .with_context(|| format!("failed to create {:?}", path))?;
+            }
+            ArchiveEntry::Special => return Err(reject(ArchiveRejection::SpecialFile)),
+        }
+
+        Ok(())
+    }
+}
+
+/// Extracts the contents of the archive (zip, tar.gz or tar.zst) into the directory at
+/// destination_dir. The archive comes from the user and ends up in the rover directory, so
+/// every entry is checked before it is written and the whole archive is rejected on the first
+/// entry that is not allowed.
+pub fn extract_archive(archive_file: &str, destination_dir: &str) -> Result<(), Error> {
     std::fs::create_dir_all(destination_dir)
         .with_context(|| format!("failed to create dirs {}", destination_dir))?;
 
     let mut file =
-        fs::File::open(zip_file).with_context(|| format!("failed to open {}", zip_file))?;
-    let mut bytes: Vec<u8> = Vec::new();
-    file.read_to_end(&mut bytes)
-        .with_context(|| format!("failed to read to end of {}", zip_file))?;
+        fs::File::open(archive_file).with_context(|| format!("failed to open {}", archive_file))?;
+
+    let mut writer = ArchiveWriter {
+        target: PathBuf::from(destination_dir),
+        entries: 0,
+        remaining_size: MAX_ARCHIVE_SIZE,
+    };
+
+    // Entries are read from the file as they are extracted, the archive is never fully in memory
+    let reader = io::BufReader::new(
+        file.try_clone()
+            .with_context(|| format!("failed to open {}", archive_file))?,
+    );
+    match ArchiveFormat::detect(&mut file)? {
+        ArchiveFormat::Zip => extract_zip(reader, &mut writer),
+        ArchiveFormat::TarGz => extract_tar(flate2::read::GzDecoder::new(reader), &mut writer),
+        ArchiveFormat::TarZst => {
+            let decoder = zstd::Decoder::with_buffer(reader)
+                .with_context(|| format!("failed to read {}", archive_file))?;
+            extract_tar(decoder, &mut writer)
+        }
+    }
+}
+
+fn extract_zip(reader: impl Read + Seek, writer: &mut ArchiveWriter) -> Result<(), Error> {
+    let mut zip = zip::ZipArchive::new(reader)?;
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i)?;
+        let name = entry.name().to_string();
+
+        let mode = entry.unix_mode().unwrap_or(libc::S_IFREG | 0o644);
+        let kind = if entry.is_dir() {
+            ArchiveEntry::Dir
+        } else {
+            match mode & libc::S_IFMT {
+                libc::S_IFLNK => {
+                    let mut link = String::new();
+                    entry
+                        .by_ref()
+                        .take(libc::PATH_MAX as u64)
+                        .read_to_string(&mut link)
+                        .with_context(|| format!("failed to read symlink {}", name))?;
+                    ArchiveEntry::Symlink(link)
+                }
+                libc::S_IFREG => ArchiveEntry::File(mode),
+                libc::S_IFDIR => ArchiveEntry::Dir,
+                _ => ArchiveEntry::Special,
+            }
+        };
+
+        writer.write(&name, kind, &mut entry)?;
+    }
+
+    Ok(())
+}
+
+fn extract_tar(reader: impl Read, writer: &mut ArchiveWriter) -> Result<(), Error> {
+    let mut tar = tar::Archive::new(reader);
+
+    let entries = tar
+        .entries()
+        .with_context(|| "failed to read tar archive".to_string())?;
+    for entry in entries {
+        let mut entry = entry.with_context(|| "failed to read tar entry".to_string())?;
+        let header = entry.header();
+        let entry_type = header.entry_type();
 
-    let target = PathBuf::from(destination_dir);
+        // Global pax headers only hold metadata for the entries that follow
+        if entry_type.is_pax_global_extensions() {
+            continue;
+        }
+
+        let name = entry
+            .path()
+            .with_context(|| "failed to read tar entry path".to_string())?
+            .to_string_lossy()
+            .to_string();
+        let mode = header
+            .mode()
+            .with_context(|| format!("failed to read mode of {}", name))?;
 
-    let data_cursor = io::Cursor::new(bytes);
-    let mut zip = zip::ZipArchive::new(data_cursor)?;
+        let kind = if entry_type.is_file() {
+            ArchiveEntry::File(mode)
+        } else if entry_type.is_dir() {
+            ArchiveEntry::Dir
+        } else if entry_type.is_symlink() {
+            match entry.link_name() {
+                Ok(Some(link)) => ArchiveEntry::Symlink(link.to_string_lossy().to_string()),
+                _ => ArchiveEntry::Special,
+            }
+        } else {
+            // Hard links, devices, fifos and the like
+            ArchiveEntry::Special
+        };
 
-    zip.extract(target)?;
+        writer.write(&name, kind, &mut entry)?;
+    }
 
     Ok(())
 }
 
-/// Makes sure the directories for a given service exist. If there is an
-/// existing service at a given path it will delete it and prepare it such
-/// that the new service can be safely moved in place.
-// fn prepare_dirs(author: &str, name: &str, version: &str) -> Result<String, Error> {
-fn prepare_dirs(fq: &FqBuf) -> Result<String, Error> {
-    // Construct the full path
-    let full_path_string = fq.dir().clone();
-    let full_path = PathBuf::from(full_path_string.clone());
+/// The path of an archive entry relative to the directory it is extracted to, None if it is
+/// absolute or (partly) outside of that directory.
+fn archive_entry_path(name: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::new();
+    for component in Path::new(name).components() {
+        match component {
+            Component::Normal(c) => path.push(c),
+            Component::CurDir => (),
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(path)
+}
 
-    // Ensure the directory exists
-    std::fs::create_dir_all(full_path.clone())
-        .with_context(|| format!("failed to create dirs {:?}", full_path))?;
+/// Resolves the `..` components of a relative path without touching the file system, None if
+/// the path is absolute or goes above the directory it is relative to.
+fn within_root(path: &Path) -> Option<PathBuf> {
+    let mut resolved = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(c) => resolved.push(c),
+            Component::CurDir => (),
+            Component::ParentDir => {
+                if !resolved.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(resolved)
+}
 
-    // If it already existed and it contained old contents, remove them.
-    std::fs::remove_dir_all(full_path.as_path())
-        .with_context(|| format!("failed to remove path {:?}", full_path))?;
+/// Whether a symlink in dir (relative to the root) points into the root. Its target may only go
+/// up before it names anything: a `..` after a name could go up from wherever another symlink
+/// points to, which the path alone does not tell (and that symlink may come later in the
+/// archive).
+fn symlink_within_root(dir: &Path, link: &str) -> bool {
+    let mut named = false;
+    for component in Path::new(link).components() {
+        match component {
+            Component::Normal(_) => named = true,
+            Component::ParentDir if named => return false,
+            _ => (),
+        }
+    }
+    within_root(&dir.join(link)).is_some()
+}
 
-    Ok(full_path_string)
+/// Whether any existing part of the path within root is a symlink, writing there would follow
+/// a symlink that was extracted earlier.
+fn through_symlink(root: &Path, relative: &Path) -> bool {
+    let mut current = root.to_path_buf();
+    relative.components().any(|component| {
+        current.push(component);
+        fs::symlink_metadata(&current).is_ok_and(|m| m.file_type().is_symlink())
+    })
 }
 
-/// Downloads the vu-ase service from the downloads page and creates a zip file
-/// /tmp/name-version.zip.
-pub async fn download_service(url: &String) -> Result<(), Error> {
+/// Downloads the vu-ase service from the downloads page and writes it to the
+/// archive of the given staging directory. The archive is streamed to disk chunk by chunk,
+/// and checked against the SHA-256 digest if one is given.
+pub async fn download_service(
+    staging: &Staging,
+    url: &String,
+    expected_digest: Option<&str>,
+) -> Result<(), Error> {
     info!("Downloading: {}", url);
     let client = Client::new();
 
@@ -108,7 +367,7 @@ pub async fn download_service(url: &String) -> Result<(), Error> {
         .await;
 
     match res {
-        Ok(res) => {
+        Ok(mut res) => {
             if res.status() != StatusCode::OK {
                 let resp: axum::http::StatusCode = res.status();
 
@@ -132,16 +391,24 @@ pub async fn download_service(url: &String) -> Result<(), Error> {
                     _ => return Err(Error::Http(resp)),
                 }
             }
-            std::fs::remove_file(ZIP_FILE).ok();
-
-            let mut file = std::fs::File::create(ZIP_FILE)
-                .with_context(|| format!("failed to create {}", ZIP_FILE))?;
+            let archive = staging.archive();
+            let mut file = std::fs::File::create(&archive)
+                .with_context(|| format!("failed to create {}", archive))?;
+            let mut hasher = Sha256::new();
 
-            let bytes = res.bytes().await?;
+            while let Some(chunk) = res.chunk().await? {
+                hasher.update(&chunk);
+                file.write_all(&chunk)
+                    .with_context(|| format!("failed to write to {}", archive))?;
+            }
 
-            file.write_all(&bytes)
-                .with_context(|| format!("failed to failed to write to {}", ZIP_FILE))?;
-            Ok(())
+            let digest = format!("{:x}", hasher.finalize());
+            match expected_digest {
+                Some(expected) if expected.trim().to_lowercase() != digest => {
+                    Err(Error::ServiceDownloadDigestMismatch)
+                }
+                _ => Ok(()),
+            }
         }
         Err(err) => {
             if err.is_timeout() {
@@ -155,32 +422,94 @@ pub async fn download_service(url: &String) -> Result<(), Error> {
     }
 }
 
-/// Downloads a service to /tmp and moves it into the correct place on disk.
-/// There shouldn't be any directories or files in the unique path of the service,
-/// however if there are, they will get deleted to make space.
-pub async fn download_and_install_service(url: &String, is_daemon: bool) -> Result<FqBuf, Error> {
-    download_service(url).await?;
-    let mut fq = extract_fq_from_zip().await?;
+/// Downloads the detached signature that is published next to the archive at the given url,
+/// a service without one is not an error here. Unless signatures are required, neither is a
+/// signature that can not be downloaded: the service is then treated as unsigned.
+pub async fn download_signature(
+    staging: &Staging,
+    url: &String,
+    policy: SignaturePolicy,
+) -> Result<(), Error> {
+    let signature_url = format!("{}.{}", url, SIGNATURE_EXTENSION);
+    let download = async {
+        let res = Client::new()
+            .get(&signature_url)
+            .timeout(Duration::from_secs(DOWNLOAD_TIMEOUT))
+            .send()
+            .await?;
+
+        match res.status() {
+            StatusCode::OK => {
+                let signature = staging.signature();
+                let bytes = res.bytes().await?;
+                fs::write(&signature, &bytes)
+                    .with_context(|| format!("failed to write to {}", signature))?;
+                Ok(())
+            }
+            StatusCode::NOT_FOUND => Ok(()),
+            status => Err(Error::Http(status)),
+        }
+    };
+
+    match download.await {
+        Err(e) if policy != SignaturePolicy::Require => {
+            warn!(
+                "could not download {}, continuing without a signature: {:?}",
+                signature_url, e
+            );
+            let _ = fs::remove_file(staging.signature());
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+/// Downloads a service to its own staging directory and moves it into the correct place on
+/// disk. An existing service at the same path is replaced.
+pub async fn download_and_install_service(
+    settings: &Settings,
+    url: &String,
+    digest: Option<&str>,
+    is_daemon: bool,
+) -> Result<FqBuf, Error> {
+    // The daemons come from urls that are built into roverd, which do not have to publish a
+    // signature. One that is published is still checked.
+    let policy = match settings.signature_policy {
+        SignaturePolicy::Require if is_daemon => SignaturePolicy::Verify,
+        policy => policy,
+    };
+
+    let staging = Staging::new(settings)?;
+    download_service(&staging, url, digest).await?;
+    if policy != SignaturePolicy::Ignore {
+        download_signature(&staging, url, policy).await?;
+    }
+    let mut fq = extract_fq_from_archive(settings, &staging, policy).await?;
     fq.is_daemon = is_daemon;
-    install_service(&fq).await?;
+    install_service(settings, &staging, &fq).await?;
     Ok(fq)
 }
 
-/// Attempts to unzip and read out the service in the temporary directory
-/// returns the FqBuf on success.
-pub async fn extract_fq_from_zip() -> Result<FqBuf, Error> {
-    // Clear the destination directory, no matter if it fails
-    let _ = std::fs::remove_dir_all(UNZIPPED_DIR);
+/// Checks the signature of the archive in the staging directory, then attempts to extract
+/// and read out the service. Returns the FqBuf on success.
+pub async fn extract_fq_from_archive(
+    settings: &Settings,
+    staging: &Staging,
+    policy: SignaturePolicy,
+) -> Result<FqBuf, Error> {
+    verify_signature(settings, staging, policy)?;
+
+    let extracted_dir = staging.extracted_dir();
 
     // Create directory, this must not fail
-    std::fs::create_dir_all(UNZIPPED_DIR)
-        .with_context(|| format!("failed to create {}", UNZIPPED_DIR))?;
+    std::fs::create_dir_all(&extracted_dir)
+        .with_context(|| format!("failed to create {}", extracted_dir))?;
 
     // Unpack the downloaded service and validate it.
-    extract_zip(ZIP_FILE, UNZIPPED_DIR)?;
+    extract_archive(&staging.archive(), &extracted_dir)?;
 
     // Read contents and
-    let service_contents = std::fs::read_to_string(format!("{}/service.yaml", UNZIPPED_DIR))
+    let service_contents = std::fs::read_to_string(format!("{}/service.yaml", extracted_dir))
         .map_err(|_| Error::ServiceYamlNotFoundInDownload)?;
     let service = serde_yaml::from_str::<Service>(&service_contents)?.validate()?;
 
@@ -188,59 +517,130 @@ pub async fn extract_fq_from_zip() -> Result<FqBuf, Error> {
     Ok(fq)
 }
 
-/// Expects a zipfile to be ready at ZIP_FILE, extract it and install it. Parses the service.yaml
-/// and install contents into the correct location on disk.
-pub async fn install_service(fq: &FqBuf) -> Result<(), Error> {
-    // Deletes any existing files/dirs that are on the /author/name/version path
-    // Makes sure the directories exist.
-    let full_path = prepare_dirs(fq)?;
+/// Installs the extracted contents of the staging directory as the given service. The new
+/// tree is copied next to the service directory first and then renamed into place, so the
+/// service directory only ever holds the old or the complete new version.
+pub async fn install_service(
+    settings: &Settings,
+    staging: &Staging,
+    fq: &FqBuf,
+) -> Result<(), Error> {
+    let full_path = fq.dir(settings);
+    let _lock = lock_service_dir(&full_path).await;
+
+    // The hidden siblings live on the same filesystem as the service directory (unlike the
+    // staging directory), which is what makes the renames below atomic.
+    let parent = Path::new(&full_path)
+        .parent()
+        .ok_or_else(|| anyhow!("{} has no parent directory", full_path))?;
+    let version = fq.version.as_str();
+    let incoming = parent.join(format!(".{}.incoming-{}", version, staging.id()));
+    let outgoing = parent.join(format!(".{}.outgoing-{}", version, staging.id()));
+
+    std::fs::create_dir_all(parent)
+        .with_context(|| format!("failed to create dirs {:?}", parent))?;
 
     // Copy contents into place
-    copy_recursively(UNZIPPED_DIR, &full_path).with_context(|| {
-        format!(
-            "failed to copy contents from {} to {}",
-            UNZIPPED_DIR, full_path
-        )
-    })?;
+    let extracted_dir = staging.extracted_dir();
+    if let Err(e) = copy_recursively(settings, &extracted_dir, &incoming) {
+        let _ = fs::remove_dir_all(&incoming);
+        return Err(e
+            .context(format!(
+                "failed to copy contents from {} to {:?}",
+                extracted_dir, incoming
+            ))
+            .into());
+    }
+
+    // Move the old version aside, it is only removed once the new one took its place
+    let replaced = Path::new(&full_path).exists();
+    if replaced {
+        fs::rename(&full_path, &outgoing)
+            .with_context(|| format!("failed to move {} to {:?}", full_path, outgoing))?;
+    }
+
+    if let Err(e) = fs::rename(&incoming, &full_path) {
+        if replaced {
+            let _ = fs::rename(&outgoing, &full_path);
+        }
+        let _ = fs::remove_dir_all(&incoming);
+        return Err(Error::Context(anyhow!(
+            "failed to move {:?} to {}: {}",
+            incoming,
+            full_path,
+            e
+        )));
+    }
 
-    chown(&full_path, DEBIX_UID, DEBIX_GID)
-        .with_context(|| format!("failed to set the ownership of {}", &full_path))?;
+    if replaced {
+        if let Err(e) = fs::remove_dir_all(&outgoing) {
+            warn!(
+                "failed to remove the previous contents {:?}: {}",
+                outgoing, e
+            );
+        }
+    }
 
     Ok(())
 }
 
-pub fn list_dir_contents(added_path: &str) -> Result<Vec<String>, Error> {
-    let path_string = format!("{}/{}", ROVER_DIR, added_path);
+pub fn list_dir_contents(settings: &Settings, added_path: &str) -> Result<Vec<String>, Error> {
+    let path_string = format!("{}/{}", settings.rover_dir, added_path);
     let paths = fs::read_dir(&path_string)
         .map_err(|_| Error::ServiceNotFound(format!("Could not find {} on disk", path_string)))?;
     let mut contents: Vec<String> = vec![];
 
     for path in paths {
-        contents.push(
-            path.with_context(|| "failed to unpack direntry".to_string())?
-                .file_name()
-                .to_os_string()
-                .into_string()?,
-        )
+        let name = path
+            .with_context(|| "failed to unpack direntry".to_string())?
+            .file_name()
+            .to_os_string()
+            .into_string()?;
+
+        // Hidden entries are installs that are still in progress
+        if !name.starts_with('.') {
+            contents.push(name);
+        }
     }
 
     Ok(contents)
 }
 
-/// Updates the config and creates the file if it doesn't exist
-pub fn update_config(config: &Configuration) -> Result<(), Error> {
+/// Replaces the file with the given contents such that it is either fully written or left
+/// untouched: the contents go to a temporary file next to it which is synced to disk and
+/// then renamed over the original. The permissions of the original are kept.
+pub fn write_atomically(path: impl AsRef<Path>, contents: &[u8]) -> io::Result<()> {
+    let path = path.as_ref();
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+
+    let mut file = File::create(&tmp)?;
+    if let Ok(metadata) = fs::metadata(path) {
+        file.set_permissions(metadata.permissions())?;
+    }
+    file.write_all(contents)?;
+    file.sync_all()?;
+
+    fs::rename(&tmp, path)?;
+
+    // The rename itself is only durable once the directory is synced as well
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        File::open(parent)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Updates the config and creates the file if it doesn't exist. Only valid configurations
+/// are written, so the backup is updated as well to always hold the last known-good copy.
+pub fn update_config(settings: &Settings, config: &Configuration) -> Result<(), Error> {
     let contents = serde_yaml::to_string(&config)?;
 
-    std::fs::create_dir_all(ROVER_CONFIG_DIR).map_err(|_| Error::ConfigFileIO)?;
+    std::fs::create_dir_all(&settings.config_dir).map_err(|_| Error::ConfigFileIO)?;
 
-    let mut file = OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(ROVER_CONFIG_FILE)
+    write_atomically(settings.config_file(), contents.as_bytes())
         .map_err(|_| Error::ConfigFileIO)?;
-
-    file.write_all(contents.as_bytes())
+    write_atomically(settings.config_backup_file(), contents.as_bytes())
         .map_err(|_| Error::ConfigFileIO)?;
 
     Ok(())
@@ -278,9 +678,9 @@ fn get_latest_version(versions: &[String]) -> Option<String> {
 /// Checks the filesystem for the latest daemon for a given author & service_name
 /// returns an error if it can't find it. If it can't find one, then this fails
 /// the init sequence and the rover is not operational.
-pub fn find_latest_daemon(author: &str, name: &str) -> Result<FqBuf, Error> {
+pub fn find_latest_daemon(settings: &Settings, author: &str, name: &str) -> Result<FqBuf, Error> {
     // List the directory with all versions
-    let daemon_path = PathBuf::from(format!("{}/{}/{}", DAEMON_DIR, author, name));
+    let daemon_path = PathBuf::from(format!("{}/{}/{}", settings.daemon_dir, author, name));
 
     // Collect all the entries of the daemon's directory and check which one is the
     // newest
@@ -378,3 +778,341 @@ macro_rules! time_now {
             .as_millis()
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    enum Entry<'a> {
+        File(&'a str, &'a [u8]),
+        Dir(&'a str),
+        Symlink(&'a str, &'a str),
+    }
+
+    /// Names are written as they are, the tar and zip builders refuse some of the unsafe ones.
+    fn tar(entries: &[Entry]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(vec![]);
+        for entry in entries {
+            let mut header = tar::Header::new_gnu();
+            let (name, data, kind): (&str, &[u8], _) = match entry {
+                Entry::File(name, data) => (name, data, tar::EntryType::Regular),
+                Entry::Dir(name) => (name, b"", tar::EntryType::Directory),
+                Entry::Symlink(name, link) => {
+                    header.set_link_name_literal(link).unwrap();
+                    (name, b"", tar::EntryType::Symlink)
+                }
+            };
+            header.as_old_mut().name[..name.len()].copy_from_slice(name.as_bytes());
+            header.set_entry_type(kind);
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append(&header, data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    fn zip(entries: &[Entry]) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(Cursor::new(vec![]));
+        let options = zip::write::FileOptions::default();
+        for entry in entries {
+            match entry {
+                Entry::File(name, data) => {
+                    zip.start_file(*name, options).unwrap();
+                    zip.write_all(data).unwrap();
+                }
+                Entry::Dir(name) => zip.add_directory(*name, options).unwrap(),
+                Entry::Symlink(name, link) => zip.add_symlink(*name, *link, options).unwrap(),
+            }
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    /// The same entries in every supported format.
+    fn archives(entries: &[Entry]) -> Vec<(&'static str, Vec<u8>)> {
+        let tar = tar(entries);
+        let mut gz = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        gz.write_all(&tar).unwrap();
+
+        vec![
+            ("zip", zip(entries)),
+            ("tar.gz", gz.finish().unwrap()),
+            ("tar.zst", zstd::encode_all(&tar[..], 0).unwrap()),
+        ]
+    }
+
+    /// Extracts the archive into `out` of a fresh directory, which is returned as well.
+    fn extract(test: &str, format: &str, archive: &[u8]) -> (PathBuf, Result<(), Error>) {
+        let dir =
+            std::env::temp_dir().join(format!("roverd-{}-{}-{}", test, format, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let archive_file = dir.join("archive");
+        fs::write(&archive_file, archive).unwrap();
+        let result = extract_archive(
+            archive_file.to_str().unwrap(),
+            dir.join("out").to_str().unwrap(),
+        );
+
+        (dir, result)
+    }
+
+    fn assert_rejected(test: &str, entries: &[Entry], expected: &str, reason: ArchiveRejection) {
+        for (format, archive) in archives(entries) {
+            let (dir, result) = extract(test, format, &archive);
+            match result {
+                Err(Error::ArchiveEntryRejected(name, r))
+                    if name == expected
+                        && std::mem::discriminant(&r) == std::mem::discriminant(&reason) => {}
+                other => panic!("{} ({}): unexpected result {:?}", test, format, other),
+            }
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_extract_archive() {
+        let entries = [
+            Entry::Dir("service/"),
+            Entry::File("service/service.yaml", b"name: imaging\n"),
+            Entry::Symlink("service/link", "service.yaml"),
+            Entry::File("./bin/run", b"#!/bin/sh\n"),
+        ];
+
+        for (format, archive) in archives(&entries) {
+            let (dir, result) = extract("valid", format, &archive);
+            result.unwrap();
+
+            let out = dir.join("out");
+            let contents = fs::read_to_string(out.join("service/link")).unwrap();
+            assert_eq!(contents, "name: imaging\n", "{}", format);
+            assert!(out.join("bin/run").is_file(), "{}", format);
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        let (dir, result) = extract("unknown", "txt", b"not an archive");
+        assert!(matches!(result, Err(Error::ArchiveFormatUnknown)));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_reject_parent_dir() {
+        let entries = [
+            Entry::File("service.yaml", b""),
+            Entry::File("../escaped", b"evil"),
+        ];
+        assert_rejected(
+            "parent",
+            &entries,
+            "../escaped",
+            ArchiveRejection::UnsafePath,
+        );
+
+        let entries = [Entry::File("service/../../escaped", b"evil")];
+        assert_rejected(
+            "nested-parent",
+            &entries,
+            "service/../../escaped",
+            ArchiveRejection::UnsafePath,
+        );
+    }
+
+    #[test]
+    fn test_reject_absolute_path() {
+        let entries = [Entry::File("/tmp/roverd-escaped", b"evil")];
+        assert_rejected(
+            "absolute",
+            &entries,
+            "/tmp/roverd-escaped",
+            ArchiveRejection::UnsafePath,
+        );
+        assert!(!Path::new("/tmp/roverd-escaped").exists());
+    }
+
+    #[test]
+    fn test_reject_symlink_escape() {
+        let entries = [Entry::Symlink("service/etc", "../../etc")];
+        assert_rejected(
+            "symlink-escape",
+            &entries,
+            "service/etc",
+            ArchiveRejection::UnsafeSymlink,
+        );
+
+        let entries = [Entry::Symlink("etc", "/etc")];
+        assert_rejected(
+            "symlink-absolute",
+            &entries,
+            "etc",
+            ArchiveRejection::UnsafeSymlink,
+        );
+
+        // Each link points into the archive on its own, but the kernel resolves "out" through
+        // "up" to the parent of the archive. This works in either order.
+        let entries = [
+            Entry::Dir("a/b/"),
+            Entry::Symlink("a/b/up", "../.."),
+            Entry::Symlink("a/b/out", "up/.."),
+        ];
+        assert_rejected(
+            "symlink-chain",
+            &entries,
+            "a/b/out",
+            ArchiveRejection::UnsafeSymlink,
+        );
+        let entries = [
+            Entry::Dir("a/b/"),
+            Entry::Symlink("a/b/out", "up/.."),
+            Entry::Symlink("a/b/up", "../.."),
+        ];
+        assert_rejected(
+            "symlink-chain-reversed",
+            &entries,
+            "a/b/out",
+            ArchiveRejection::UnsafeSymlink,
+        );
+    }
+
+    #[test]
+    fn test_symlinks_within_archive() {
+        // Going up first and then down again is fine, also through another symlink
+        let entries = [
+            Entry::File("service/service.yaml", b"name: imaging\n"),
+            Entry::Symlink("a/b/up", "../.."),
+            Entry::Symlink("a/b/yaml", "up/service/service.yaml"),
+            Entry::Symlink("a/yaml", "./../service/service.yaml"),
+        ];
+
+        for (format, archive) in archives(&entries) {
+            let (dir, result) = extract("symlink-within", format, &archive);
+            result.unwrap();
+
+            let out = dir.join("out");
+            for link in ["a/b/yaml", "a/yaml"] {
+                let contents = fs::read_to_string(out.join(link)).unwrap();
+                assert_eq!(contents, "name: imaging\n", "{} ({})", link, format);
+            }
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_reject_write_through_symlink() {
+        // The symlink itself stays within the archive, but the entries after it may not use
+        // it (it could be replaced by one that points elsewhere in between)
+        let entries = [
+            Entry::Dir("service/"),
+            Entry::Symlink("link", "service"),
+            Entry::File("link/service.yaml", b"evil"),
+        ];
+        assert_rejected(
+            "through-symlink",
+            &entries,
+            "link/service.yaml",
+            ArchiveRejection::UnsafeSymlink,
+        );
+
+        // Replacing an extracted symlink is not allowed either
+        let entries = [
+            Entry::Symlink("link", "service.yaml"),
+            Entry::File("link", b"evil"),
+        ];
+        assert_rejected(
+            "replace-symlink",
+            &entries,
+            "link",
+            ArchiveRejection::UnsafeSymlink,
+        );
+    }
+
+    #[test]
+    fn test_reject_special_file() {
+        // Zip archives can not hold fifos, tar archives can
+        let mut header = tar::Header::new_gnu();
+        header.set_path("fifo").unwrap();
+        header.set_entry_type(tar::EntryType::Fifo);
+        header.set_size(0);
+        header.set_mode(0o644);
+        header.set_cksum();
+        let mut builder = tar::Builder::new(vec![]);
+        builder.append(&header, &b""[..]).unwrap();
+        let archive = zstd::encode_all(&builder.into_inner().unwrap()[..], 0).unwrap();
+
+        let (dir, result) = extract("special", "tar.zst", &archive);
+        match result {
+            Err(Error::ArchiveEntryRejected(name, ArchiveRejection::SpecialFile)) => {
+                assert_eq!(name, "fifo")
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// Runs the extractor of the format with a writer that is close to its limits, so the
+    /// limits can be tested without huge archives.
+    fn extract_near_limits(
+        format: &str,
+        archive: Vec<u8>,
+        target: &Path,
+        entries: usize,
+        remaining_size: u64,
+    ) -> Result<(), Error> {
+        let mut writer = ArchiveWriter {
+            target: target.to_path_buf(),
+            entries,
+            remaining_size,
+        };
+
+        match format {
+            "zip" => extract_zip(Cursor::new(archive), &mut writer),
+            "tar.gz" => extract_tar(flate2::read::GzDecoder::new(&archive[..]), &mut writer),
+            _ => extract_tar(zstd::Decoder::new(&archive[..]).unwrap(), &mut writer),
+        }
+    }
+
+    #[test]
+    fn test_reject_too_many_entries() {
+        let entries = [Entry::File("a", b""), Entry::File("b", b"")];
+        for (format, archive) in archives(&entries) {
+            let target = std::env::temp_dir().join(format!(
+                "roverd-entries-{}-{}",
+                format,
+                std::process::id()
+            ));
+
+            let result =
+                extract_near_limits(format, archive, &target, MAX_ARCHIVE_ENTRIES - 1, 1024);
+            match result {
+                Err(Error::ArchiveEntryRejected(name, ArchiveRejection::TooManyEntries)) => {
+                    assert_eq!(name, "b", "{}", format)
+                }
+                other => panic!("{}: unexpected result {:?}", format, other),
+            }
+            assert!(target.join("a").exists(), "{}", format);
+            fs::remove_dir_all(target).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_reject_too_large() {
+        let entries = [Entry::File("a", &[0; 600]), Entry::File("b", &[0; 600])];
+        for (format, archive) in archives(&entries) {
+            let target =
+                std::env::temp_dir().join(format!("roverd-size-{}-{}", format, std::process::id()));
+
+            let result = extract_near_limits(format, archive, &target, 0, 1024);
+            match result {
+                Err(Error::ArchiveEntryRejected(name, ArchiveRejection::TooLarge)) => {
+                    assert_eq!(name, "b", "{}", format)
+                }
+                other => panic!("{}: unexpected result {:?}", format, other),
+            }
+            fs::remove_dir_all(target).unwrap();
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+use anyhow::Context;
+use minisign_verify::{PublicKey, Signature};
+use tracing::info;
+
+use crate::error::Error;
+use crate::settings::{Settings, SignaturePolicy};
+
+use super::staging::Staging;
+
+/// Checks the detached signature of the staged archive (if there is one) against the trusted
+/// keys, as far as the signature policy asks for it.
+pub fn verify_signature(
+    settings: &Settings,
+    staging: &Staging,
+    policy: SignaturePolicy,
+) -> Result<(), Error> {
+    if policy == SignaturePolicy::Ignore {
+        return Ok(());
+    }
+
+    let signature_file = staging.signature();
+    if !Path::new(&signature_file).exists() {
+        return match policy {
+            SignaturePolicy::Require => Err(Error::SignatureMissing),
+            _ => Ok(()),
+        };
+    }
+
+    let signature = Signature::from_file(&signature_file)
+        .map_err(|e| Error::SignatureInvalid(e.to_string()))?;
+
+    // The key id in the signature tells which of the trusted keys has to be used
+    let keys = trusted_keys(settings)?;
+    let mut verifier = keys
+        .iter()
+        .find_map(|key| match key.verify_stream(&signature) {
+            Err(minisign_verify::Error::UnexpectedKeyId) => None,
+            result => Some(result),
+        })
+        .ok_or(Error::SignatureUntrusted)?
+        .map_err(|e| Error::SignatureInvalid(e.to_string()))?;
+
+    let archive = staging.archive();
+    let mut file =
+        fs::File::open(&archive).with_context(|| format!("failed to open {}", archive))?;
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .with_context(|| format!("failed to read {}", archive))?;
+        if n == 0 {
+            break;
+        }
+        verifier.update(&buf[..n]);
+    }
+
+    verifier
+        .finalize()
+        .map_err(|e| Error::SignatureInvalid(e.to_string()))?;
+
+    info!("signature verified: {}", signature.trusted_comment());
+    Ok(())
+}
+
+/// All minisign public keys (`*.pub`) in the trusted keys directory, which might not exist.
+fn trusted_keys(settings: &Settings) -> Result<Vec<PublicKey>, Error> {
+    let dir = settings.trusted_keys_dir();
+    if !Path::new(&dir).exists() {
+        return Ok(vec![]);
+    }
+
+    let mut keys = vec![];
+    for entry in fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir))? {
+        let path = entry
+            .with_context(|| format!("failed to read {}", dir))?
+            .path();
+        if path.extension().is_some_and(|e| e == "pub") {
+            let key = PublicKey::from_file(&path)
+                .map_err(|_| Error::TrustedKeyInvalid(path.to_string_lossy().to_string()))?;
+            keys.push(key);
+        }
+    }
+
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The key and signature of the file "test" from the minisign-verify test suite
+    const PUBLIC_KEY: &str = "untrusted comment: minisign public key\n\
+                              RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3\n";
+    const SIGNATURE: &str = "untrusted comment: signature from minisign secret key\n\
+                             RUQf6LRCGA9i559r3g7V1qNyJDApGip8MfqcadIgT9CuhV3EMhHoN1mGTkUidF/\
+                             z7SrlQgXdy8ofjb7bNJJylDOocrCo8KLzZwo=\n\
+                             trusted comment: timestamp:1556193335\tfile:test\n\
+                             y/rUw2y8/hOUYjZU71eHp/Wo1KZ40fGy2VJEDl34XMJM+TX48Ss/17u3IvIfbVR1FkZZSNCisQbuQY+bHwhEBg==\n";
+
+    /// Stages the archive (and signature) in a fresh directory, with the test key trusted
+    /// if asked for.
+    fn staged(
+        test: &str,
+        archive: &str,
+        signature: Option<&str>,
+        trusted: bool,
+    ) -> (Settings, Staging) {
+        let dir =
+            std::env::temp_dir().join(format!("roverd-signature-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let dir = dir.to_string_lossy().to_string();
+        let settings = Settings {
+            config_dir: dir.clone(),
+            staging_dir: format!("{}/staging", dir),
+            ..Settings::default()
+        };
+
+        fs::create_dir_all(settings.trusted_keys_dir()).unwrap();
+        if trusted {
+            fs::write(
+                format!("{}/test.pub", settings.trusted_keys_dir()),
+                PUBLIC_KEY,
+            )
+            .unwrap();
+        }
+
+        let staging = Staging::new(&settings).unwrap();
+        fs::write(staging.archive(), archive).unwrap();
+        if let Some(signature) = signature {
+            fs::write(staging.signature(), signature).unwrap();
+        }
+        (settings, staging)
+    }
+
+    fn cleanup(settings: Settings, staging: Staging) {
+        drop(staging);
+        fs::remove_dir_all(settings.config_dir).unwrap();
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let (settings, staging) = staged("unsigned", "test", None, true);
+        assert!(verify_signature(&settings, &staging, SignaturePolicy::Ignore).is_ok());
+        assert!(verify_signature(&settings, &staging, SignaturePolicy::Verify).is_ok());
+        assert!(matches!(
+            verify_signature(&settings, &staging, SignaturePolicy::Require),
+            Err(Error::SignatureMissing)
+        ));
+        cleanup(settings, staging);
+    }
+
+    #[test]
+    fn test_valid_signature() {
+        let (settings, staging) = staged("valid", "test", Some(SIGNATURE), true);
+        for policy in [
+            SignaturePolicy::Ignore,
+            SignaturePolicy::Verify,
+            SignaturePolicy::Require,
+        ] {
+            let result = verify_signature(&settings, &staging, policy);
+            assert!(result.is_ok(), "{:?}: {:?}", policy, result);
+        }
+        cleanup(settings, staging);
+    }
+
+    #[test]
+    fn test_bad_signature() {
+        // The signature does not match the contents of the archive
+        let (settings, staging) = staged("tampered", "tost", Some(SIGNATURE), true);
+        assert!(verify_signature(&settings, &staging, SignaturePolicy::Ignore).is_ok());
+        for policy in [SignaturePolicy::Verify, SignaturePolicy::Require] {
+            let result = verify_signature(&settings, &staging, policy);
+            assert!(
+                matches!(result, Err(Error::SignatureInvalid(_))),
+                "{:?}: {:?}",
+                policy,
+                result
+            );
+        }
+        cleanup(settings, staging);
+
+        let (settings, staging) = staged("malformed", "test", Some("not a signature"), true);
+        assert!(matches!(
+            verify_signature(&settings, &staging, SignaturePolicy::Verify),
+            Err(Error::SignatureInvalid(_))
+        ));
+        cleanup(settings, staging);
+    }
+
+    #[test]
+    fn test_untrusted_key() {
+        let (settings, staging) = staged("untrusted", "test", Some(SIGNATURE), false);
+        for policy in [SignaturePolicy::Verify, SignaturePolicy::Require] {
+            let result = verify_signature(&settings, &staging, policy);
+            assert!(
+                matches!(result, Err(Error::SignatureUntrusted)),
+                "{:?}: {:?}",
+                policy,
+                result
+            );
+        }
+        cleanup(settings, staging);
+    }
+}
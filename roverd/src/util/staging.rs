@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use once_cell::sync::Lazy;
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+use tracing::warn;
+
+use crate::constants::*;
+use crate::error::Error;
+use crate::settings::Settings;
+use crate::time_now;
+
+static NEXT_STAGING_ID: AtomicU64 = AtomicU64::new(0);
+
+/// One lock per service directory, so installs (and deletes) of the same service are
+/// serialized while different services can still be installed at the same time. A lock only
+/// stays in the map while it is held or waited for.
+static SERVICE_DIR_LOCKS: Lazy<Mutex<HashMap<String, Arc<AsyncMutex<()>>>>> =
+    Lazy::new(Default::default);
+
+/// Private scratch directory of a single install, which holds the incoming service archive and
+/// its extracted contents. The directory is removed again once the install is dropped.
+#[derive(Debug)]
+pub struct Staging {
+    id: String,
+    dir: String,
+}
+
+impl Staging {
+    pub fn new(settings: &Settings) -> Result<Staging, Error> {
+        let id = format!(
+            "{}-{}",
+            time_now!(),
+            NEXT_STAGING_ID.fetch_add(1, Ordering::Relaxed)
+        );
+        let dir = format!("{}/{}", settings.staging_dir, id);
+
+        fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir))?;
+
+        Ok(Staging { id, dir })
+    }
+
+    /// Unique identifier of this install, also used to name its temporary directories
+    /// next to the service directory.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn archive(&self) -> String {
+        format!("{}/service.archive", self.dir)
+    }
+
+    /// Detached minisign signature of the archive, only present if one was sent along.
+    pub fn signature(&self) -> String {
+        format!("{}.{}", self.archive(), SIGNATURE_EXTENSION)
+    }
+
+    pub fn extracted_dir(&self) -> String {
+        format!("{}/extracted", self.dir)
+    }
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.dir) {
+            warn!("failed to clean up staging directory {}: {}", self.dir, e);
+        }
+    }
+}
+
+/// Lock on a service directory, released when it is dropped.
+pub struct ServiceDirLock {
+    dir: String,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl Drop for ServiceDirLock {
+    fn drop(&mut self) {
+        let mut locks = SERVICE_DIR_LOCKS.lock().unwrap();
+        drop(self.guard.take());
+
+        // Nobody else is waiting for the directory if the map holds the only reference, so the
+        // entry can go. New waiters need the map first, so they can not show up in between.
+        if locks
+            .get(&self.dir)
+            .is_some_and(|lock| Arc::strong_count(lock) == 1)
+        {
+            locks.remove(&self.dir);
+        }
+    }
+}
+
+/// Waits until no one else is modifying the given service directory. The directory stays
+/// locked until the returned guard is dropped.
+pub async fn lock_service_dir(dir: &str) -> ServiceDirLock {
+    let lock = SERVICE_DIR_LOCKS
+        .lock()
+        .unwrap()
+        .entry(dir.to_string())
+        .or_default()
+        .clone();
+
+    ServiceDirLock {
+        dir: dir.to_string(),
+        guard: Some(lock.lock_owned().await),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn is_tracked(dir: &str) -> bool {
+        SERVICE_DIR_LOCKS.lock().unwrap().contains_key(dir)
+    }
+
+    #[tokio::test]
+    async fn test_service_dir_locks_are_removed() {
+        let dir = format!("/roverd-test-locks-{}", std::process::id());
+
+        let lock = lock_service_dir(&dir).await;
+        assert!(is_tracked(&dir));
+        drop(lock);
+        assert!(!is_tracked(&dir));
+
+        // The entry is kept while someone is still waiting for it
+        let lock = lock_service_dir(&dir).await;
+        let waiter = tokio::spawn({
+            let dir = dir.clone();
+            async move {
+                let _lock = lock_service_dir(&dir).await;
+                assert!(is_tracked(&dir));
+            }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(lock);
+        assert!(is_tracked(&dir));
+
+        waiter.await.unwrap();
+        assert!(!is_tracked(&dir));
+    }
+}
@@ -19,4 +19,27 @@ pub struct Configuration {
     /// List of paths to service folders that are enabled. Each folder should contain a
     /// service.yaml file.
     pub enabled: Vec<String>,
+
+    /// Configuration values of enabled services that replace the values from their
+    /// service.yaml when the pipeline is started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<Vec<Override>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Override {
+    /// Path of the enabled service (as listed in enabled) these values apply to.
+    pub service: String,
+
+    /// The configuration options to override, which must be declared in the service.yaml.
+    pub configuration: Vec<ConfigurationOverride>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConfigurationOverride {
+    /// The name of the configuration option.
+    pub name: String,
+
+    /// The new value, of the same type as the value declared in the service.yaml.
+    pub value: crate::service::Value,
 }
@@ -54,6 +54,54 @@ impl Validate<ValidatedConfiguration> for gen::Configuration {
             }
         }
 
+        // Overrides can only apply to enabled services, and only once per service and option
+        let mut overridden_services = Vec::new();
+        for (index, o) in self.overrides.iter().flatten().enumerate() {
+            if !self.enabled.contains(&o.service) {
+                errors.push(Error::FieldValidationError(crate::error::FieldError {
+                    path: vec![
+                        "overrides".to_string(),
+                        index.to_string(),
+                        "service".to_string(),
+                    ],
+                    message: format!("{} is not an enabled service", o.service),
+                }));
+            }
+
+            if overridden_services.contains(&&o.service) {
+                errors.push(Error::FieldValidationError(crate::error::FieldError {
+                    path: vec![
+                        "overrides".to_string(),
+                        index.to_string(),
+                        "service".to_string(),
+                    ],
+                    message: format!(
+                        "overrides must be unique, but {} exists more than once",
+                        o.service
+                    ),
+                }));
+            } else {
+                overridden_services.push(&o.service);
+            }
+
+            let mut names = Vec::new();
+            for (option_index, option) in o.configuration.iter().enumerate() {
+                if names.contains(&&option.name) {
+                    errors.push(Error::FieldValidationError(crate::error::FieldError {
+                        path: vec![
+                            "overrides".to_string(),
+                            index.to_string(),
+                            "configuration".to_string(),
+                            option_index.to_string(),
+                        ],
+                        message: format!("{} is overridden more than once", option.name),
+                    }));
+                } else {
+                    names.push(&option.name);
+                }
+            }
+        }
+
         if errors.is_empty() {
             Ok(ValidatedConfiguration(self.clone()))
         } else {
@@ -29,9 +29,37 @@ pub struct FieldError {
 pub enum PipelineValidationError {
     UnmetDependencyError(UnmetDependencyError),
     DuplicateServiceError(String),
+    SelfLoopError(String), // the name of the service that consumes its own output
+    // Warnings do not make a pipeline invalid, they are only reported alongside errors
+    CycleWarning(CycleWarning),
+    UnusedOutputWarning(UnusedOutputWarning),
+    // The name of the service that is not connected to any other
+    UnreachableServiceWarning(String),
     // ... more errors can be added as we want to define more fine-grained categories
 }
 
+impl PipelineValidationError {
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            PipelineValidationError::CycleWarning(_)
+                | PipelineValidationError::UnusedOutputWarning(_)
+                | PipelineValidationError::UnreachableServiceWarning(_)
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CycleWarning {
+    pub services: Vec<String>, // the names of the services that (indirectly) depend on each other
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct UnusedOutputWarning {
+    pub service: String, // the name of the service that produces the stream
+    pub stream: String,  // the name of the stream that no service consumes
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub enum UnmetDependencyError {
     UnmetStream(UnmetStreamError),
@@ -106,6 +134,18 @@ impl fmt::Display for PipelineValidationError {
             PipelineValidationError::DuplicateServiceError(name) => {
                 write!(f, "Service '{}' is defined more than once in this pipeline. Remove duplicate services.", name)
             }
+            PipelineValidationError::SelfLoopError(name) => {
+                write!(f, "Service '{}' takes its own output as input. Remove this input from the service.yaml configuration.", name)
+            }
+            PipelineValidationError::CycleWarning(e) => {
+                write!(f, "Cyclic dependency warning: {}", e)
+            }
+            PipelineValidationError::UnusedOutputWarning(e) => {
+                write!(f, "Unused output warning: {}", e)
+            }
+            PipelineValidationError::UnreachableServiceWarning(name) => {
+                write!(f, "Unreachable service warning: service '{}' does not exchange any streams with the other services in this pipeline.", name)
+            }
         }
     }
 }
@@ -127,6 +167,24 @@ impl fmt::Display for UnmetServiceError {
         )
     }
 }
+impl fmt::Display for CycleWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "services '{}' depend on each other, so they are started in their configured order and some of them will not find their inputs right away.",
+            self.services.join("', '")
+        )
+    }
+}
+impl fmt::Display for UnusedOutputWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "stream '{}' of service '{}' is not used by any service in this pipeline.",
+            self.stream, self.service
+        )
+    }
+}
 impl fmt::Display for UnmetDependencyError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -141,3 +199,35 @@ impl std::error::Error for Error {}
 
 // Multiple results can be returned
 pub type Result<T> = std::result::Result<T, Vec<Error>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_warning() {
+        let errors = [
+            PipelineValidationError::UnmetDependencyError(UnmetDependencyError::UnmetService(
+                UnmetServiceError {
+                    source: "a".to_string(),
+                    target: "b".to_string(),
+                },
+            )),
+            PipelineValidationError::DuplicateServiceError("a".to_string()),
+            PipelineValidationError::SelfLoopError("a".to_string()),
+        ];
+        assert!(errors.iter().all(|e| !e.is_warning()));
+
+        let warnings = [
+            PipelineValidationError::CycleWarning(CycleWarning {
+                services: vec!["a".to_string(), "b".to_string()],
+            }),
+            PipelineValidationError::UnusedOutputWarning(UnusedOutputWarning {
+                service: "a".to_string(),
+                stream: "a".to_string(),
+            }),
+            PipelineValidationError::UnreachableServiceWarning("a".to_string()),
+        ];
+        assert!(warnings.iter().all(|e| e.is_warning()));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::error::{Error, Result};
+use crate::error::{
+    CycleWarning, Error, PipelineValidationError, Result, UnmetDependencyError, UnmetServiceError,
+    UnmetStreamError, UnusedOutputWarning,
+};
 use crate::{config::Validate, service};
 
 /**
@@ -18,6 +21,132 @@ impl Pipeline {
     pub fn new(services: Vec<service::ValidatedService>) -> Self {
         Self { services }
     }
+
+    /// Returns the indices of the (other) services that the service at `index` takes inputs from.
+    fn dependencies(&self, index: usize) -> Vec<usize> {
+        let service = &self.services[index];
+        self.services
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| {
+                *i != index
+                    && s.0.name != service.0.name
+                    && service
+                        .0
+                        .inputs
+                        .iter()
+                        .any(|input| input.service == s.0.name)
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Returns for every service which services it (indirectly) depends on, as a matrix
+    /// where `reachable[a][b]` is set if service a needs service b to be running.
+    fn reachability(&self) -> Vec<Vec<bool>> {
+        let dependencies: Vec<Vec<usize>> = (0..self.services.len())
+            .map(|i| self.dependencies(i))
+            .collect();
+
+        let mut reachable = vec![vec![false; self.services.len()]; self.services.len()];
+        for (start, row) in reachable.iter_mut().enumerate() {
+            let mut stack = dependencies[start].clone();
+            while let Some(index) = stack.pop() {
+                if !row[index] {
+                    row[index] = true;
+                    stack.extend(dependencies[index].iter().copied());
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Returns all groups of services that (indirectly) depend on each other. Each cycle is
+    /// reported once, with its services in the configured order.
+    fn cycles(&self) -> Vec<Vec<String>> {
+        let reachable = self.reachability();
+        let mut seen = vec![false; self.services.len()];
+        let mut cycles = Vec::new();
+
+        for start in 0..self.services.len() {
+            if seen[start] || !reachable[start][start] {
+                continue;
+            }
+
+            let members: Vec<usize> = (0..self.services.len())
+                .filter(|&i| i == start || (reachable[start][i] && reachable[i][start]))
+                .collect();
+            for &i in members.iter() {
+                seen[i] = true;
+            }
+            cycles.push(
+                members
+                    .iter()
+                    .map(|&i| self.services[i].0.name.clone())
+                    .collect(),
+            );
+        }
+
+        cycles
+    }
+
+    /// Returns the issues in this pipeline that do not prevent it from running, but likely are
+    /// a mistake: services that depend on each other (so not all of them can be started after
+    /// their inputs), outputs that no service consumes and services that are not connected to
+    /// any other service.
+    pub fn warnings(&self) -> Vec<Error> {
+        let mut warnings = Vec::new();
+
+        for services in self.cycles() {
+            warnings.push(Error::PipelineValidationError(
+                PipelineValidationError::CycleWarning(CycleWarning { services }),
+            ));
+        }
+
+        for service in self.services.iter() {
+            let consumers: Vec<&service::ValidatedService> = self
+                .services
+                .iter()
+                .filter(|s| {
+                    s.0.name != service.0.name
+                        && s.0
+                            .inputs
+                            .iter()
+                            .any(|input| input.service == service.0.name)
+                })
+                .collect();
+
+            for output in service.0.outputs.iter() {
+                if !consumers.iter().any(|s| {
+                    s.0.inputs.iter().any(|input| {
+                        input.service == service.0.name && input.streams.contains(output)
+                    })
+                }) {
+                    warnings.push(Error::PipelineValidationError(
+                        PipelineValidationError::UnusedOutputWarning(UnusedOutputWarning {
+                            service: service.0.name.clone(),
+                            stream: output.clone(),
+                        }),
+                    ));
+                }
+            }
+
+            // A single service on its own is a perfectly fine pipeline
+            let has_inputs = service
+                .0
+                .inputs
+                .iter()
+                .any(|input| input.service != service.0.name);
+            if self.services.len() > 1 && !has_inputs && consumers.is_empty() {
+                warnings.push(Error::PipelineValidationError(
+                    PipelineValidationError::UnreachableServiceWarning(service.0.name.clone()),
+                ));
+            }
+        }
+
+        warnings
+    }
 }
 
 impl Validate<RunnablePipeline> for Pipeline {
@@ -41,21 +170,36 @@ impl Validate<RunnablePipeline> for Pipeline {
         // Are all used inputs produced as output by another service?
         for service in self.services.iter() {
             for input in service.0.inputs.iter() {
+                // Consuming your own output can never be satisfied, report it once per service
+                if input.service == service.0.name {
+                    errors.push(Error::PipelineValidationError(
+                        PipelineValidationError::SelfLoopError(service.0.name.clone()),
+                    ));
+                    continue;
+                }
+
+                // If the service is not enabled at all, report that once instead of every stream
+                let Some(target) = self.services.iter().find(|s| s.0.name == input.service) else {
+                    errors.push(Error::PipelineValidationError(
+                        PipelineValidationError::UnmetDependencyError(
+                            UnmetDependencyError::UnmetService(UnmetServiceError {
+                                source: service.0.name.clone(),
+                                target: input.service.clone(),
+                            }),
+                        ),
+                    ));
+                    continue;
+                };
+
                 for stream in input.streams.iter() {
-                    if !self.services.iter().any(|s| {
-                        s.0.name == input.service
-                            && s.0.name != service.0.name
-                            && s.0.outputs.iter().any(|o| o == stream)
-                    }) {
+                    if !target.0.outputs.iter().any(|o| o == stream) {
                         errors.push(Error::PipelineValidationError(
-                            crate::error::PipelineValidationError::UnmetDependencyError(
-                                crate::error::UnmetDependencyError::UnmetStream(
-                                    crate::error::UnmetStreamError {
-                                        source: service.0.name.clone(),
-                                        target: input.service.clone(),
-                                        stream: stream.clone(),
-                                    },
-                                ),
+                            PipelineValidationError::UnmetDependencyError(
+                                UnmetDependencyError::UnmetStream(UnmetStreamError {
+                                    source: service.0.name.clone(),
+                                    target: input.service.clone(),
+                                    stream: stream.clone(),
+                                }),
                             ),
                         ));
                     }
@@ -66,6 +210,9 @@ impl Validate<RunnablePipeline> for Pipeline {
         if errors.is_empty() {
             Ok(RunnablePipeline(self.clone()))
         } else {
+            // Warnings are only relevant to report alongside errors, a valid pipeline exposes
+            // them through warnings()
+            errors.extend(self.warnings());
             Err(errors)
         }
     }
@@ -81,6 +228,32 @@ impl RunnablePipeline {
     pub fn services(&self) -> &Vec<service::ValidatedService> {
         &self.0.services
     }
+
+    pub fn warnings(&self) -> Vec<Error> {
+        self.0.warnings()
+    }
+
+    /// Returns the services in the order they should be started in: every service comes after
+    /// the services it takes inputs from. Apart from that the configured order is kept, and
+    /// services that are part of a cycle are placed at the end in their configured order.
+    pub fn start_order(&self) -> Vec<&service::ValidatedService> {
+        let mut remaining: Vec<&service::ValidatedService> = self.0.services.iter().collect();
+        let mut ordered: Vec<&service::ValidatedService> = Vec::new();
+
+        // Repeatedly pick the first service of which all inputs have been started already
+        while let Some(index) = remaining.iter().position(|service| {
+            service
+                .0
+                .inputs
+                .iter()
+                .all(|input| ordered.iter().any(|o| o.0.name == input.service))
+        }) {
+            ordered.push(remaining.remove(index));
+        }
+
+        ordered.extend(remaining);
+        ordered
+    }
 }
 
 #[cfg(test)]
@@ -103,6 +276,8 @@ mod tests {
             inputs: vec![],
             outputs: vec!["a".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let b = Service {
             name: "b".to_string(),
@@ -119,6 +294,8 @@ mod tests {
             }],
             outputs: vec!["b".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let c = Service {
             name: "c".to_string(),
@@ -135,6 +312,8 @@ mod tests {
             }],
             outputs: vec!["c".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
 
         // Validate all services
@@ -170,6 +349,8 @@ mod tests {
             inputs: vec![],
             outputs: vec!["a".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let b = Service {
             name: "b".to_string(),
@@ -186,6 +367,8 @@ mod tests {
             }],
             outputs: vec!["b".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let c = Service {
             name: "c".to_string(),
@@ -202,6 +385,8 @@ mod tests {
             }],
             outputs: vec!["c".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
 
         // Validate all services
@@ -220,7 +405,19 @@ mod tests {
             }
         }
 
-        assert!(validated_pipeline.is_err());
+        // Service b exists, it just does not output the stream that c needs
+        let errors: Vec<Error> = validated_pipeline
+            .unwrap_err()
+            .into_iter()
+            .filter(|e| !matches!(e, Error::PipelineValidationError(e) if e.is_warning()))
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            Error::PipelineValidationError(PipelineValidationError::UnmetDependencyError(
+                UnmetDependencyError::UnmetStream(e)
+            )) if e.source == "c" && e.target == "b" && e.stream == "c"
+        ));
     }
 
     #[test]
@@ -247,6 +444,8 @@ mod tests {
             ],
             outputs: vec!["a".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let b = Service {
             name: "b".to_string(),
@@ -263,6 +462,8 @@ mod tests {
             }],
             outputs: vec!["b".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let c = Service {
             name: "c".to_string(),
@@ -279,6 +480,8 @@ mod tests {
             }],
             outputs: vec!["c".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
 
         // Validate all services
@@ -297,6 +500,146 @@ mod tests {
         assert_eq!(validated_pipeline.services()[0].0.name, "a");
         assert_eq!(validated_pipeline.services()[1].0.name, "b");
         assert_eq!(validated_pipeline.services()[2].0.name, "c");
+
+        // All three services are part of the same cycle, which is reported once as a warning
+        let warnings = validated_pipeline.warnings();
+        let cycles: Vec<&CycleWarning> = warnings
+            .iter()
+            .filter_map(|e| match e {
+                Error::PipelineValidationError(PipelineValidationError::CycleWarning(c)) => Some(c),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].services, vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_start_order() {
+        // The A -> B -> C dependency chain, but configured in reverse
+        let c = Service {
+            name: "c".to_string(),
+            author: "ase-test".to_string(),
+            version: "0.1.0".to_string(),
+            source: "github.com/ase-test/c".to_string(),
+            commands: service::Commands {
+                build: None,
+                run: "echo 'c'".to_string(),
+            },
+            inputs: vec![service::Input {
+                service: "b".to_string(),
+                streams: vec!["b".to_string()],
+            }],
+            outputs: vec!["c".to_string()],
+            configuration: vec![],
+            restart: None,
+            stop_timeout: None,
+        };
+        let b = Service {
+            name: "b".to_string(),
+            author: "ase-test".to_string(),
+            version: "0.1.0".to_string(),
+            source: "github.com/ase-test/b".to_string(),
+            commands: service::Commands {
+                build: None,
+                run: "echo 'b'".to_string(),
+            },
+            inputs: vec![service::Input {
+                service: "a".to_string(),
+                streams: vec!["a".to_string()],
+            }],
+            outputs: vec!["b".to_string()],
+            configuration: vec![],
+            restart: None,
+            stop_timeout: None,
+        };
+        let a = Service {
+            name: "a".to_string(),
+            author: "ase-test".to_string(),
+            version: "0.1.0".to_string(),
+            source: "github.com/ase-test/a".to_string(),
+            commands: service::Commands {
+                build: None,
+                run: "echo 'a'".to_string(),
+            },
+            inputs: vec![],
+            outputs: vec!["a".to_string()],
+            configuration: vec![],
+            restart: None,
+            stop_timeout: None,
+        };
+
+        let c = c.validate().unwrap();
+        let b = b.validate().unwrap();
+        let a = a.validate().unwrap();
+
+        let pipeline = Pipeline::new(vec![c, b, a]).validate().unwrap();
+
+        // The configured order is kept in services(), but producers are started first
+        assert_eq!(pipeline.services()[0].0.name, "c");
+        let order = pipeline.start_order();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[0].0.name, "a");
+        assert_eq!(order[1].0.name, "b");
+        assert_eq!(order[2].0.name, "c");
+    }
+
+    #[test]
+    fn test_pipeline_warnings() {
+        // A -> B, where B's output is not consumed and C is not connected to anything
+        let service = |name: &str, inputs: Vec<service::Input>| Service {
+            name: name.to_string(),
+            author: "ase-test".to_string(),
+            version: "0.1.0".to_string(),
+            source: format!("github.com/ase-test/{}", name),
+            commands: service::Commands {
+                build: None,
+                run: format!("echo '{}'", name),
+            },
+            inputs,
+            outputs: vec![name.to_string()],
+            configuration: vec![],
+            restart: None,
+            stop_timeout: None,
+        };
+        let a = service("a", vec![]);
+        let b = service(
+            "b",
+            vec![service::Input {
+                service: "a".to_string(),
+                streams: vec!["a".to_string()],
+            }],
+        );
+        let c = service("c", vec![]);
+
+        let pipeline = Pipeline::new(vec![
+            a.validate().unwrap(),
+            b.validate().unwrap(),
+            c.validate().unwrap(),
+        ]);
+
+        // Warnings do not make the pipeline invalid
+        let pipeline = pipeline.validate().unwrap();
+        let warnings = pipeline.warnings();
+        for warning in warnings.iter() {
+            println!("{}", warning);
+        }
+
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings.iter().all(|w| match w {
+            Error::PipelineValidationError(e) => e.is_warning(),
+            _ => false,
+        }));
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            Error::PipelineValidationError(PipelineValidationError::UnusedOutputWarning(u))
+                if u.service == "b" && u.stream == "b"
+        )));
+        assert!(warnings.iter().any(|w| matches!(
+            w,
+            Error::PipelineValidationError(PipelineValidationError::UnreachableServiceWarning(n))
+                if n == "c"
+        )));
     }
 
     #[test]
@@ -314,6 +657,8 @@ mod tests {
             inputs: vec![],
             outputs: vec!["a".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let b = Service {
             name: "b".to_string(),
@@ -330,6 +675,8 @@ mod tests {
             }],
             outputs: vec!["b".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let c = Service {
             name: "c".to_string(),
@@ -346,6 +693,8 @@ mod tests {
             }],
             outputs: vec!["c".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
 
         // Validate all services
@@ -364,7 +713,19 @@ mod tests {
             }
         }
 
-        assert!(validated_pipeline.is_err());
+        // The missing service is reported as such, not as each of the streams it should provide
+        let errors: Vec<Error> = validated_pipeline
+            .unwrap_err()
+            .into_iter()
+            .filter(|e| !matches!(e, Error::PipelineValidationError(e) if e.is_warning()))
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            Error::PipelineValidationError(PipelineValidationError::UnmetDependencyError(
+                UnmetDependencyError::UnmetService(e)
+            )) if e.source == "c" && e.target == "x"
+        ));
     }
 
     #[test]
@@ -382,6 +743,8 @@ mod tests {
             inputs: vec![],
             outputs: vec!["a".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let b = Service {
             name: "b".to_string(),
@@ -398,6 +761,8 @@ mod tests {
             }],
             outputs: vec!["b".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
         let c = Service {
             name: "b".to_string(),
@@ -414,6 +779,8 @@ mod tests {
             }],
             outputs: vec!["c".to_string()],
             configuration: vec![],
+            restart: None,
+            stop_timeout: None,
         };
 
         // Validate all services
@@ -35,9 +35,17 @@ pub struct Service {
     /// Names of the streams that this service produces.
     pub outputs: Vec<String>,
 
+    /// Determines if and how the service is restarted by roverd when its process exits.
+    /// Defaults to never restarting, in which case the pipeline is stopped.
+    pub restart: Option<Restart>,
+
     /// URL of the service's source repository.
     pub source: String,
 
+    /// Time in milliseconds roverd waits for the service to exit after asking it to stop,
+    /// before it is killed.
+    pub stop_timeout: Option<i64>,
+
     /// The version of the service.
     pub version: String,
 }
@@ -86,6 +94,34 @@ pub enum Value {
     String(String),
 }
 
+/// Determines if and how the service is restarted by roverd when its process exits.
+/// Defaults to never restarting, in which case the pipeline is stopped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Restart {
+    /// Delay in milliseconds before the first restart, doubled for every subsequent restart.
+    pub backoff: Option<i64>,
+
+    /// Maximum number of restarts after the pipeline was started, the pipeline is stopped
+    /// once they run out (only for the on-failure policy).
+    pub max_retries: Option<i64>,
+
+    /// When to restart the service (options: never, on-failure, always).
+    pub policy: Policy,
+}
+
+/// When to restart the service (options: never, on-failure, always).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Policy {
+    #[serde(rename = "always")]
+    Always,
+
+    #[serde(rename = "never")]
+    Never,
+
+    #[serde(rename = "on-failure")]
+    OnFailure,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Input {
     /// The name of the service providing the input streams.
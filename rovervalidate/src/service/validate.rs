@@ -9,6 +9,69 @@ use regex::Regex;
 #[derive(Debug, Clone)]
 pub struct ValidatedService(pub gen::Service);
 
+impl ValidatedService {
+    /// Returns this service with the values of its configuration options replaced by the
+    /// given overrides. Every override must refer to a declared option and keep its type.
+    pub fn with_overrides(
+        &self,
+        overrides: &[crate::config::ConfigurationOverride],
+    ) -> Result<ValidatedService> {
+        let mut errors = Vec::new();
+        let mut service = self.0.clone();
+
+        for (index, o) in overrides.iter().enumerate() {
+            let path = vec![
+                "configuration".to_string(),
+                index.to_string(),
+                "value".to_string(),
+            ];
+
+            let Some(option) = service.configuration.iter_mut().find(|c| c.name == o.name) else {
+                errors.push(Error::FieldValidationError(crate::error::FieldError {
+                    path,
+                    message: format!(
+                        "option {} is not declared by service {}",
+                        o.name, service.name
+                    ),
+                }));
+                continue;
+            };
+
+            // Without an explicit type, the declared value determines the type
+            let declared = match (&option.configuration_type, &option.value) {
+                (Some(t), _) => t.clone(),
+                (None, gen::Value::Number(_)) => gen::Type::Number,
+                (None, gen::Value::String(_)) => gen::Type::String,
+            };
+            let overridden = gen::Configuration {
+                name: option.name.clone(),
+                tunable: option.tunable,
+                configuration_type: Some(declared),
+                value: o.value.clone(),
+            };
+
+            match overridden.validate() {
+                Ok(_) => option.value = overridden.value,
+                Err(e) => errors.extend(e.into_iter().map(|e| match e {
+                    Error::FieldValidationError(f) => {
+                        Error::FieldValidationError(crate::error::FieldError {
+                            path: path.clone(),
+                            message: format!("option {} {}", o.name, f.message),
+                        })
+                    }
+                    e => e,
+                })),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(ValidatedService(service))
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 /**
  * This module applies validation logic to the auto-generated types for a rover service. We cannot use the validated crate, because if we regenerate the types, we lose the validation logic.
  * But the validate_field macro gives nicer errors anyway, so it's not a big deal.
@@ -115,6 +178,32 @@ impl Validate<ValidatedService> for gen::Service {
             }
         }
 
+        validate_field!(self.stop_timeout, &mut errors, |stop_timeout| {
+            match stop_timeout {
+                Some(timeout) if *timeout < 0 => Some("must not be negative"),
+                _ => None,
+            }
+        });
+
+        // Validate the restart policy
+        if let Some(restart) = &self.restart {
+            if let Err(restart_errors) = restart.validate() {
+                for error in restart_errors {
+                    match error {
+                        Error::FieldValidationError(mut field_error) => {
+                            field_error.path.insert(0, "restart".to_string());
+                            errors.push(Error::FieldValidationError(field_error));
+                        }
+                        Error::ParseError(mut field_error) => {
+                            field_error.path.insert(0, "restart".to_string());
+                            errors.push(Error::ParseError(field_error));
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+
         let pattern = Regex::new(r"^[a-z]+(-[a-z]+)*$").unwrap();
         // Validate all outputs
         for (index, output) in self.outputs.iter().enumerate() {
@@ -237,6 +326,38 @@ impl Validate<bool> for gen::Input {
     }
 }
 
+impl Validate<bool> for gen::Restart {
+    fn validate(&self) -> Result<bool> {
+        let mut errors = Vec::new();
+
+        validate_field!(self.max_retries, &mut errors, |max_retries| {
+            match max_retries {
+                Some(_) if self.policy != gen::Policy::OnFailure => {
+                    Some("can only be used with the on-failure policy")
+                }
+                Some(retries) if *retries < 0 => Some("must not be negative"),
+                _ => None,
+            }
+        });
+
+        validate_field!(self.backoff, &mut errors, |backoff| {
+            match backoff {
+                Some(_) if self.policy == gen::Policy::Never => {
+                    Some("cannot be used with the never policy")
+                }
+                Some(backoff) if *backoff < 0 => Some("must not be negative"),
+                _ => None,
+            }
+        });
+
+        if errors.is_empty() {
+            Ok(true)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 impl Validate<bool> for gen::Configuration {
     fn validate(&self) -> Result<bool> {
         let mut errors = Vec::new();
@@ -361,4 +482,62 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_configuration_overrides() {
+        let service: gen::Service = serde_yaml::from_str(
+            r#"
+name: imaging
+author: vu-ase
+source: github.com/vu-ase/imaging
+version: 1.0.0
+commands:
+  run: ./imaging
+inputs: []
+outputs: []
+configuration:
+  - name: threshold
+    value: 0.3
+  - name: mode
+    value: slow
+"#,
+        )
+        .unwrap();
+        let service = service.validate().unwrap();
+
+        let valid: Vec<crate::config::ConfigurationOverride> = serde_yaml::from_str(
+            r#"
+- name: threshold
+  value: 0.5
+"#,
+        )
+        .unwrap();
+        let overridden = service.with_overrides(&valid).unwrap();
+        assert!(matches!(
+            overridden.0.configuration[0].value,
+            gen::Value::Number(n) if n == 0.5
+        ));
+        assert!(matches!(
+            &overridden.0.configuration[1].value,
+            gen::Value::String(s) if s == "slow"
+        ));
+
+        // Options must be declared and keep the type of their declared value
+        let invalid: Vec<crate::config::ConfigurationOverride> = serde_yaml::from_str(
+            r#"
+- name: threshold
+  value: high
+- name: mode
+  value: 1.0
+- name: speed
+  value: 1.0
+"#,
+        )
+        .unwrap();
+        let errors = service.with_overrides(&invalid).unwrap_err();
+        for error in errors.iter() {
+            println!("{}", error);
+        }
+        assert_eq!(errors.len(), 3);
+    }
 }